@@ -0,0 +1,392 @@
+use std::{collections::{BTreeMap, HashMap, VecDeque}, io::Write};
+
+use crate::{
+    errors::Error,
+    helpers::graph_writer::{write_dot, write_gexf, write_graphml, write_to_string, AttributeType, GraphData, GraphEdge, GraphNode},
+    responses::search::SearchResponse,
+    Work,
+};
+
+use super::name::{normalize_name, PersonName};
+
+/// An author in the co-authorship graph, identified by the normalised name key.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CoAuthorNode {
+    /// Normalised name key of the author (see `normalize_name`).
+    pub key: String,
+
+    /// Spellings of the name seen in the works, with the number of works each was used in.
+    pub variants: BTreeMap<String, usize>,
+
+    /// Number of works the author appears on.
+    pub works: usize,
+}
+
+impl CoAuthorNode {
+    /// Returns the most frequently used spelling of the author's name.
+    pub fn name(&self) -> &str {
+        self.variants.iter()
+            .max_by(|(a_name, a_count), (b_name, b_count)| a_count.cmp(b_count).then(b_name.cmp(a_name)))
+            .map_or(self.key.as_str(), |(name, _)| name.as_str())
+    }
+}
+
+/// An undirected edge between two co-authors, weighted by the number of works they wrote together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CoAuthorEdge {
+    /// Index of the first author, always smaller than `target`.
+    pub source: usize,
+
+    /// Index of the second author.
+    pub target: usize,
+
+    /// Number of works the two authors wrote together.
+    pub weight: usize,
+}
+
+/// An undirected, weighted co-authorship graph built from works.
+///
+/// Authors are identified by their normalised name (see `normalize_name`), so different spellings of 
+/// the same name are merged into one node while authors sharing a family name and initial stay apart.
+/// A name given only by initials joins the node of the one full name with that family name and initial,
+/// or gets a node of its own if there is none or several. Two authors are connected if they appear on
+/// the same work, the edge weight counts the works they share.
+///
+/// ```
+/// use core_api_client::{authors::coauthors::CoAuthorGraph, Author, Work};
+///
+/// let work = |names: &[&str]| Work {
+///     authors: Some(names.iter().map(|n| Author::from(*n)).collect()),
+///     ..Default::default()
+/// };
+/// let works = vec![
+///     work(&["Smith, John", "Doe, Jane"]),
+///     work(&["J. Smith", "Jane Doe", "Roe, Richard"]),
+///     work(&["Jane Smith", "Roe, Richard"]),
+/// ];
+/// let graph = CoAuthorGraph::from_works(&works);
+///
+/// assert_eq!(graph.node_count(), 4);
+/// assert_ne!(graph.find("John Smith"), graph.find("Jane Smith"));
+/// let smith = graph.find("John Smith").unwrap();
+/// let doe = graph.find("Doe, J.").unwrap();
+/// assert_eq!(graph.weight(smith, doe), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CoAuthorGraph {
+    nodes: Vec<CoAuthorNode>,
+    edges: BTreeMap<(usize, usize), usize>,
+    index: HashMap<String, usize>,
+    full_names: HashMap<String, Vec<usize>>,
+}
+
+impl CoAuthorGraph {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a graph from a set of works.
+    pub fn from_works<'a, I>(works: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Work>,
+    {
+        let mut graph = Self::new();
+        for work in works {
+            graph.add_work(work);
+        }
+        graph
+    }
+
+    /// Adds the authors of all works in a search response to the graph.
+    pub fn add_search_response(&mut self, response: &SearchResponse<Work>) {
+        for work in response.results.iter().flatten() {
+            self.add_work(work);
+        }
+    }
+
+    /// Adds the authors of a work to the graph and connects every pair of them.
+    pub fn add_work(&mut self, work: &Work) {
+        let mut authors: Vec<usize> = vec![];
+        for author in work.authors.iter().flatten() {
+            let key = normalize_name(&author.name);
+            if key.is_empty() {
+                continue;
+            }
+            let position = self.node_for(key, PersonName::parse(&author.name).initial_key());
+            if authors.contains(&position) {
+                continue;
+            }
+            let node = &mut self.nodes[position];
+            node.works += 1;
+            *node.variants.entry(author.name.trim().to_string()).or_insert(0) += 1;
+            authors.push(position);
+        }
+
+        for (i, a) in authors.iter().enumerate() {
+            for b in authors.iter().skip(i + 1) {
+                *self.edges.entry((*a.min(b), *a.max(b))).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Returns the authors of the graph, edges refer to authors by their position in this slice.
+    pub fn nodes(&self) -> &[CoAuthorNode] {
+        &self.nodes
+    }
+
+    /// Returns the edges of the graph.
+    pub fn edges(&self) -> Vec<CoAuthorEdge> {
+        self.edges.iter()
+            .map(|((source, target), weight)| CoAuthorEdge { source: *source, target: *target, weight: *weight })
+            .collect()
+    }
+
+    /// Returns the number of authors in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of co-author pairs in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Finds an author by name, the name is normalised the same way as names in works.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        let key = normalize_name(name);
+        let initial_key = PersonName::parse(name).initial_key();
+        if key == initial_key {
+            if let Some([only]) = self.full_names.get(&initial_key).map(Vec::as_slice) {
+                return Some(*only);
+            }
+        }
+        self.index.get(&key).copied()
+    }
+
+    /// Returns the node of a name key, adding it if needed. Initial-only keys resolve to the single full name
+    /// with that initial, and the first full name with an initial takes over the node of earlier initial-only
+    /// mentions.
+    fn node_for(&mut self, key: String, initial_key: String) -> usize {
+        if let Some(position) = self.index.get(&key) {
+            return *position;
+        }
+        let full_names = self.full_names.entry(initial_key.clone()).or_default();
+        let position = if key == initial_key {
+            if let [only] = full_names.as_slice() {
+                return *only;
+            }
+            None
+        } else if full_names.is_empty() {
+            self.index.remove(&initial_key)
+        } else {
+            None
+        };
+        let position = match position {
+            Some(position) => {
+                self.nodes[position].key = key.clone();
+                position
+            },
+            None => {
+                self.nodes.push(CoAuthorNode { key: key.clone(), ..Default::default() });
+                self.nodes.len() - 1
+            },
+        };
+        if key != initial_key {
+            full_names.push(position);
+        }
+        self.index.insert(key, position);
+        position
+    }
+
+    /// Returns the number of works two authors wrote together.
+    pub fn weight(&self, a: usize, b: usize) -> usize {
+        self.edges.get(&(a.min(b), a.max(b))).copied().unwrap_or(0)
+    }
+
+    /// Returns the co-authors of an author with the number of shared works.
+    pub fn coauthors_of(&self, node: usize) -> Vec<(usize, usize)> {
+        self.edges.iter()
+            .filter_map(|((s, t), w)| match (*s == node, *t == node) {
+                (true, _) => Some((*t, *w)),
+                (_, true) => Some((*s, *w)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the degree centrality of every author: the number of distinct co-authors divided by the 
+    /// number of other authors in the graph.
+    pub fn degree_centrality(&self) -> Vec<f64> {
+        let others = self.nodes.len().saturating_sub(1).max(1) as f64;
+        self.degrees().into_iter().map(|d| d as f64 / others).collect()
+    }
+
+    /// Returns the weighted degree of every author: the total number of co-authorships, counting 
+    /// repeated collaborations.
+    pub fn weighted_degree(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.nodes.len()];
+        for ((s, t), w) in self.edges.iter() {
+            degrees[*s] += w;
+            degrees[*t] += w;
+        }
+        degrees
+    }
+
+    /// Returns the closeness centrality of every author, computed within the author's connected component
+    /// with the Wasserman and Faust correction, so authors in small components do not score highly.
+    pub fn closeness_centrality(&self) -> Vec<f64> {
+        let adjacency = self.adjacency();
+        let n = self.nodes.len();
+        (0..n).map(|source| {
+            let distances = bfs_distances(&adjacency, source);
+            let reachable: Vec<usize> = distances.iter().flatten().copied().filter(|d| *d > 0).collect();
+            let total: usize = reachable.iter().sum();
+            if total == 0 || n < 2 {
+                return 0.0;
+            }
+            let r = reachable.len() as f64;
+            (r / total as f64) * (r / (n - 1) as f64)
+        }).collect()
+    }
+
+    /// Returns the normalised betweenness centrality of every author (Brandes' algorithm, unweighted).
+    pub fn betweenness_centrality(&self) -> Vec<f64> {
+        let adjacency = self.adjacency();
+        let n = self.nodes.len();
+        let mut centrality = vec![0.0; n];
+
+        for source in 0..n {
+            let mut stack = vec![];
+            let mut predecessors: Vec<Vec<usize>> = vec![vec![]; n];
+            let mut paths = vec![0.0; n];
+            let mut distance: Vec<Option<usize>> = vec![None; n];
+            paths[source] = 1.0;
+            distance[source] = Some(0);
+
+            let mut queue = VecDeque::from([source]);
+            while let Some(v) = queue.pop_front() {
+                stack.push(v);
+                let dv = distance[v].unwrap_or_default();
+                for &w in adjacency[v].iter() {
+                    if distance[w].is_none() {
+                        distance[w] = Some(dv + 1);
+                        queue.push_back(w);
+                    }
+                    if distance[w] == Some(dv + 1) {
+                        paths[w] += paths[v];
+                        predecessors[w].push(v);
+                    }
+                }
+            }
+
+            let mut dependency = vec![0.0; n];
+            while let Some(w) = stack.pop() {
+                for &v in predecessors[w].iter() {
+                    dependency[v] += paths[v] / paths[w] * (1.0 + dependency[w]);
+                }
+                if w != source {
+                    centrality[w] += dependency[w];
+                }
+            }
+        }
+
+        // every pair is counted from both ends in an undirected graph
+        let scale = if n > 2 { 1.0 / ((n - 1) * (n - 2)) as f64 } else { 0.0 };
+        centrality.into_iter().map(|c| c * scale).collect()
+    }
+
+    fn degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.nodes.len()];
+        for (s, t) in self.edges.keys() {
+            degrees[*s] += 1;
+            degrees[*t] += 1;
+        }
+        degrees
+    }
+
+    fn adjacency(&self) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![vec![]; self.nodes.len()];
+        for (s, t) in self.edges.keys() {
+            adjacency[*s].push(*t);
+            adjacency[*t].push(*s);
+        }
+        adjacency
+    }
+
+    /// Writes the graph in the [GraphML](http://graphml.graphdrawing.org/) format.
+    pub fn write_graphml<W: Write>(&self, writer: W) -> Result<(), Error> {
+        write_graphml(&self.graph_data(), writer)
+    }
+
+    /// Writes the graph in the [GEXF](https://gexf.net/) format.
+    pub fn write_gexf<W: Write>(&self, writer: W) -> Result<(), Error> {
+        write_gexf(&self.graph_data(), writer)
+    }
+
+    /// Writes the graph in the Graphviz [DOT](https://graphviz.org/doc/info/lang.html) format.
+    pub fn write_dot<W: Write>(&self, writer: W) -> Result<(), Error> {
+        write_dot(&self.graph_data(), writer)
+    }
+
+    /// Returns the graph in the GraphML format.
+    pub fn to_graphml(&self) -> String {
+        write_to_string(|buf| self.write_graphml(buf))
+    }
+
+    /// Returns the graph in the GEXF format.
+    pub fn to_gexf(&self) -> String {
+        write_to_string(|buf| self.write_gexf(buf))
+    }
+
+    /// Returns the graph in the DOT format.
+    pub fn to_dot(&self) -> String {
+        write_to_string(|buf| self.write_dot(buf))
+    }
+
+    fn graph_data(&self) -> GraphData {
+        let degree = self.degree_centrality();
+        let betweenness = self.betweenness_centrality();
+        GraphData {
+            name: "coauthors".to_string(),
+            directed: false,
+            node_attributes: vec![
+                ("key", AttributeType::String),
+                ("works", AttributeType::Int),
+                ("degree_centrality", AttributeType::Double),
+                ("betweenness_centrality", AttributeType::Double),
+            ],
+            nodes: self.nodes.iter().enumerate().map(|(i, node)| GraphNode {
+                id: format!("a{}", i),
+                label: node.name().to_string(),
+                attributes: vec![
+                    Some(node.key.clone()),
+                    Some(node.works.to_string()),
+                    Some(degree[i].to_string()),
+                    Some(betweenness[i].to_string()),
+                ],
+            }).collect(),
+            edges: self.edges.iter().map(|((s, t), w)| GraphEdge {
+                source: format!("a{}", s),
+                target: format!("a{}", t),
+                weight: Some(*w as f64),
+            }).collect(),
+        }
+    }
+}
+
+fn bfs_distances(adjacency: &[Vec<usize>], source: usize) -> Vec<Option<usize>> {
+    let mut distance = vec![None; adjacency.len()];
+    distance[source] = Some(0);
+    let mut queue = VecDeque::from([source]);
+    while let Some(v) = queue.pop_front() {
+        let dv = distance[v].unwrap_or_default();
+        for &w in adjacency[v].iter() {
+            if distance[w].is_none() {
+                distance[w] = Some(dv + 1);
+                queue.push_back(w);
+            }
+        }
+    }
+    distance
+}
@@ -0,0 +1,254 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::{IdentifierValue, Orcid, Work};
+
+use super::name::PersonName;
+
+/// A single occurrence of an author name on a work.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AuthorMention {
+    /// Position of the work in the list of works given to the clusterer.
+    pub work: usize,
+
+    /// Position of the author in the author list of the work.
+    pub position: usize,
+
+    /// Name as written on the work.
+    pub name: String,
+
+    /// ORCID of the author, if the work supplied one.
+    pub orcid: Option<Orcid>,
+}
+
+/// A group of author mentions believed to refer to the same person.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorCluster {
+    /// The most complete form of the name among the mentions.
+    pub name: PersonName,
+
+    /// The mentions in the cluster.
+    pub mentions: Vec<AuthorMention>,
+
+    /// ORCID of the person, if any mention carried one.
+    pub orcid: Option<Orcid>,
+}
+
+impl AuthorCluster {
+    /// Returns the distinct spellings of the name in the cluster.
+    pub fn variants(&self) -> BTreeSet<&str> {
+        self.mentions.iter().map(|m| m.name.as_str()).collect()
+    }
+
+    /// Returns the positions of the works the person appears on.
+    pub fn works(&self) -> BTreeSet<usize> {
+        self.mentions.iter().map(|m| m.work).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MentionData {
+    mention: AuthorMention,
+    name: PersonName,
+    coauthors: BTreeSet<String>,
+    venues: BTreeSet<String>,
+}
+
+/// Clusters author mentions across works into persons.
+///
+/// Mentions are first blocked by family name and first initial, so only names that could denote the 
+/// same person are compared. Within a block two mentions are merged when their names are compatible 
+/// (see `PersonName::is_compatible`) and there is evidence they are the same person:
+///
+/// * they carry the same ORCID,
+/// * their full given names are equal (not just initials), or
+/// * they share a co-author or a journal (by ISSN or title), as configured. A shared publisher is not
+///   evidence, large publishers would merge unrelated people.
+///
+/// Mentions with different ORCIDs are never merged, and a cluster is never extended with a mention
+/// whose name is incompatible with any of its members (e.g. `J. Smith` does not join `John Smith` and 
+/// `Jane Smith` into one person). The ORCID of a mention is the one on its `Author`; ORCIDs found only in
+/// the identifiers of a work are attributed to its author when the work has a single author, and ignored
+/// otherwise.
+///
+/// ```
+/// use core_api_client::{authors::disambiguation::AuthorClusterer, Author, Work};
+///
+/// let work = |names: &[&str], publisher: &str| Work {
+///     authors: Some(names.iter().map(|n| Author::from(*n)).collect()),
+///     publisher: Some(publisher.to_string()),
+///     ..Default::default()
+/// };
+/// let works = vec![
+///     work(&["John Smith", "Jane Doe"], "Elsevier"),
+///     work(&["Smith, J.", "Doe, Jane"], "Springer"),
+///     work(&["J. Smith"], "Elsevier"),
+/// ];
+/// let clusters = AuthorClusterer::new().cluster(&works);
+///
+/// // the second Smith shares a co-author with the first, the third only shares a publisher
+/// let smiths: Vec<_> = clusters.iter().filter(|c| c.name.family == "Smith").collect();
+/// assert_eq!(smiths.len(), 2);
+/// assert_eq!(smiths[0].name.to_string(), "Smith, John");
+/// ```
+#[derive(Debug, Clone)]
+pub struct AuthorClusterer {
+    use_coauthors: bool,
+    use_venues: bool,
+}
+
+impl Default for AuthorClusterer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthorClusterer {
+    /// Creates a clusterer that uses both co-author and venue evidence.
+    pub fn new() -> Self {
+        AuthorClusterer { use_coauthors: true, use_venues: true }
+    }
+
+    /// Sets whether a shared co-author is evidence for merging two mentions.
+    pub fn use_coauthors(self, use_coauthors: bool) -> Self {
+        Self { use_coauthors, ..self }
+    }
+
+    /// Sets whether a shared journal (by ISSN or title) is evidence for merging two mentions.
+    pub fn use_venues(self, use_venues: bool) -> Self {
+        Self { use_venues, ..self }
+    }
+
+    /// Clusters the author mentions of the given works. Clusters are ordered by the first work they appear on.
+    pub fn cluster(&self, works: &[Work]) -> Vec<AuthorCluster> {
+        let mentions = collect_mentions(works);
+
+        let mut blocks: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (i, mention) in mentions.iter().enumerate() {
+            blocks.entry(mention.name.initial_key()).or_default().push(i);
+        }
+
+        let mut clusters: Vec<Vec<usize>> = vec![];
+        for block in blocks.values() {
+            clusters.extend(self.cluster_block(&mentions, block));
+        }
+        clusters.sort_by_key(|members| members.iter().map(|m| (mentions[*m].mention.work, mentions[*m].mention.position)).min());
+
+        clusters.into_iter().map(|members| {
+            let name = members.iter()
+                .map(|m| &mentions[*m].name)
+                .max_by_key(|name| (name.given.iter().map(|g| g.chars().count().min(2)).sum::<usize>(), name.given.len()))
+                .cloned()
+                .unwrap_or_default();
+            let orcid = members.iter().find_map(|m| mentions[*m].mention.orcid.clone());
+            AuthorCluster { 
+                name, 
+                mentions: members.iter().map(|m| mentions[*m].mention.clone()).collect(), 
+                orcid,
+            }
+        }).collect()
+    }
+
+    fn cluster_block(&self, mentions: &[MentionData], block: &[usize]) -> Vec<Vec<usize>> {
+        // clusters hold positions in the block, `cluster_of` maps a position to its cluster
+        let mut clusters: Vec<Vec<usize>> = (0..block.len()).map(|i| vec![i]).collect();
+        let mut cluster_of: Vec<usize> = (0..block.len()).collect();
+
+        // a single pass over the pairs with evidence, merging their clusters unless any two members conflict;
+        // clusters only grow, so a conflicting merge stays impossible later on
+        for a in 0..block.len() {
+            for b in (a + 1)..block.len() {
+                let (ca, cb) = (cluster_of[a], cluster_of[b]);
+                if ca == cb || !self.has_evidence(&mentions[block[a]], &mentions[block[b]]) {
+                    continue;
+                }
+                let conflicting = clusters[ca].iter()
+                    .flat_map(|x| clusters[cb].iter().map(move |y| (x, y)))
+                    .any(|(x, y)| conflict(&mentions[block[*x]], &mentions[block[*y]]));
+                if conflicting {
+                    continue;
+                }
+                let (keep, merged) = (ca.min(cb), ca.max(cb));
+                let members = std::mem::take(&mut clusters[merged]);
+                for member in members.iter() {
+                    cluster_of[*member] = keep;
+                }
+                clusters[keep].extend(members);
+            }
+        }
+
+        clusters.into_iter()
+            .filter(|members| !members.is_empty())
+            .map(|mut members| {
+                members.sort_unstable();
+                members.into_iter().map(|i| block[i]).collect()
+            })
+            .collect()
+    }
+
+    fn has_evidence(&self, x: &MentionData, y: &MentionData) -> bool {
+        let same_orcid = x.mention.orcid.is_some() && x.mention.orcid == y.mention.orcid;
+        let same_full_name = !x.name.has_initials_only() && x.name.full_key() == y.name.full_key();
+        let shared_coauthor = self.use_coauthors && !x.coauthors.is_disjoint(&y.coauthors);
+        let shared_venue = self.use_venues && !x.venues.is_disjoint(&y.venues);
+        same_orcid || same_full_name || shared_coauthor || shared_venue
+    }
+}
+
+/// Checks whether two mentions can not be the same person.
+fn conflict(x: &MentionData, y: &MentionData) -> bool {
+    !x.name.is_compatible(&y.name)
+        || matches!((&x.mention.orcid, &y.mention.orcid), (Some(o1), Some(o2)) if o1 != o2)
+        || x.mention.work == y.mention.work
+}
+
+fn collect_mentions(works: &[Work]) -> Vec<MentionData> {
+    let mut mentions = vec![];
+    for (w, work) in works.iter().enumerate() {
+        let authors: Vec<_> = work.authors.iter().flatten().collect();
+        let names: Vec<PersonName> = authors.iter().map(|a| a.parsed_name()).collect();
+
+        let mut venues = BTreeSet::new();
+        for journal in work.journals.iter().flatten() {
+            venues.extend(journal.issns().iter().map(|issn| format!("issn:{}", issn)));
+            if let Some(title) = &journal.title {
+                venues.insert(format!("journal:{}", title.trim().to_lowercase()));
+            }
+        }
+
+        let orcids = work_orcids(work, authors.len());
+        for (position, author) in authors.iter().enumerate() {
+            if names[position].family.is_empty() {
+                continue;
+            }
+            let coauthors = names.iter()
+                .enumerate()
+                .filter(|(i, _)| *i != position)
+                .map(|(_, name)| name.initial_key())
+                .collect();
+            mentions.push(MentionData {
+                mention: AuthorMention { 
+                    work: w, 
+                    position, 
+                    name: author.name.clone(), 
+                    orcid: author.orcid.as_ref().and_then(IdentifierValue::valid).cloned().or_else(|| orcids.get(&position).cloned()),
+                },
+                name: names[position].clone(),
+                coauthors,
+                venues: venues.clone(),
+            });
+        }
+    }
+    mentions
+}
+
+/// ORCIDs listed in the identifiers of a work can only be attributed to an author if the work has a single author.
+fn work_orcids(work: &Work, authors: usize) -> HashMap<usize, Orcid> {
+    let mut orcids = HashMap::new();
+    if authors == 1 {
+        let orcid = work.orcids().into_iter().next();
+        if let Some(orcid) = orcid {
+            orcids.insert(0, orcid);
+        }
+    }
+    orcids
+}
@@ -0,0 +1,3 @@
+pub mod name;
+pub mod coauthors;
+pub mod disambiguation;
@@ -0,0 +1,210 @@
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// Lower case particles that belong to the family name (e.g. `van` in `Ludwig van Beethoven`).
+const FAMILY_PARTICLES: &[&str] = &[
+    "van", "von", "der", "den", "de", "del", "della", "di", "da", "dos", "das", "du", "la", "le", "ter", "ten", "bin", "ibn", "al",
+];
+
+/// Removes diacritics from a string (`Tošić` becomes `Tosic`), including letters that do not decompose 
+/// into a base letter and a combining mark, such as `ø`, `ł` or `ß`.
+///
+/// ```
+/// use core_api_client::authors::name::fold_diacritics;
+///
+/// assert_eq!(fold_diacritics("Tošić Łukasz Ørsted Weiß"), "Tosic Lukasz Orsted Weiss");
+/// ```
+pub fn fold_diacritics(value: &str) -> String {
+    let mut folded = String::with_capacity(value.len());
+    for c in value.nfkd().filter(|c| !is_combining_mark(*c)) {
+        match c {
+            'ø' => folded.push('o'),
+            'Ø' => folded.push('O'),
+            'ł' => folded.push('l'),
+            'Ł' => folded.push('L'),
+            'đ' => folded.push('d'),
+            'Đ' => folded.push('D'),
+            'ß' => folded.push_str("ss"),
+            'æ' => folded.push_str("ae"),
+            'Æ' => folded.push_str("AE"),
+            'œ' => folded.push_str("oe"),
+            'Œ' => folded.push_str("OE"),
+            'þ' => folded.push_str("th"),
+            'Þ' => folded.push_str("TH"),
+            'ı' => folded.push('i'),
+            c => folded.push(c),
+        }
+    }
+    folded
+}
+
+/// A personal name split into given and family names.
+///
+/// The parts keep the spelling of the original name, the key methods return ASCII folded, lower case 
+/// forms suited for matching. Names given as `Family, Given`, `Given Family` and `Family Initials` 
+/// (`Smith JA`) are recognised, family name particles (`van`, `de`, ...) are kept with the family name.
+///
+/// ```
+/// use core_api_client::authors::name::PersonName;
+///
+/// let name = PersonName::parse("Tošić, Aleksandar J.");
+/// assert_eq!(name.family, "Tošić");
+/// assert_eq!(name.given, vec!["Aleksandar", "J"]);
+/// assert_eq!(name.full_key(), "tosic aleksandar j");
+/// assert_eq!(name.initials_key(), "tosic aj");
+/// assert_eq!(name.initial_key(), "tosic a");
+/// assert!(name.is_compatible(&PersonName::parse("A. Tosic")));
+/// assert!(!name.is_compatible(&PersonName::parse("Andrej Tošić")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PersonName {
+    /// Given names or initials, in order.
+    pub given: Vec<String>,
+
+    /// Family name, including particles.
+    pub family: String,
+}
+
+impl PersonName {
+    /// Parses a name into given and family names.
+    pub fn parse(name: &str) -> Self {
+        let (given, family) = split_name(name);
+        PersonName { given, family: family.join(" ") }
+    }
+
+    /// Returns the folded family name (e.g. `van beethoven`).
+    pub fn family_key(&self) -> String {
+        fold_token(&self.family)
+    }
+
+    /// Returns the folded family name followed by the first initial (e.g. `smith j`).
+    pub fn initial_key(&self) -> String {
+        match self.given.first().and_then(|g| fold_token(g).chars().next()) {
+            Some(initial) => format!("{} {}", self.family_key(), initial),
+            None => self.family_key(),
+        }
+    }
+
+    /// Returns the folded family name followed by all initials (e.g. `smith ja`).
+    pub fn initials_key(&self) -> String {
+        let initials: String = self.given.iter()
+            .flat_map(|g| fold_token(g).split('-').filter_map(|part| part.chars().next()).collect::<Vec<_>>())
+            .collect();
+        if initials.is_empty() {
+            self.family_key()
+        } else {
+            format!("{} {}", self.family_key(), initials)
+        }
+    }
+
+    /// Returns the folded family name followed by all given names (e.g. `smith john a`).
+    pub fn full_key(&self) -> String {
+        std::iter::once(self.family_key())
+            .chain(self.given.iter().map(|g| fold_token(g)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Checks whether the given names are initials only.
+    pub fn has_initials_only(&self) -> bool {
+        self.given.iter().all(|g| g.chars().count() <= 1)
+    }
+
+    /// Checks whether two names can denote the same person: the family names are equal and the given
+    /// names agree, where an initial matches any name starting with it (`J` matches `John`, but `John`
+    /// does not match `Jane`). Missing given names are compatible with any given names.
+    pub fn is_compatible(&self, other: &PersonName) -> bool {
+        if self.family_key() != other.family_key() {
+            return false;
+        }
+        self.given.iter().zip(other.given.iter()).all(|(a, b)| {
+            let (a, b) = (fold_token(a), fold_token(b));
+            if a.chars().count() <= 1 || b.chars().count() <= 1 {
+                a.chars().next() == b.chars().next()
+            } else {
+                a == b
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for PersonName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let given = self.given.iter()
+            .map(|g| if g.chars().count() == 1 { format!("{}.", g) } else { g.clone() })
+            .collect::<Vec<_>>()
+            .join(" ");
+        if given.is_empty() {
+            write!(f, "{}", self.family)
+        } else {
+            write!(f, "{}, {}", self.family, given)
+        }
+    }
+}
+
+fn fold_token(token: &str) -> String {
+    fold_diacritics(token).to_lowercase()
+}
+
+/// Normalises an author name into a key identifying the author within a set of works.
+///
+/// The key is the ASCII folded, lower case family name followed by the first given name, so `"Smith, John"`
+/// and `"John Smith"` map to `"smith john"` while `"Jane Smith"` maps to `"smith jane"`. Names that only
+/// give initials fall back to the first initial (see `PersonName::initial_key`).
+///
+/// ```
+/// use core_api_client::authors::name::normalize_name;
+///
+/// assert_eq!(normalize_name("Smith, John"), "smith john");
+/// assert_eq!(normalize_name("J. Smith"), "smith j");
+/// assert_eq!(normalize_name("Smith JA"), "smith j");
+/// assert_eq!(normalize_name("Aleksandar Tošić"), "tosic aleksandar");
+/// assert_eq!(normalize_name("Ludwig van Beethoven"), "van beethoven ludwig");
+/// ```
+pub fn normalize_name(name: &str) -> String {
+    let name = PersonName::parse(name);
+    match name.given.first().map(|given| fold_token(given)) {
+        Some(given) if given.chars().count() > 1 => format!("{} {}", name.family_key(), given),
+        _ => name.initial_key(),
+    }
+}
+
+/// Splits a name into given name and family name tokens, keeping their original spelling.
+fn split_name(name: &str) -> (Vec<String>, Vec<String>) {
+    let tokens = |part: &str| -> Vec<String> {
+        part.split(|c: char| c.is_whitespace() || c == '.')
+            .map(|t| t.trim_matches(|c: char| !c.is_alphanumeric() && c != '-' && c != '\''))
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+
+    if let Some((family, given)) = name.split_once(',') {
+        let family = tokens(family);
+        let given = tokens(given);
+        if !family.is_empty() {
+            return (given, family);
+        }
+        return (vec![], given);
+    }
+
+    let mut all = tokens(name);
+    if all.len() <= 1 {
+        return (vec![], all);
+    }
+    // `Smith J` or `Smith JA`, the family name followed by initials
+    let last = &all[all.len() - 1];
+    if last.chars().count() <= 2 && last.chars().all(char::is_uppercase) && all[0].chars().count() > 2 {
+        let family = vec![all.remove(0)];
+        let given = all.iter()
+            .flat_map(|initials| initials.chars().map(|c| c.to_string()).collect::<Vec<_>>())
+            .collect();
+        return (given, family);
+    }
+    // the family name is the last token, together with any particles directly preceding it
+    let mut family_start = all.len() - 1;
+    while family_start > 1 && FAMILY_PARTICLES.contains(&fold_token(&all[family_start - 1]).as_str()) {
+        family_start -= 1;
+    }
+    let family = all.split_off(family_start);
+    (all, family)
+}
@@ -0,0 +1,198 @@
+use std::{
+    fs::{self, File},
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::errors::Error;
+
+use super::{RawResponse, RequestKey};
+
+const EXTENSION: &str = "json.gz";
+
+/// Persistent cache of API responses, stored as one gzip compressed JSON file per request in a directory.
+///
+/// Files are named after the SHA-256 hash of the request (method, URI and body) and hold the raw response
+/// together with the request it answers and when it was fetched, so a cache directory can be shared or
+/// archived next to an analysis and re-used with `Api::offline` to re-run it without network access.
+/// Entries never expire unless a TTL is set. Entries that can not be read are removed and fetched again, and
+/// failing to write an entry does not fail the request.
+///
+/// ```no_run
+/// use core_api_client::{cache::disk::DiskCache, Api};
+///
+/// let cache = DiskCache::open("core-cache").unwrap();
+/// let api = Api::from("API_KEY").disk_cache(cache).offline(true);
+///
+/// // answered from the cache, or `Error::NotCached` if the work was never fetched
+/// let work = api.get_work(8972689);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+    ttl: Option<Duration>,
+}
+
+/// A response stored in a `DiskCache`, with the request it answers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// HTTP method of the request (`GET` or `POST`).
+    pub method: String,
+
+    /// Request URI, relative to the API base URL.
+    pub uri: String,
+
+    /// Request body, if any.
+    pub body: Option<String>,
+
+    /// Raw JSON response.
+    pub data: String,
+
+    /// Remaining rate limit reported with the response.
+    pub rate_limit: Option<i32>,
+
+    /// Seconds since the Unix epoch at which the response was fetched.
+    pub fetched_at: u64,
+}
+
+impl CacheEntry {
+    /// Returns the time at which the response was fetched.
+    pub fn fetched_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.fetched_at)
+    }
+
+    fn is_expired(&self, ttl: Option<Duration>) -> bool {
+        ttl.is_some_and(|ttl| self.fetched_at() + ttl <= SystemTime::now())
+    }
+}
+
+impl DiskCache {
+    /// Opens the cache stored in `dir`, creating the directory if needed.
+    pub fn open<P: AsRef<Path>>(dir: P) -> Result<Self, Error> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+        Ok(DiskCache { dir, ttl: None })
+    }
+
+    /// Sets how long entries are used after being fetched. Expired entries are ignored, and replaced when the
+    /// request is sent again.
+    pub fn ttl(self, ttl: Duration) -> Self {
+        Self { ttl: Some(ttl), ..self }
+    }
+
+    /// Returns the cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Reads all entries in the cache, including expired ones.
+    pub fn entries(&self) -> Result<Vec<CacheEntry>, Error> {
+        let mut entries = vec![];
+        for path in self.files()? {
+            entries.push(read_entry(&path)?);
+        }
+        entries.sort_by(|a, b| a.fetched_at.cmp(&b.fetched_at).then_with(|| a.uri.cmp(&b.uri)));
+        Ok(entries)
+    }
+
+    /// Returns the number of entries in the cache.
+    pub fn len(&self) -> Result<usize, Error> {
+        Ok(self.files()?.len())
+    }
+
+    /// Checks whether the cache holds no entries.
+    pub fn is_empty(&self) -> Result<bool, Error> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&self) -> Result<(), Error> {
+        for path in self.files()? {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn get(&self, key: &RequestKey) -> Result<Option<RawResponse>, Error> {
+        let path = self.path(key);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let entry = match read_entry(&path) {
+            Ok(entry) => entry,
+            Err(_) => {
+                // a corrupt entry (e.g. from an interrupted copy) is dropped and fetched again
+                let _ = fs::remove_file(&path);
+                return Ok(None);
+            },
+        };
+        if entry.is_expired(self.ttl) {
+            return Ok(None);
+        }
+        Ok(Some(RawResponse { data: entry.data, rate_limit: entry.rate_limit }))
+    }
+
+    pub(crate) fn insert(&self, key: &RequestKey, response: &RawResponse) -> Result<(), Error> {
+        let fetched_at = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        let entry = CacheEntry {
+            method: key.method.as_str().to_string(),
+            uri: key.uri.clone(),
+            body: key.body.clone(),
+            data: response.data.clone(),
+            rate_limit: response.rate_limit,
+            fetched_at,
+        };
+
+        // written next to the entry and renamed, so readers never see a partial file
+        let path = self.path(key);
+        let tmp = path.with_extension("tmp");
+        let written = write_entry(&tmp, &entry).and_then(|_| Ok(fs::rename(&tmp, path)?));
+        if written.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+        written
+    }
+
+    fn path(&self, key: &RequestKey) -> PathBuf {
+        let mut hasher = Sha256::new();
+        hasher.update(key.method.as_str());
+        hasher.update([0]);
+        hasher.update(&key.uri);
+        if let Some(body) = key.body.as_ref() {
+            hasher.update([0]);
+            hasher.update(body);
+        }
+        let name: String = hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect();
+        self.dir.join(format!("{}.{}", name, EXTENSION))
+    }
+
+    fn files(&self) -> Result<Vec<PathBuf>, Error> {
+        let mut files = vec![];
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            let is_entry = path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.ends_with(EXTENSION));
+            if is_entry {
+                files.push(path);
+            }
+        }
+        Ok(files)
+    }
+}
+
+fn write_entry(path: &Path, entry: &CacheEntry) -> Result<(), Error> {
+    let mut encoder = GzEncoder::new(File::create(path)?, Compression::default());
+    encoder.write_all(&serde_json::to_vec(entry)?)?;
+    encoder.finish()?.sync_all()?;
+    Ok(())
+}
+
+fn read_entry(path: &Path) -> Result<CacheEntry, Error> {
+    let mut json = String::new();
+    GzDecoder::new(File::open(path)?).read_to_string(&mut json)?;
+    Ok(serde_json::from_str(&json)?)
+}
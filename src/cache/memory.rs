@@ -0,0 +1,131 @@
+use std::{collections::{BTreeMap, HashMap}, time::{Duration, Instant}};
+
+use super::{CacheStats, Endpoint, RawResponse, RequestKey};
+
+/// In-memory cache of API responses with LRU eviction and per-endpoint TTLs, enabled with `Api::cache`.
+///
+/// Responses are keyed by the request (method, URI and body), so a search is only answered from the cache
+/// for the same query, limit and offset. Only responses that were parsed successfully are stored. By default
+/// entries live for one hour; searches can be kept shorter (or not cached at all with a zero TTL) via
+/// `endpoint_ttl`.
+///
+/// ```
+/// use std::time::Duration;
+/// use core_api_client::{cache::{memory::ResponseCache, Endpoint}, Api};
+///
+/// let cache = ResponseCache::new(1000)
+///     .ttl(Duration::from_secs(24 * 60 * 60))
+///     .endpoint_ttl(Endpoint::Search, Duration::from_secs(60));
+/// let api = Api::from("API_KEY").cache(cache);
+///
+/// assert_eq!(api.cache_stats().unwrap().hits, 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    capacity: usize,
+    ttl: Duration,
+    endpoint_ttls: HashMap<Endpoint, Duration>,
+    entries: HashMap<RequestKey, Entry>,
+    recency: BTreeMap<u64, RequestKey>,
+    tick: u64,
+    stats: CacheStats,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    response: RawResponse,
+    expires: Instant,
+    used: u64,
+}
+
+impl ResponseCache {
+    /// Creates a cache holding at most `capacity` responses, with a TTL of one hour.
+    pub fn new(capacity: usize) -> Self {
+        ResponseCache {
+            capacity,
+            ttl: Duration::from_secs(60 * 60),
+            endpoint_ttls: HashMap::new(),
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Sets the TTL of endpoints without their own TTL.
+    pub fn ttl(self, ttl: Duration) -> Self {
+        Self { ttl, ..self }
+    }
+
+    /// Sets the TTL of an endpoint. Responses of endpoints with a zero TTL are not cached.
+    pub fn endpoint_ttl(mut self, endpoint: Endpoint, ttl: Duration) -> Self {
+        self.endpoint_ttls.insert(endpoint, ttl);
+        self
+    }
+
+    /// Returns the TTL used for responses of an endpoint.
+    pub fn ttl_of(&self, endpoint: Endpoint) -> Duration {
+        self.endpoint_ttls.get(&endpoint).copied().unwrap_or(self.ttl)
+    }
+
+    /// Returns the hit and miss counters.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats { entries: self.entries.len(), ..self.stats }
+    }
+
+    /// Removes all entries, keeping the counters.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    fn endpoint_ttl_of(&self, key: &RequestKey) -> Duration {
+        Endpoint::from_uri(&key.uri).map_or(self.ttl, |endpoint| self.ttl_of(endpoint))
+    }
+
+    pub(crate) fn get(&mut self, key: &RequestKey) -> Option<RawResponse> {
+        if self.endpoint_ttl_of(key).is_zero() {
+            return None;
+        }
+        let now = Instant::now();
+        let (used, expired) = match self.entries.get(key) {
+            Some(entry) => (entry.used, entry.expires <= now),
+            None => {
+                self.stats.misses += 1;
+                return None;
+            },
+        };
+        self.recency.remove(&used);
+        if expired {
+            self.entries.remove(key);
+            self.stats.expired += 1;
+            self.stats.misses += 1;
+            return None;
+        }
+
+        self.tick += 1;
+        self.recency.insert(self.tick, key.clone());
+        self.stats.hits += 1;
+        let entry = self.entries.get_mut(key)?;
+        entry.used = self.tick;
+        Some(entry.response.clone())
+    }
+
+    pub(crate) fn insert(&mut self, key: RequestKey, response: RawResponse) {
+        let ttl = self.endpoint_ttl_of(&key);
+        if ttl.is_zero() || self.capacity == 0 {
+            return;
+        }
+        if let Some(previous) = self.entries.remove(&key) {
+            self.recency.remove(&previous.used);
+        }
+        while self.entries.len() >= self.capacity {
+            let Some((_, oldest)) = self.recency.pop_first() else { break };
+            self.entries.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+        self.tick += 1;
+        self.recency.insert(self.tick, key.clone());
+        self.entries.insert(key, Entry { response, expires: Instant::now() + ttl, used: self.tick });
+    }
+}
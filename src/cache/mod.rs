@@ -0,0 +1,99 @@
+pub mod disk;
+pub mod memory;
+
+use std::fmt;
+
+use crate::models::query_models::request_type::QueryRequestType;
+
+/// API endpoint a request is sent to, used to configure caching per endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Endpoint {
+    /// `works/{id}`.
+    Works,
+    /// `outputs/{id}`.
+    Outputs,
+    /// `journals/{id}`.
+    Journals,
+    /// `data-providers/{id}`.
+    DataProviders,
+    /// `discover`.
+    Discover,
+    /// Any of the `search/...` endpoints.
+    Search,
+}
+
+impl Endpoint {
+    /// Returns the endpoint of a request URI (relative to the API base URL).
+    pub(crate) fn from_uri(uri: &str) -> Option<Self> {
+        match uri.split('/').next()? {
+            "works" => Some(Endpoint::Works),
+            "outputs" => Some(Endpoint::Outputs),
+            "journals" => Some(Endpoint::Journals),
+            "data-providers" => Some(Endpoint::DataProviders),
+            "discover" => Some(Endpoint::Discover),
+            "search" => Some(Endpoint::Search),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Endpoint::Works => "works",
+            Endpoint::Outputs => "outputs",
+            Endpoint::Journals => "journals",
+            Endpoint::DataProviders => "data-providers",
+            Endpoint::Discover => "discover",
+            Endpoint::Search => "search",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A request as produced by `Query::parse_request`: the HTTP method, the URI relative to the API base URL
+/// and the body.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct RequestKey {
+    pub(crate) method: QueryRequestType,
+    pub(crate) uri: String,
+    pub(crate) body: Option<String>,
+}
+
+/// A raw API response: the JSON body and the remaining rate limit reported with it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RawResponse {
+    pub(crate) data: String,
+    pub(crate) rate_limit: Option<i32>,
+}
+
+/// Hit and miss counters of a cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    /// Requests answered from the cache.
+    pub hits: u64,
+
+    /// Requests not found in the cache (including expired entries).
+    pub misses: u64,
+
+    /// Entries found but discarded because their TTL had passed.
+    pub expired: u64,
+
+    /// Entries removed to make room for new ones.
+    pub evictions: u64,
+
+    /// Entries currently held.
+    pub entries: usize,
+}
+
+impl CacheStats {
+    /// Share of requests answered from the cache, `None` before the first request.
+    pub fn hit_ratio(&self) -> Option<f64> {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            None
+        } else {
+            Some(self.hits as f64 / total as f64)
+        }
+    }
+}
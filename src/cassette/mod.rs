@@ -0,0 +1,145 @@
+pub mod recorder;
+pub(crate) mod replayer;
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{cache::RequestKey, errors::Error};
+
+/// Value written in place of the API key in recorded `Authorization` headers.
+pub const REDACTED: &str = "Bearer [REDACTED]";
+
+/// Recorded request/response pairs, stored as a JSON Lines file with one interaction per line.
+///
+/// Cassettes are written by a `Recorder` while talking to the API and served back by `Api::replay`, which makes
+/// tests of code built on the client hermetic. Requests are matched on method, path, query parameters (in any
+/// order) and body (compared as JSON when possible).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Cassette {
+    /// Recorded interactions, in the order they were recorded.
+    pub interactions: Vec<Interaction>,
+}
+
+/// A request sent to the API and the response it received.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request.
+    pub request: RecordedRequest,
+
+    /// The response.
+    pub response: RecordedResponse,
+}
+
+/// A recorded request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// HTTP method (`GET` or `POST`).
+    pub method: String,
+
+    /// Request URI, relative to the API base URL.
+    pub uri: String,
+
+    /// Request headers, with the API key redacted.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+
+    /// Request body, if any.
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// HTTP status code.
+    pub status: u16,
+
+    /// Remaining rate limit reported with the response.
+    #[serde(default)]
+    pub rate_limit: Option<i32>,
+
+    /// Response body.
+    pub body: String,
+}
+
+impl Cassette {
+    /// Loads a cassette from a JSON Lines file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let mut interactions = vec![];
+        for line in fs::read_to_string(path)?.lines().filter(|line| !line.trim().is_empty()) {
+            interactions.push(serde_json::from_str(line)?);
+        }
+        Ok(Cassette { interactions })
+    }
+
+    /// Writes the cassette to a JSON Lines file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let mut file = BufWriter::new(File::create(path)?);
+        for interaction in self.interactions.iter() {
+            write_interaction(&mut file, interaction)?;
+        }
+        file.flush()?;
+        Ok(())
+    }
+}
+
+pub(crate) fn write_interaction<W: Write>(w: &mut W, interaction: &Interaction) -> Result<(), Error> {
+    serde_json::to_writer(&mut *w, interaction)?;
+    w.write_all(b"\n")?;
+    Ok(())
+}
+
+impl RecordedRequest {
+    pub(crate) fn from_key(key: &RequestKey) -> Self {
+        let mut headers = BTreeMap::new();
+        headers.insert("Authorization".to_string(), REDACTED.to_string());
+        RecordedRequest {
+            method: key.method.as_str().to_string(),
+            uri: key.uri.clone(),
+            headers,
+            body: key.body.clone(),
+        }
+    }
+
+    /// Checks whether a request matches this one on method, path, query parameters and body.
+    pub(crate) fn matches(&self, key: &RequestKey) -> bool {
+        let (path, query) = split_uri(&self.uri);
+        let (key_path, key_query) = split_uri(&key.uri);
+        self.method.eq_ignore_ascii_case(key.method.as_str())
+            && path.trim_end_matches('/') == key_path.trim_end_matches('/')
+            && query == key_query
+            && same_body(self.body.as_deref(), key.body.as_deref())
+    }
+}
+
+/// Splits a URI into its path and its sorted query parameters.
+fn split_uri(uri: &str) -> (&str, Vec<(&str, &str)>) {
+    let (path, query) = uri.split_once('?').unwrap_or((uri, ""));
+    let mut params: Vec<_> = query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| param.split_once('=').unwrap_or((param, "")))
+        .collect();
+    params.sort();
+    (path, params)
+}
+
+fn same_body(left: Option<&str>, right: Option<&str>) -> bool {
+    let left = left.map(str::trim).filter(|body| !body.is_empty());
+    let right = right.map(str::trim).filter(|body| !body.is_empty());
+    match (left, right) {
+        (Some(left), Some(right)) => {
+            match (serde_json::from_str::<serde_json::Value>(left), serde_json::from_str::<serde_json::Value>(right)) {
+                (Ok(left), Ok(right)) => left == right,
+                _ => left == right,
+            }
+        },
+        (left, right) => left == right,
+    }
+}
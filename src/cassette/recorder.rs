@@ -0,0 +1,56 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::Path,
+};
+
+use crate::{cache::RequestKey, errors::Error};
+
+use super::{write_interaction, Cassette, Interaction, RecordedRequest, RecordedResponse};
+
+/// Records the requests sent by an `Api` and their responses into a cassette file, enabled with `Api::record`.
+///
+/// Every interaction is appended to the file as one JSON line when it is recorded, so the cassette is complete
+/// even if the program stops early. The API key is never written: the `Authorization` header is stored as
+/// `Bearer [REDACTED]`.
+///
+/// ```no_run
+/// use core_api_client::{cassette::recorder::Recorder, Api};
+///
+/// let recorder = Recorder::create("tests/cassettes/get_work.jsonl").unwrap();
+/// let api = Api::from("API_KEY").record(recorder);
+/// let work = api.get_work(8972689);
+/// ```
+#[derive(Debug)]
+pub struct Recorder {
+    file: File,
+    cassette: Cassette,
+}
+
+impl Recorder {
+    /// Creates a new, empty cassette at `path`, replacing any existing file.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Ok(Recorder { file: File::create(path)?, cassette: Cassette::default() })
+    }
+
+    /// Opens the cassette at `path` to add interactions to it, creating it if it does not exist.
+    pub fn append<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let cassette = if path.exists() { Cassette::load(path)? } else { Cassette::default() };
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Recorder { file, cassette })
+    }
+
+    /// Returns the interactions recorded so far.
+    pub fn cassette(&self) -> &Cassette {
+        &self.cassette
+    }
+
+    pub(crate) fn record(&mut self, key: &RequestKey, response: RecordedResponse) -> Result<(), Error> {
+        let interaction = Interaction { request: RecordedRequest::from_key(key), response };
+        write_interaction(&mut self.file, &interaction)?;
+        self.file.flush()?;
+        self.cassette.interactions.push(interaction);
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use crate::cache::RequestKey;
+
+use super::{Cassette, RecordedResponse};
+
+/// Serves the responses of a cassette, enabled with `Api::replay`.
+///
+/// Matching interactions are served in the order they were recorded, so a request sent twice gets both
+/// recorded responses; once they are used up the last one is served again.
+#[derive(Debug, Clone)]
+pub(crate) struct Replayer {
+    cassette: Cassette,
+    used: Vec<bool>,
+}
+
+impl Replayer {
+    pub(crate) fn new(cassette: Cassette) -> Self {
+        let used = vec![false; cassette.interactions.len()];
+        Replayer { cassette, used }
+    }
+
+    pub(crate) fn replay(&mut self, key: &RequestKey) -> Option<RecordedResponse> {
+        let matching: Vec<usize> = self.cassette.interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| interaction.request.matches(key))
+            .map(|(i, _)| i)
+            .collect();
+        let index = matching.iter().copied().find(|&i| !self.used[i]).or(matching.last().copied())?;
+        self.used[index] = true;
+        Some(self.cassette.interactions[index].response.clone())
+    }
+}
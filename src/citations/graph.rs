@@ -0,0 +1,417 @@
+use std::{cmp::Ordering, collections::{HashMap, HashSet}, io::Write};
+
+use crate::{
+    errors::Error,
+    helpers::graph_writer::{write_dot, write_gexf, write_graphml, write_to_string, AttributeType, GraphData, GraphEdge, GraphNode},
+    Api, Doi, IdentifierValue, Reference, Work,
+};
+
+use super::resolver::ReferenceResolver;
+
+/// A work in the citation graph. Nodes of references that could not be resolved in CORE only carry
+/// the data of the reference itself.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CitationNode {
+    /// CORE id of the work.
+    pub id: Option<i32>,
+
+    /// DOI of the work.
+    pub doi: Option<Doi>,
+
+    /// Title of the work, or the raw citation string for unresolved references without a title.
+    pub title: Option<String>,
+
+    /// Publication year of the work.
+    pub year: Option<i32>,
+
+    /// Number of reference hops from the closest seed work (seeds have depth 0).
+    pub depth: usize,
+
+    /// Whether the node was resolved to a work in CORE.
+    pub resolved: bool,
+}
+
+impl CitationNode {
+    fn from_work(work: &Work, depth: usize) -> Self {
+        CitationNode { 
+            id: work.id, 
+            doi: work.doi().cloned(), 
+            title: work.title.clone(), 
+            year: work.publication_year(), 
+            depth, 
+            resolved: true,
+        }
+    }
+
+    fn from_reference(reference: &Reference, depth: usize) -> Self {
+        CitationNode { 
+            id: reference.id, 
+            doi: reference.doi.as_ref().and_then(IdentifierValue::valid).cloned(), 
+            title: reference.title.clone().or_else(|| reference.raw.clone()), 
+            year: reference.date.as_ref().and_then(|d| d.year()), 
+            depth, 
+            resolved: false,
+        }
+    }
+
+    /// Fills the missing data of the node with the data of another node describing the same work.
+    fn merge(&mut self, other: CitationNode) {
+        self.id = self.id.or(other.id);
+        self.doi = self.doi.take().or(other.doi);
+        if !self.resolved && other.resolved {
+            self.title = other.title;
+        } else {
+            self.title = self.title.take().or(other.title);
+        }
+        self.year = self.year.or(other.year);
+        self.depth = self.depth.min(other.depth);
+        self.resolved |= other.resolved;
+    }
+
+    fn label(&self) -> String {
+        self.title.clone()
+            .or_else(|| self.doi.as_ref().map(|doi| doi.to_string()))
+            .or_else(|| self.id.map(|id| id.to_string()))
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum NodeKey {
+    Id(i32),
+    Doi(Doi),
+}
+
+/// A directed citation graph. An edge `(source, target)` means that the work `source` cites the work `target`.
+/// Nodes are deduplicated by CORE id and DOI, so a work reached through several paths is stored once.
+///
+/// Graphs are built with a `CitationCrawler`, or manually with `add_work` and `add_reference`, and can be
+/// exported to GraphML, GEXF and DOT.
+///
+/// ```
+/// use core_api_client::{citations::graph::CitationGraph, Work};
+///
+/// let mut graph = CitationGraph::new();
+/// let work = Work { id: Some(1), title: Some("Seed".to_string()), ..Default::default() };
+/// let cited = Work { id: Some(2), title: Some("Cited".to_string()), ..Default::default() };
+/// let a = graph.add_work(&work, 0);
+/// let b = graph.add_work(&cited, 1);
+/// graph.add_citation(a, b);
+///
+/// assert_eq!(graph.edge_count(), 1);
+/// assert!(graph.to_dot().contains("\"n0\" -> \"n1\";"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CitationGraph {
+    nodes: Vec<CitationNode>,
+    edges: Vec<(usize, usize)>,
+    index: HashMap<NodeKey, usize>,
+}
+
+impl CitationGraph {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the nodes of the graph, edges refer to nodes by their position in this slice.
+    pub fn nodes(&self) -> &[CitationNode] {
+        &self.nodes
+    }
+
+    /// Returns the edges of the graph as `(citing, cited)` node indices.
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// Returns the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Finds the node of a work by its CORE id.
+    pub fn find_by_id(&self, id: i32) -> Option<usize> {
+        self.index.get(&NodeKey::Id(id)).copied()
+    }
+
+    /// Finds the node of a work by its DOI.
+    pub fn find_by_doi(&self, doi: &Doi) -> Option<usize> {
+        self.index.get(&NodeKey::Doi(doi.clone())).copied()
+    }
+
+    /// Adds a work at the given depth and returns its node index. If the work is already in the graph
+    /// the existing node is updated with any data it was missing. When the CORE id and the DOI of the work
+    /// belong to two different nodes, those are merged into the first one; the other node is removed and
+    /// the indices of the nodes after it shift down by one.
+    pub fn add_work(&mut self, work: &Work, depth: usize) -> usize {
+        self.add_node(CitationNode::from_work(work, depth))
+    }
+
+    /// Adds an unresolved reference at the given depth and returns its node index, or `None` if the 
+    /// reference has neither a CORE id nor a DOI to identify it by. Nodes are merged as in `add_work`.
+    pub fn add_reference(&mut self, reference: &Reference, depth: usize) -> Option<usize> {
+        if reference.id.is_none() && reference.doi.is_none() {
+            return None;
+        }
+        Some(self.add_node(CitationNode::from_reference(reference, depth)))
+    }
+
+    /// Adds an edge stating that node `citing` cites node `cited`. Duplicate edges and self citations are ignored.
+    pub fn add_citation(&mut self, citing: usize, cited: usize) {
+        if citing != cited && citing < self.nodes.len() && cited < self.nodes.len() && !self.edges.contains(&(citing, cited)) {
+            self.edges.push((citing, cited));
+        }
+    }
+
+    /// Returns the indices of the nodes cited by the given node.
+    pub fn references_of(&self, node: usize) -> Vec<usize> {
+        self.edges.iter().filter(|(s, _)| *s == node).map(|(_, t)| *t).collect()
+    }
+
+    /// Returns the indices of the nodes citing the given node.
+    pub fn citations_of(&self, node: usize) -> Vec<usize> {
+        self.edges.iter().filter(|(_, t)| *t == node).map(|(s, _)| *s).collect()
+    }
+
+    fn add_node(&mut self, node: CitationNode) -> usize {
+        let keys: Vec<NodeKey> = [node.id.map(NodeKey::Id), node.doi.clone().map(NodeKey::Doi)]
+            .into_iter()
+            .flatten()
+            .collect();
+
+        let mut positions: Vec<usize> = keys.iter().filter_map(|key| self.index.get(key).copied()).collect();
+        positions.sort_unstable();
+        positions.dedup();
+
+        let position = match positions.split_first() {
+            Some((&position, others)) => {
+                self.nodes[position].merge(node);
+                for other in others.iter().rev() {
+                    self.merge_nodes(position, *other);
+                }
+                position
+            },
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            },
+        };
+        for key in keys {
+            self.index.entry(key).or_insert(position);
+        }
+        position
+    }
+
+    /// Merges node `other` into node `keep` (with `keep < other`), re-pointing its edges and keys.
+    fn merge_nodes(&mut self, keep: usize, other: usize) {
+        let removed = self.nodes.remove(other);
+        self.nodes[keep].merge(removed);
+
+        let shift = |i: usize| match i.cmp(&other) {
+            Ordering::Less => i,
+            Ordering::Equal => keep,
+            Ordering::Greater => i - 1,
+        };
+        let mut seen = HashSet::new();
+        self.edges = self.edges.iter()
+            .map(|(s, t)| (shift(*s), shift(*t)))
+            .filter(|(s, t)| s != t && seen.insert((*s, *t)))
+            .collect();
+        for position in self.index.values_mut() {
+            *position = shift(*position);
+        }
+    }
+
+    /// Writes the graph in the [GraphML](http://graphml.graphdrawing.org/) format.
+    pub fn write_graphml<W: Write>(&self, writer: W) -> Result<(), Error> {
+        write_graphml(&self.graph_data(), writer)
+    }
+
+    /// Writes the graph in the [GEXF](https://gexf.net/) format.
+    pub fn write_gexf<W: Write>(&self, writer: W) -> Result<(), Error> {
+        write_gexf(&self.graph_data(), writer)
+    }
+
+    /// Writes the graph in the Graphviz [DOT](https://graphviz.org/doc/info/lang.html) format.
+    pub fn write_dot<W: Write>(&self, writer: W) -> Result<(), Error> {
+        write_dot(&self.graph_data(), writer)
+    }
+
+    /// Returns the graph in the GraphML format.
+    pub fn to_graphml(&self) -> String {
+        write_to_string(|buf| self.write_graphml(buf))
+    }
+
+    /// Returns the graph in the GEXF format.
+    pub fn to_gexf(&self) -> String {
+        write_to_string(|buf| self.write_gexf(buf))
+    }
+
+    /// Returns the graph in the DOT format.
+    pub fn to_dot(&self) -> String {
+        write_to_string(|buf| self.write_dot(buf))
+    }
+
+    fn graph_data(&self) -> GraphData {
+        GraphData {
+            name: "citations".to_string(),
+            directed: true,
+            node_attributes: vec![
+                ("core_id", AttributeType::Int),
+                ("doi", AttributeType::String),
+                ("year", AttributeType::Int),
+                ("depth", AttributeType::Int),
+                ("resolved", AttributeType::String),
+            ],
+            nodes: self.nodes.iter().enumerate().map(|(i, node)| GraphNode {
+                id: format!("n{}", i),
+                label: node.label(),
+                attributes: vec![
+                    node.id.map(|id| id.to_string()),
+                    node.doi.as_ref().map(|doi| doi.to_string()),
+                    node.year.map(|year| year.to_string()),
+                    Some(node.depth.to_string()),
+                    Some(node.resolved.to_string()),
+                ],
+            }).collect(),
+            edges: self.edges.iter().map(|(s, t)| GraphEdge {
+                source: format!("n{}", s),
+                target: format!("n{}", t),
+                weight: None,
+            }).collect(),
+        }
+    }
+}
+
+/// Builds a `CitationGraph` by following the references of seed works up to a configurable depth.
+///
+/// Each level of the crawl resolves the references of all works found on the previous level through a 
+/// shared `ReferenceResolver`, so lookups are batched and every work is requested at most once.
+///
+/// ```rust
+/// use core_api_client::{Api, Doi};
+/// use core_api_client::citations::graph::CitationCrawler;
+///
+/// let api = Api::from("API_KEY");
+/// let seeds = vec![Doi::parse("10.1016/0370-2693(96)00910-0").unwrap()];
+/// let graph = CitationCrawler::new(&api)
+///     .max_depth(2)
+///     .max_nodes(500)
+///     .crawl_dois(&seeds);
+///
+/// if let Ok(graph) = graph {
+///     std::fs::write("citations.graphml", graph.to_graphml()).unwrap();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CitationCrawler<'a> {
+    api: &'a Api,
+    resolver: ReferenceResolver<'a>,
+    max_depth: usize,
+    max_nodes: usize,
+    include_unresolved: bool,
+}
+
+impl<'a> CitationCrawler<'a> {
+    /// Creates a crawler with a depth of 1, at most 1000 nodes and unresolved references included as nodes.
+    pub fn new(api: &'a Api) -> Self {
+        CitationCrawler { 
+            api, 
+            resolver: ReferenceResolver::new(api), 
+            max_depth: 1, 
+            max_nodes: 1000, 
+            include_unresolved: true,
+        }
+    }
+
+    /// Uses the given resolver, e.g. to control batching and rate limiting or to reuse its cache.
+    pub fn resolver(self, resolver: ReferenceResolver<'a>) -> Self {
+        Self { resolver, ..self }
+    }
+
+    /// Sets how many reference hops are followed from the seed works.
+    pub fn max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    /// Sets the maximum number of nodes, the crawl stops expanding once the graph reaches it.
+    pub fn max_nodes(self, max_nodes: usize) -> Self {
+        Self { max_nodes, ..self }
+    }
+
+    /// Sets whether references that can not be resolved in CORE are added as (leaf) nodes.
+    pub fn include_unresolved(self, include_unresolved: bool) -> Self {
+        Self { include_unresolved, ..self }
+    }
+
+    /// Crawls the citation graph starting from works identified by DOI. DOIs unknown to CORE are 
+    /// added as unresolved seed nodes.
+    pub fn crawl_dois(&mut self, dois: &[Doi]) -> Result<CitationGraph, Error> {
+        let mut seeds = vec![];
+        let mut missing = vec![];
+        for doi in dois {
+            match self.api.find_work(doi)?.response {
+                Some(work) => seeds.push(work),
+                None => missing.push(doi.clone()),
+            }
+        }
+        let mut graph = self.crawl(seeds)?;
+        if self.include_unresolved {
+            for doi in missing {
+                graph.add_reference(&Reference { doi: Some(doi.into()), ..Default::default() }, 0);
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Crawls the citation graph starting from the given works.
+    pub fn crawl(&mut self, seeds: Vec<Work>) -> Result<CitationGraph, Error> {
+        let mut graph = CitationGraph::new();
+        for seed in seeds.iter() {
+            graph.add_work(seed, 0);
+        }
+        let mut frontier = seeds;
+
+        for depth in 1..=self.max_depth {
+            if frontier.is_empty() || graph.node_count() >= self.max_nodes {
+                break;
+            }
+            let references = frontier.iter()
+                .flat_map(|work| work.references.iter().flatten());
+            self.resolver.resolve_all(references)?;
+
+            let mut next = vec![];
+            'works: for work in frontier.iter() {
+                for reference in work.references.iter().flatten() {
+                    if graph.node_count() >= self.max_nodes {
+                        break 'works;
+                    }
+                    let known = graph.node_count();
+                    let cited = match self.resolver.cached(reference).flatten() {
+                        Some(cited) => {
+                            let node = graph.add_work(cited, depth);
+                            if node == known {
+                                next.push(cited.clone());
+                            }
+                            Some(node)
+                        },
+                        None if self.include_unresolved => graph.add_reference(reference, depth),
+                        None => None,
+                    };
+                    if let Some(cited) = cited {
+                        // adding the cited node may have merged nodes and shifted indices
+                        let citing = graph.add_work(work, depth - 1);
+                        graph.add_citation(citing, cited);
+                    }
+                }
+            }
+            frontier = next;
+        }
+        Ok(graph)
+    }
+}
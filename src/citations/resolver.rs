@@ -0,0 +1,258 @@
+use std::{collections::HashMap, thread, time::Duration};
+
+use crate::{
+    errors::Error,
+    models::{query_models::logical_operator::LogicalOperator, work_models::reference::Reference},
+    Api, Doi, SearchQuery, Work, WorkIdentifier,
+};
+
+/// Key under which a reference is looked up in CORE.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ReferenceKey {
+    Id(i32),
+    Doi(Doi),
+}
+
+impl ReferenceKey {
+    /// Keys of a reference in the order they are looked up: the CORE id first, then the DOI.
+    fn of(reference: &Reference) -> Vec<Self> {
+        let id = reference.id.map(ReferenceKey::Id);
+        let doi = reference.doi.as_ref()
+            .and_then(|doi| doi.valid())
+            .map(|doi| ReferenceKey::Doi(doi.clone()));
+        id.into_iter().chain(doi).collect()
+    }
+}
+
+/// Result of resolving the references of a work.
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedReferences {
+    /// The references in the order they were given, each with the work in CORE it points to, if it was found.
+    pub references: Vec<(Reference, Option<Work>)>,
+
+    /// Whether resolution stopped early because the rate limit reserve was reached. 
+    /// References that were not looked up have no work.
+    pub rate_limited: bool,
+
+    /// Remaining rate limit after the last request, if any request was made.
+    pub ratelimit_remaining: Option<i32>,
+}
+
+impl ResolvedReferences {
+    /// Returns the works in CORE the references point to, in the order of the references.
+    pub fn resolved(&self) -> Vec<&Work> {
+        self.references.iter().filter_map(|(_, work)| work.as_ref()).collect()
+    }
+
+    /// Returns the references that could not be found in CORE, given by their raw citation string
+    /// (or title or DOI, if the raw string is missing).
+    pub fn unresolved(&self) -> Vec<String> {
+        self.references.iter()
+            .filter(|(_, work)| work.is_none())
+            .map(|(reference, _)| unresolved_label(reference))
+            .collect()
+    }
+}
+
+/// Resolves the references of works into the works they point to in CORE.
+///
+/// References are looked up by their CORE id and, if they have none or the id is not found, by their DOI. 
+/// Lookups are batched into search requests of `batch_size` references and every result (including misses) is cached, so 
+/// resolving references of many works that cite the same papers only requests each paper once.
+/// When the remaining rate limit reported by the API drops to `rate_limit_reserve`, the resolver stops
+/// issuing requests and reports the remaining references as unresolved.
+///
+/// ```rust
+/// use core_api_client::Api;
+/// use core_api_client::citations::resolver::ReferenceResolver;
+/// use std::time::Duration;
+///
+/// let api = Api::from("API_KEY");
+/// let mut resolver = ReferenceResolver::new(&api)
+///     .batch_size(20)
+///     .delay(Duration::from_millis(200));
+///
+/// if let Ok(work) = api.get_work(0) {
+///     let references = resolver.resolve(&work.response);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ReferenceResolver<'a> {
+    api: &'a Api,
+    batch_size: usize,
+    rate_limit_reserve: i32,
+    delay: Duration,
+    cache: HashMap<ReferenceKey, Option<Work>>,
+    ratelimit_remaining: Option<i32>,
+}
+
+impl<'a> ReferenceResolver<'a> {
+    /// Creates a resolver with a batch size of 25, no delay between requests and a rate limit reserve of 0.
+    pub fn new(api: &'a Api) -> Self {
+        ReferenceResolver { 
+            api, 
+            batch_size: 25, 
+            rate_limit_reserve: 0, 
+            delay: Duration::ZERO, 
+            cache: HashMap::new(),
+            ratelimit_remaining: None,
+        }
+    }
+
+    /// Sets the number of references looked up in a single request.
+    pub fn batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size: batch_size.max(1), ..self }
+    }
+
+    /// Sets the number of requests that should be left unused from the rate limit.
+    pub fn rate_limit_reserve(self, rate_limit_reserve: i32) -> Self {
+        Self { rate_limit_reserve, ..self }
+    }
+
+    /// Sets the delay between consecutive requests.
+    pub fn delay(self, delay: Duration) -> Self {
+        Self { delay, ..self }
+    }
+
+    /// Returns the cached work for a reference, `Some(None)` if the reference is known to be missing 
+    /// from CORE and `None` if it was not looked up yet.
+    pub fn cached(&self, reference: &Reference) -> Option<Option<&Work>> {
+        let keys = ReferenceKey::of(reference);
+        if keys.is_empty() {
+            return None;
+        }
+        for key in keys {
+            match self.cache.get(&key)? {
+                Some(work) => return Some(Some(work)),
+                None => continue,
+            }
+        }
+        Some(None)
+    }
+
+    /// Resolves the references of a single work.
+    pub fn resolve(&mut self, work: &Work) -> Result<ResolvedReferences, Error> {
+        self.resolve_all(work.references.iter().flatten())
+    }
+
+    /// Resolves a list of references, which may come from several works.
+    pub fn resolve_all<'r, I>(&mut self, references: I) -> Result<ResolvedReferences, Error>
+    where
+        I: IntoIterator<Item = &'r Reference>,
+    {
+        let references: Vec<&Reference> = references.into_iter().collect();
+        let rate_limited = self.fetch_missing(&references)?;
+
+        let references = references.into_iter()
+            .map(|reference| (reference.clone(), self.cached(reference).flatten().cloned()))
+            .collect();
+        Ok(ResolvedReferences { references, rate_limited, ratelimit_remaining: self.ratelimit_remaining })
+    }
+
+    /// Looks up all references that are not cached yet, falling back to the DOI of references whose
+    /// id is not in CORE. Returns `true` if it stopped due to the rate limit.
+    fn fetch_missing(&mut self, references: &[&Reference]) -> Result<bool, Error> {
+        loop {
+            let mut missing: Vec<ReferenceKey> = vec![];
+            for reference in references {
+                let next = ReferenceKey::of(reference).into_iter()
+                    .find(|key| !matches!(self.cache.get(key), Some(None)))
+                    .filter(|key| !self.cache.contains_key(key));
+                if let Some(key) = next.filter(|key| !missing.contains(key)) {
+                    missing.push(key);
+                }
+            }
+            if missing.is_empty() {
+                return Ok(false);
+            }
+
+            let (ids, dois): (Vec<ReferenceKey>, Vec<ReferenceKey>) = missing
+                .into_iter()
+                .partition(|key| matches!(key, ReferenceKey::Id(_)));
+
+            for batch in ids.chunks(self.batch_size).chain(dois.chunks(self.batch_size)) {
+                if self.fetch_batch(batch)? {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+
+    /// Looks up a batch of keys, requesting further pages until every key is found or all results were seen.
+    /// Keys are only cached as missing once the results are exhausted. Returns `true` if it stopped due to
+    /// the rate limit.
+    fn fetch_batch(&mut self, batch: &[ReferenceKey]) -> Result<bool, Error> {
+        let expression = batch.iter()
+            .map(|key| match key {
+                ReferenceKey::Id(id) => format!("id:{}", id),
+                ReferenceKey::Doi(doi) => doi.search_expression(),
+            })
+            .collect::<Vec<_>>()
+            .join(&LogicalOperator::Or.parse());
+
+        let mut offset = 0;
+        loop {
+            if self.ratelimit_remaining.is_some_and(|r| r <= self.rate_limit_reserve) {
+                return Ok(true);
+            }
+            if self.ratelimit_remaining.is_some() && !self.delay.is_zero() {
+                thread::sleep(self.delay);
+            }
+
+            let query = SearchQuery::<String, String>::paged(batch.len() as i32, offset)
+                .and_raw(format!("({})", expression));
+            let resp = self.api.search_works(query)?;
+            self.ratelimit_remaining = resp.ratelimit_remaining.or(self.ratelimit_remaining);
+
+            let results = resp.response.results.unwrap_or_default();
+            offset += results.len() as i32;
+            let more = !results.is_empty() && resp.response.total_hits.is_some_and(|total| offset < total);
+            for work in results {
+                let keys = [
+                    work.id.map(ReferenceKey::Id),
+                    work.doi().cloned().map(ReferenceKey::Doi),
+                ];
+                for key in keys.into_iter().flatten() {
+                    if batch.contains(&key) {
+                        self.cache.entry(key).or_insert_with(|| Some(work.clone()));
+                    }
+                }
+            }
+
+            if !more || batch.iter().all(|key| self.cache.contains_key(key)) {
+                break;
+            }
+        }
+        for key in batch {
+            self.cache.entry(key.clone()).or_insert(None);
+        }
+        Ok(false)
+    }
+}
+
+fn unresolved_label(reference: &Reference) -> String {
+    reference.raw.clone()
+        .or_else(|| reference.title.clone())
+        .or_else(|| reference.doi.as_ref().map(|doi| doi.to_string()))
+        .unwrap_or_default()
+}
+
+impl Api {
+    /// Resolves the references of a work into the works they point to in CORE. References are looked up 
+    /// by CORE id, then DOI, in batches, see `ReferenceResolver` for control over batching, caching across
+    /// works and rate limiting.
+    ///
+    /// ```rust
+    /// use core_api_client::Api;
+    ///
+    /// let api = Api::from("API_KEY");
+    /// if let Ok(work) = api.get_work(0) {
+    ///     if let Ok(references) = api.resolve_references(&work.response) {
+    ///         println!("{} resolved, {} unresolved", references.resolved().len(), references.unresolved().len());
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_references(&self, work: &Work) -> Result<ResolvedReferences, Error> {
+        ReferenceResolver::new(self).resolve(work)
+    }
+}
@@ -0,0 +1,349 @@
+use std::{collections::{BTreeMap, HashMap}, fmt};
+
+use crate::{authors::name::{fold_diacritics, PersonName}, ArxivId, Doi, Work};
+
+use super::merge::merge_works;
+
+/// Reason two records were considered the same work.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchReason {
+    /// Both records have the same DOI.
+    Doi(Doi),
+    /// Both records have the same CORE id.
+    CoreId(i32),
+    /// Both records have the same arXiv identifier (ignoring the version).
+    ArxivId(ArxivId),
+    /// The records have a similar title, the same first author and close publication years.
+    SimilarTitle {
+        /// Similarity of the normalised titles, between 0 and 1.
+        similarity: f64,
+    },
+}
+
+impl fmt::Display for MatchReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatchReason::Doi(doi) => write!(f, "same DOI {}", doi),
+            MatchReason::CoreId(id) => write!(f, "same CORE id {}", id),
+            MatchReason::ArxivId(id) => write!(f, "same arXiv id {}", id),
+            MatchReason::SimilarTitle { similarity } => write!(f, "similar title ({:.2}), first author and year", similarity),
+        }
+    }
+}
+
+/// A match between two input records.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchedPair {
+    /// Position of the first record in the input.
+    pub first: usize,
+
+    /// Position of the second record in the input.
+    pub second: usize,
+
+    /// Why the records were matched.
+    pub reason: MatchReason,
+}
+
+/// A group of input records that were merged into a single work.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeGroup {
+    /// Positions of the merged records in the input, in ascending order.
+    pub members: Vec<usize>,
+
+    /// The matches that joined the records into the group.
+    pub matches: Vec<MatchedPair>,
+}
+
+/// Report of a deduplication run.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DedupReport {
+    /// Number of input records.
+    pub input_count: usize,
+
+    /// Number of records after merging.
+    pub output_count: usize,
+
+    /// Groups of two or more records that were merged, in the order of the resulting works.
+    pub groups: Vec<MergeGroup>,
+}
+
+/// Finds records describing the same work, e.g. when combining the results of several searches, and
+/// merges them with `merge_works`.
+///
+/// Records are matched by DOI, CORE id and arXiv id. Optionally (enabled by default), records without
+/// a matching identifier are also matched when their normalised titles are similar (character bigram
+/// Dice coefficient above the threshold), their first authors share a family name and initial, and
+/// their publication years are within the tolerance. Records without authors and records with different
+/// DOIs, CORE ids or arXiv ids are never matched by title, neither directly nor through other records.
+/// Matches are transitive.
+///
+/// ```
+/// use core_api_client::{dedup::deduplicator::Deduplicator, Author, Doi, Work};
+///
+/// let doi = Doi::parse("10.1000/xyz").unwrap();
+/// let works = vec![
+///     Work { id: Some(1), doi: Some(doi.clone().into()), ..Default::default() },
+///     Work { doi: Some(doi.into()), title: Some("Deep Learning".to_string()), ..Default::default() },
+///     Work {
+///         title: Some("Deep learning.".to_string()),
+///         year_published: Some(2015),
+///         authors: Some(vec![Author::from("LeCun, Yann")]),
+///         ..Default::default()
+///     },
+///     Work {
+///         title: Some("Deep Learning".to_string()),
+///         year_published: Some(2016),
+///         authors: Some(vec![Author::from("Y. LeCun")]),
+///         ..Default::default()
+///     },
+/// ];
+/// let (merged, report) = Deduplicator::new().dedup(works);
+///
+/// assert_eq!(merged.len(), 2);
+/// assert_eq!(report.groups[0].members, vec![0, 1]);
+/// assert_eq!(report.groups[1].members, vec![2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Deduplicator {
+    fuzzy: bool,
+    title_threshold: f64,
+    year_tolerance: i32,
+}
+
+impl Default for Deduplicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deduplicator {
+    /// Creates a deduplicator with fuzzy title matching, a title similarity threshold of 0.9 and a
+    /// publication year tolerance of 1 year.
+    pub fn new() -> Self {
+        Deduplicator { fuzzy: true, title_threshold: 0.9, year_tolerance: 1 }
+    }
+
+    /// Sets whether records are matched by title, first author and year.
+    pub fn fuzzy(self, fuzzy: bool) -> Self {
+        Self { fuzzy, ..self }
+    }
+
+    /// Sets the minimal title similarity (between 0 and 1) for fuzzy matches.
+    pub fn title_threshold(self, title_threshold: f64) -> Self {
+        Self { title_threshold, ..self }
+    }
+
+    /// Sets the maximal difference of publication years for fuzzy matches.
+    pub fn year_tolerance(self, year_tolerance: i32) -> Self {
+        Self { year_tolerance, ..self }
+    }
+
+    /// Finds the groups of records describing the same work. Every input record is in exactly one group,
+    /// groups are ordered by their first member.
+    pub fn group(&self, works: &[Work]) -> Vec<MergeGroup> {
+        let mut sets = DisjointSets::new(works.len());
+        let mut matches: Vec<MatchedPair> = vec![];
+
+        let mut by_doi: HashMap<&Doi, usize> = HashMap::new();
+        let mut by_id: HashMap<i32, usize> = HashMap::new();
+        let mut by_arxiv: HashMap<&str, usize> = HashMap::new();
+        for (i, work) in works.iter().enumerate() {
+            if let Some(doi) = work.doi() {
+                match by_doi.get(doi) {
+                    Some(first) => record(&mut sets, &mut matches, *first, i, MatchReason::Doi(doi.clone())),
+                    None => { by_doi.insert(doi, i); },
+                }
+            }
+            if let Some(id) = work.id {
+                match by_id.get(&id) {
+                    Some(first) => record(&mut sets, &mut matches, *first, i, MatchReason::CoreId(id)),
+                    None => { by_id.insert(id, i); },
+                }
+            }
+            if let Some(arxiv) = work.arxiv_id() {
+                match by_arxiv.get(arxiv.without_version()) {
+                    Some(first) => record(&mut sets, &mut matches, *first, i, MatchReason::ArxivId(arxiv.clone())),
+                    None => { by_arxiv.insert(arxiv.without_version(), i); },
+                }
+            }
+        }
+
+        if self.fuzzy {
+            self.match_titles(works, &mut sets, &mut matches);
+        }
+
+        let mut groups: BTreeMap<usize, MergeGroup> = BTreeMap::new();
+        for i in 0..works.len() {
+            let root = sets.find(i);
+            groups.entry(root).or_insert_with(|| MergeGroup { members: vec![], matches: vec![] }).members.push(i);
+        }
+        for pair in matches {
+            let root = sets.find(pair.first);
+            if let Some(group) = groups.get_mut(&root) {
+                group.matches.push(pair);
+            }
+        }
+        let mut groups: Vec<MergeGroup> = groups.into_values().collect();
+        groups.sort_by_key(|group| group.members[0]);
+        groups
+    }
+
+    /// Deduplicates the records, returning the merged works (in the order of their first record) and
+    /// a report of the merges.
+    pub fn dedup(&self, works: Vec<Work>) -> (Vec<Work>, DedupReport) {
+        let groups = self.group(&works);
+        let mut slots: Vec<Option<Work>> = works.into_iter().map(Some).collect();
+
+        let merged: Vec<Work> = groups.iter()
+            .map(|group| merge_works(group.members.iter().filter_map(|i| slots[*i].take()).collect()))
+            .collect();
+        let report = DedupReport {
+            input_count: slots.len(),
+            output_count: merged.len(),
+            groups: groups.into_iter().filter(|group| group.members.len() > 1).collect(),
+        };
+        (merged, report)
+    }
+
+    fn match_titles(&self, works: &[Work], sets: &mut DisjointSets, matches: &mut Vec<MatchedPair>) {
+        // block by first author, so titles are only compared between plausible candidates
+        let mut blocks: HashMap<String, Vec<(usize, String, Option<i32>)>> = HashMap::new();
+        for (i, work) in works.iter().enumerate() {
+            let title = match work.title.as_deref().map(normalize_title) {
+                Some(title) if !title.is_empty() => title,
+                _ => continue,
+            };
+            let author = work.authors.iter()
+                .flatten()
+                .next()
+                .map(|author| PersonName::parse(&author.name).initial_key())
+                .filter(|author| !author.is_empty());
+            // without a first author a title alone is too weak to match on
+            let Some(author) = author else { continue };
+            blocks.entry(author).or_default().push((i, title, work.publication_year()));
+        }
+
+        // members of every group so far, so a title match is only taken when no two records of the
+        // two groups conflict; otherwise records with different DOIs could merge through a third one
+        let mut members: HashMap<usize, Vec<usize>> = HashMap::new();
+        for i in 0..works.len() {
+            members.entry(sets.find(i)).or_default().push(i);
+        }
+
+        let mut blocks: Vec<_> = blocks.into_values().collect();
+        blocks.sort_by_key(|block| block[0].0);
+        for block in blocks {
+            for (a, (i, title_i, year_i)) in block.iter().enumerate() {
+                for (j, title_j, year_j) in block.iter().skip(a + 1) {
+                    let (root_i, root_j) = (sets.find(*i), sets.find(*j));
+                    if root_i == root_j {
+                        continue;
+                    }
+                    let conflicting = members[&root_i].iter()
+                        .flat_map(|x| members[&root_j].iter().map(move |y| (x, y)))
+                        .any(|(x, y)| identifiers_conflict(&works[*x], &works[*y]));
+                    if conflicting {
+                        continue;
+                    }
+                    let years_close = match (year_i, year_j) {
+                        (Some(x), Some(y)) => (x - y).abs() <= self.year_tolerance,
+                        _ => true,
+                    };
+                    if !years_close {
+                        continue;
+                    }
+                    let similarity = title_similarity(title_i, title_j);
+                    if similarity >= self.title_threshold {
+                        record(sets, matches, *i, *j, MatchReason::SimilarTitle { similarity });
+                        let (keep, merged) = (root_i.min(root_j), root_i.max(root_j));
+                        let moved = members.remove(&merged).unwrap_or_default();
+                        members.entry(keep).or_default().extend(moved);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checks whether two records have different DOIs, CORE ids or arXiv ids, so they can not be the same work.
+fn identifiers_conflict(a: &Work, b: &Work) -> bool {
+    fn differ<T: PartialEq>(a: Option<T>, b: Option<T>) -> bool {
+        matches!((a, b), (Some(a), Some(b)) if a != b)
+    }
+    differ(a.doi(), b.doi())
+        || differ(a.id, b.id)
+        || differ(a.arxiv_id().map(ArxivId::without_version), b.arxiv_id().map(ArxivId::without_version))
+}
+
+fn record(sets: &mut DisjointSets, matches: &mut Vec<MatchedPair>, first: usize, second: usize, reason: MatchReason) {
+    if sets.find(first) != sets.find(second) {
+        sets.union(first, second);
+        matches.push(MatchedPair { first, second, reason });
+    }
+}
+
+/// Lower cases the title, removes diacritics and punctuation and collapses whitespace.
+pub(crate) fn normalize_title(title: &str) -> String {
+    fold_diacritics(title)
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Dice coefficient of the character bigrams of two strings.
+pub(crate) fn title_similarity(a: &str, b: &str) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+    let bigrams = |s: &str| -> HashMap<(char, char), usize> {
+        let chars: Vec<char> = s.chars().collect();
+        let mut counts = HashMap::new();
+        for pair in chars.windows(2) {
+            *counts.entry((pair[0], pair[1])).or_insert(0) += 1;
+        }
+        counts
+    };
+    let (a, b) = (bigrams(a), bigrams(b));
+    let total: usize = a.values().sum::<usize>() + b.values().sum::<usize>();
+    if total == 0 {
+        return 0.0;
+    }
+    let shared: usize = a.iter().map(|(bigram, count)| (*count).min(*b.get(bigram).unwrap_or(&0))).sum();
+    2.0 * shared as f64 / total as f64
+}
+
+#[derive(Debug)]
+struct DisjointSets {
+    parent: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(size: usize) -> Self {
+        DisjointSets { parent: (0..size).collect() }
+    }
+
+    fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut current = i;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a != b {
+            self.parent[a.max(b)] = a.min(b);
+        }
+    }
+}
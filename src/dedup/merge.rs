@@ -0,0 +1,119 @@
+use crate::{ApiDate, Doi, Identifier, IdentifierKind, IdentifierValue, Work};
+
+/// Merges several records of the same work into a single record, keeping the richest value of every field.
+///
+/// * text fields (title, abstract, full text, publisher, ...) keep the longest value,
+/// * lists (identifiers, data providers, outputs, links, ...) are combined without duplicates,
+///   except authors and references, which keep the longest list to avoid duplicated name variants,
+/// * when the records have different DOIs, the first valid one is kept and the others are added to the identifiers,
+/// * dates keep the most precise parseable value, the citation count keeps the largest value,
+/// * remaining fields keep the first value present, in the order of the given records.
+///
+/// ```
+/// use core_api_client::{dedup::merge::merge_works, Work};
+///
+/// let a = Work { id: Some(1), title: Some("Short".to_string()), citation_count: Some(3), ..Default::default() };
+/// let b = Work { title: Some("A longer title".to_string()), citation_count: Some(5), ..Default::default() };
+/// let merged = merge_works(vec![a, b]);
+///
+/// assert_eq!(merged.id, Some(1));
+/// assert_eq!(merged.title.as_deref(), Some("A longer title"));
+/// assert_eq!(merged.citation_count, Some(5));
+/// ```
+pub fn merge_works(works: Vec<Work>) -> Work {
+    let mut works = works.into_iter();
+    let mut merged = works.next().unwrap_or_default();
+    for work in works {
+        merged = merge_pair(merged, work);
+    }
+    merged
+}
+
+fn merge_pair(a: Work, b: Work) -> Work {
+    let (doi, identifiers) = merge_doi(a.doi, b.doi, union(a.identifiers, b.identifiers));
+    Work {
+        accepted_date: richest_date(a.accepted_date, b.accepted_date),
+        arxiv_id: a.arxiv_id.or(b.arxiv_id),
+        authors: longest_list(a.authors, b.authors),
+        citation_count: a.citation_count.max(b.citation_count),
+        contributors: union(a.contributors, b.contributors),
+        outputs: union(a.outputs, b.outputs),
+        created_date: richest_date(a.created_date, b.created_date),
+        data_providers: union(a.data_providers, b.data_providers),
+        deposited_date: richest_date(a.deposited_date, b.deposited_date),
+        abstract_text: longest_text(a.abstract_text, b.abstract_text),
+        document_type: a.document_type.or(b.document_type),
+        doi,
+        download_url: a.download_url.or(b.download_url),
+        field_of_study: a.field_of_study.or(b.field_of_study),
+        full_text: longest_text(a.full_text, b.full_text),
+        id: a.id.or(b.id),
+        identifiers,
+        title: longest_text(a.title, b.title),
+        language: a.language.or(b.language),
+        mag_id: a.mag_id.or(b.mag_id),
+        oai_ids: union(a.oai_ids, b.oai_ids),
+        published_date: richest_date(a.published_date, b.published_date),
+        publisher: longest_text(a.publisher, b.publisher),
+        pubmed_id: a.pubmed_id.or(b.pubmed_id),
+        references: longest_list(a.references, b.references),
+        source_fulltext_urls: union(a.source_fulltext_urls, b.source_fulltext_urls),
+        journals: union(a.journals, b.journals),
+        updated_date: a.updated_date.max(b.updated_date),
+        year_published: a.year_published.or(b.year_published),
+        links: union(a.links, b.links),
+    }
+}
+
+fn merge_doi(
+    a: Option<IdentifierValue<Doi>>,
+    b: Option<IdentifierValue<Doi>>,
+    identifiers: Option<Vec<Identifier>>,
+) -> (Option<IdentifierValue<Doi>>, Option<Vec<Identifier>>) {
+    match (a, b) {
+        (Some(a), Some(b)) if a != b => {
+            let (kept, other) = if !a.is_valid() && b.is_valid() { (b, a) } else { (a, b) };
+            let other = Identifier { identifier: other.as_str().to_string(), identifier_type: IdentifierKind::Doi };
+            (Some(kept), union(identifiers, Some(vec![other])))
+        },
+        (a, b) => (a.or(b), identifiers),
+    }
+}
+
+fn longest_text(a: Option<String>, b: Option<String>) -> Option<String> {
+    match (a, b) {
+        (Some(a), Some(b)) if b.trim().chars().count() > a.trim().chars().count() => Some(b),
+        (Some(a), _) => Some(a),
+        (None, b) => b,
+    }
+}
+
+fn longest_list<T>(a: Option<Vec<T>>, b: Option<Vec<T>>) -> Option<Vec<T>> {
+    match (a, b) {
+        (Some(a), Some(b)) if b.len() > a.len() => Some(b),
+        (Some(a), _) => Some(a),
+        (None, b) => b,
+    }
+}
+
+fn union<T: PartialEq>(a: Option<Vec<T>>, b: Option<Vec<T>>) -> Option<Vec<T>> {
+    match (a, b) {
+        (Some(mut a), Some(b)) => {
+            for item in b {
+                if !a.contains(&item) {
+                    a.push(item);
+                }
+            }
+            Some(a)
+        },
+        (a, b) => a.or(b),
+    }
+}
+
+fn richest_date(a: Option<ApiDate>, b: Option<ApiDate>) -> Option<ApiDate> {
+    match (a, b) {
+        (Some(a), Some(b)) if (b.is_parsed(), b.precision) > (a.is_parsed(), a.precision) => Some(b),
+        (Some(a), _) => Some(a),
+        (None, b) => b,
+    }
+}
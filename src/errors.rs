@@ -3,11 +3,48 @@ pub enum Error {
     InvalidApiKey,
     Request(reqwest::Error),
     Parsing(String),
-
+    InvalidIdentifier(String),
+    Io(std::io::Error),
+    NotCached(String),
+    NotRecorded(String),
+    OffsetLimit(i32),
+    #[cfg(feature = "arrow")]
+    Arrow(arrow::error::ArrowError),
+    #[cfg(feature = "arrow")]
+    Parquet(parquet::errors::ParquetError),
+    #[cfg(feature = "sqlite")]
+    Sqlite(rusqlite::Error),
 }
 
 impl From<serde_json::Error> for Error {
     fn from(err: serde_json::Error) -> Error {
         Error::Parsing(err.to_string())
     }
-}
\ No newline at end of file
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<arrow::error::ArrowError> for Error {
+    fn from(err: arrow::error::ArrowError) -> Error {
+        Error::Arrow(err)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<parquet::errors::ParquetError> for Error {
+    fn from(err: parquet::errors::ParquetError) -> Error {
+        Error::Parquet(err)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for Error {
+    fn from(err: rusqlite::Error) -> Error {
+        Error::Sqlite(err)
+    }
+}
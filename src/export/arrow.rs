@@ -0,0 +1,375 @@
+use std::{io::Write, sync::Arc};
+
+use arrow::{
+    array::{
+        ArrayRef, Date32Array, Float32Array, Int32Array, Int64Array, ListArray, ListBuilder, StringArray,
+        StringBuilder, StructArray, TimestampMillisecondArray,
+    },
+    buffer::{NullBuffer, OffsetBuffer},
+    datatypes::{DataType, Field, Fields, Schema, SchemaRef, TimeUnit},
+    record_batch::RecordBatch,
+};
+use chrono::NaiveDate;
+use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
+
+use crate::{errors::Error, ApiDate, DataProvider, Journal, Work};
+
+/// Records that can be converted into Arrow record batches with a fixed schema.
+pub trait ArrowRecord: Sized {
+    /// Schema of the record batches.
+    fn arrow_schema() -> SchemaRef;
+
+    /// Converts records into a record batch with the schema of `arrow_schema`.
+    fn to_record_batch(records: &[Self]) -> Result<RecordBatch, Error>;
+}
+
+impl ArrowRecord for Work {
+    /// Columns use the API field names. Authors, identifiers, data providers and links are lists of
+    /// structs, journals and OAI ids lists of strings. Publication, acceptance and deposit dates are
+    /// `Date32`, creation and update dates millisecond timestamps; unparseable dates are null.
+    fn arrow_schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, true),
+            Field::new("title", DataType::Utf8, true),
+            Field::new("doi", DataType::Utf8, true),
+            Field::new("arxivId", DataType::Utf8, true),
+            Field::new("pubmedId", DataType::Utf8, true),
+            Field::new("magId", DataType::Utf8, true),
+            Field::new("oaiIds", utf8_list(), true),
+            Field::new("authors", struct_list(author_fields()), true),
+            Field::new("identifiers", struct_list(identifier_fields()), true),
+            Field::new("dataProviders", struct_list(data_provider_fields()), true),
+            Field::new("journals", utf8_list(), true),
+            Field::new("publisher", DataType::Utf8, true),
+            Field::new("yearPublished", DataType::Int32, true),
+            Field::new("publishedDate", DataType::Date32, true),
+            Field::new("acceptedDate", DataType::Date32, true),
+            Field::new("depositedDate", DataType::Date32, true),
+            Field::new("createdDate", timestamp(), true),
+            Field::new("updatedDate", timestamp(), true),
+            Field::new("documentType", DataType::Utf8, true),
+            Field::new("fieldOfStudy", DataType::Utf8, true),
+            Field::new("language", DataType::Utf8, true),
+            Field::new("citationCount", DataType::Int32, true),
+            Field::new("abstract", DataType::Utf8, true),
+            Field::new("downloadUrl", DataType::Utf8, true),
+            Field::new("links", struct_list(link_fields()), true),
+            Field::new("fullText", DataType::Utf8, true),
+        ]))
+    }
+
+    fn to_record_batch(works: &[Self]) -> Result<RecordBatch, Error> {
+        let authors = works.iter().map(|work| work.authors.as_ref().map(|authors| authors.iter()
+            .map(|author| vec![Some(author.name.clone()), author.orcid.as_ref().map(|orcid| orcid.to_string())])
+            .collect()));
+        let identifiers = works.iter().map(|work| work.identifiers.as_ref().map(|ids| ids.iter()
+            .map(|id| vec![Some(id.identifier_type.as_str().to_string()), Some(id.identifier.clone())])
+            .collect()));
+        let links = works.iter().map(|work| work.links.as_ref().map(|links| links.iter()
+            .map(|link| vec![link.kind().map(|kind| kind.as_str().to_string()), Some(link.url().to_string())])
+            .collect()));
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from_iter(works.iter().map(|work| work.id))),
+            strings(works.iter().map(|work| work.title.clone())),
+            strings(works.iter().map(|work| work.doi.as_ref().map(|doi| doi.to_string()))),
+            strings(works.iter().map(|work| work.arxiv_id.as_ref().map(|id| id.to_string()))),
+            strings(works.iter().map(|work| work.pubmed_id.as_ref().map(|id| id.to_string()))),
+            strings(works.iter().map(|work| work.mag_id.clone())),
+            string_lists(works.iter().map(|work| work.oai_ids.as_ref().map(|ids| ids.iter().map(|id| id.to_string()).collect()))),
+            string_struct_list(author_fields(), authors)?,
+            string_struct_list(identifier_fields(), identifiers)?,
+            data_provider_list(works)?,
+            string_lists(works.iter().map(|work| work.journals.as_ref().map(|journals| journals.iter().filter_map(|j| j.title.clone()).collect()))),
+            strings(works.iter().map(|work| work.publisher.clone())),
+            Arc::new(Int32Array::from_iter(works.iter().map(|work| work.publication_year()))),
+            dates(works.iter().map(|work| work.published_date.as_ref())),
+            dates(works.iter().map(|work| work.accepted_date.as_ref())),
+            dates(works.iter().map(|work| work.deposited_date.as_ref())),
+            timestamps(works.iter().map(|work| work.created_date.as_ref())),
+            timestamps(works.iter().map(|work| work.updated_date.as_ref())),
+            strings(works.iter().map(|work| work.document_type.as_ref().map(|kind| kind.as_str().to_string()))),
+            strings(works.iter().map(|work| work.field_of_study.as_ref().map(|field| field.as_str().to_string()))),
+            strings(works.iter().map(|work| work.language.as_ref().and_then(|language| language.code.clone()))),
+            Arc::new(Int32Array::from_iter(works.iter().map(|work| work.citation_count))),
+            strings(works.iter().map(|work| work.abstract_text.clone())),
+            strings(works.iter().map(|work| work.download_url.clone())),
+            string_struct_list(link_fields(), links)?,
+            strings(works.iter().map(|work| work.full_text.clone())),
+        ];
+        Ok(RecordBatch::try_new(Self::arrow_schema(), columns)?)
+    }
+}
+
+impl ArrowRecord for DataProvider {
+    /// Columns use the API field names, with the location and statistics flattened into separate columns.
+    fn arrow_schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("openDoarId", DataType::Int32, true),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("email", DataType::Utf8, true),
+            Field::new("uri", DataType::Utf8, true),
+            Field::new("oaiPmhUrl", DataType::Utf8, true),
+            Field::new("homepageUrl", DataType::Utf8, true),
+            Field::new("source", DataType::Utf8, true),
+            Field::new("software", DataType::Utf8, true),
+            Field::new("metadataFormat", DataType::Utf8, true),
+            Field::new("createdDate", timestamp(), true),
+            Field::new("countryCode", DataType::Utf8, true),
+            Field::new("latitude", DataType::Float32, true),
+            Field::new("longitude", DataType::Float32, true),
+            Field::new("type", DataType::Utf8, true),
+            Field::new("countMetadata", DataType::Int64, true),
+            Field::new("countFulltext", DataType::Int64, true),
+            Field::new("countDoi", DataType::Int64, true),
+            Field::new("lastHarvestingDate", timestamp(), true),
+            Field::new("rorId", DataType::Utf8, true),
+            Field::new("institutionName", DataType::Utf8, true),
+            Field::new("aliases", utf8_list(), true),
+        ]))
+    }
+
+    fn to_record_batch(providers: &[Self]) -> Result<RecordBatch, Error> {
+        let location = |p: &DataProvider| p.location.clone().unwrap_or_default();
+        let stats = |p: &DataProvider| p.stats.clone().unwrap_or_default();
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from_iter_values(providers.iter().map(|p| p.id))),
+            Arc::new(Int32Array::from_iter(providers.iter().map(|p| p.open_doar_id))),
+            strings(providers.iter().map(|p| p.name.clone())),
+            strings(providers.iter().map(|p| p.email.clone())),
+            strings(providers.iter().map(|p| p.uri.clone())),
+            strings(providers.iter().map(|p| p.oai_pmh_url.clone())),
+            strings(providers.iter().map(|p| p.homepage_url.clone())),
+            strings(providers.iter().map(|p| p.source.clone())),
+            strings(providers.iter().map(|p| p.software.as_ref().map(|s| s.as_str().to_string()))),
+            strings(providers.iter().map(|p| p.metadata_format.clone())),
+            timestamps(providers.iter().map(|p| p.created_date.as_ref())),
+            strings(providers.iter().map(|p| location(p).country_code)),
+            Arc::new(Float32Array::from_iter(providers.iter().map(|p| location(p).latitude))),
+            Arc::new(Float32Array::from_iter(providers.iter().map(|p| location(p).longitude))),
+            strings(providers.iter().map(|p| p.data_provider_type.as_ref().map(|t| t.as_str().to_string()))),
+            Arc::new(Int64Array::from_iter(providers.iter().map(|p| stats(p).count_metadata))),
+            Arc::new(Int64Array::from_iter(providers.iter().map(|p| stats(p).count_fulltext))),
+            Arc::new(Int64Array::from_iter(providers.iter().map(|p| stats(p).count_doi))),
+            timestamps(providers.iter().map(|p| p.stats.as_ref().and_then(|s| s.last_harvesting_date.as_ref()))),
+            strings(providers.iter().map(|p| p.ror_id.clone())),
+            strings(providers.iter().map(|p| p.institution_name.clone())),
+            string_lists(providers.iter().map(|p| p.aliases.clone())),
+        ];
+        Ok(RecordBatch::try_new(Self::arrow_schema(), columns)?)
+    }
+}
+
+impl ArrowRecord for Journal {
+    /// Columns use the API field names, with the ISSNs extracted from the identifiers into their own list.
+    fn arrow_schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("title", DataType::Utf8, false),
+            Field::new("identifiers", utf8_list(), false),
+            Field::new("issns", utf8_list(), false),
+            Field::new("language", DataType::Utf8, false),
+            Field::new("publisher", DataType::Utf8, false),
+            Field::new("subjects", utf8_list(), false),
+            Field::new("dataProviderId", DataType::Int32, false),
+        ]))
+    }
+
+    fn to_record_batch(journals: &[Self]) -> Result<RecordBatch, Error> {
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from_iter_values(journals.iter().map(|j| j.title.clone()))),
+            string_lists(journals.iter().map(|j| Some(j.identifiers.clone()))),
+            string_lists(journals.iter().map(|j| Some(j.issns().iter().map(|issn| issn.to_string()).collect()))),
+            Arc::new(StringArray::from_iter_values(journals.iter().map(|j| j.language.clone()))),
+            Arc::new(StringArray::from_iter_values(journals.iter().map(|j| j.publisher.clone()))),
+            string_lists(journals.iter().map(|j| Some(j.subjects.clone()))),
+            Arc::new(Int32Array::from_iter_values(journals.iter().map(|j| j.data_provider_id))),
+        ];
+        Ok(RecordBatch::try_new(Self::arrow_schema(), columns)?)
+    }
+}
+
+/// Streams records into a Parquet file, one row group per `row_group_size` records (10 000 by default),
+/// compressed with Snappy. Records are buffered until a row group is complete, so at most one row group
+/// is held in memory.
+///
+/// ```
+/// use core_api_client::{export::arrow::ParquetWriter, Work};
+///
+/// let works = (0..5).map(|id| Ok(Work { id: Some(id), ..Default::default() }));
+/// let mut writer = ParquetWriter::<_, Work>::new(vec![]).row_group_size(2);
+/// assert_eq!(writer.write_all(works).unwrap(), 5);
+///
+/// let file = writer.finish().unwrap();
+/// assert_eq!(&file[..4], b"PAR1");
+/// ```
+pub struct ParquetWriter<W: Write + Send, T: ArrowRecord> {
+    sink: Option<W>,
+    writer: Option<ArrowWriter<W>>,
+    buffer: Vec<T>,
+    row_group_size: usize,
+}
+
+impl<W: Write + Send, T: ArrowRecord> ParquetWriter<W, T> {
+    /// Creates a writer writing to the given sink.
+    pub fn new(sink: W) -> Self {
+        ParquetWriter { sink: Some(sink), writer: None, buffer: vec![], row_group_size: 10_000 }
+    }
+
+    /// Sets the number of records per row group. Has no effect after the first row group was written.
+    pub fn row_group_size(self, row_group_size: usize) -> Self {
+        Self { row_group_size: row_group_size.max(1), ..self }
+    }
+
+    /// Adds a record, writing a row group when enough records are buffered.
+    pub fn write(&mut self, record: T) -> Result<(), Error> {
+        self.buffer.push(record);
+        if self.buffer.len() >= self.row_group_size {
+            self.flush_row_group()?;
+        }
+        Ok(())
+    }
+
+    /// Writes all records of an iterator (e.g. `Api::iter_works`), stopping at the first error. Returns the
+    /// number of written records.
+    pub fn write_all(&mut self, records: impl IntoIterator<Item = Result<T, Error>>) -> Result<usize, Error> {
+        let mut count = 0;
+        for record in records {
+            self.write(record?)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Writes the remaining records and the file footer and returns the sink.
+    pub fn finish(mut self) -> Result<W, Error> {
+        if !self.buffer.is_empty() {
+            self.flush_row_group()?;
+        }
+        let writer = self.writer()?;
+        let writer = writer.take().expect("writer was just created");
+        Ok(writer.into_inner()?)
+    }
+
+    fn flush_row_group(&mut self) -> Result<(), Error> {
+        let batch = T::to_record_batch(&self.buffer)?;
+        self.buffer.clear();
+        let writer = self.writer()?.as_mut().expect("writer was just created");
+        writer.write(&batch)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn writer(&mut self) -> Result<&mut Option<ArrowWriter<W>>, Error> {
+        if let Some(sink) = self.sink.take() {
+            let properties = WriterProperties::builder()
+                .set_max_row_group_size(self.row_group_size)
+                .set_compression(Compression::SNAPPY)
+                .build();
+            self.writer = Some(ArrowWriter::try_new(sink, T::arrow_schema(), Some(properties))?);
+        }
+        Ok(&mut self.writer)
+    }
+}
+
+fn timestamp() -> DataType {
+    DataType::Timestamp(TimeUnit::Millisecond, None)
+}
+
+fn utf8_list() -> DataType {
+    DataType::List(Arc::new(Field::new("item", DataType::Utf8, true)))
+}
+
+fn struct_list(fields: Fields) -> DataType {
+    DataType::List(Arc::new(Field::new("item", DataType::Struct(fields), true)))
+}
+
+fn author_fields() -> Fields {
+    Fields::from(vec![Field::new("name", DataType::Utf8, true), Field::new("orcid", DataType::Utf8, true)])
+}
+
+fn identifier_fields() -> Fields {
+    Fields::from(vec![Field::new("type", DataType::Utf8, true), Field::new("identifier", DataType::Utf8, true)])
+}
+
+fn data_provider_fields() -> Fields {
+    Fields::from(vec![Field::new("id", DataType::Int32, true), Field::new("name", DataType::Utf8, true)])
+}
+
+fn link_fields() -> Fields {
+    Fields::from(vec![Field::new("type", DataType::Utf8, true), Field::new("url", DataType::Utf8, true)])
+}
+
+fn strings(values: impl Iterator<Item = Option<String>>) -> ArrayRef {
+    Arc::new(StringArray::from_iter(values))
+}
+
+fn string_lists(values: impl Iterator<Item = Option<Vec<String>>>) -> ArrayRef {
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for value in values {
+        match value {
+            Some(items) => {
+                for item in items {
+                    builder.values().append_value(item);
+                }
+                builder.append(true);
+            },
+            None => builder.append(false),
+        }
+    }
+    Arc::new(builder.finish())
+}
+
+fn dates<'a>(values: impl Iterator<Item = Option<&'a ApiDate>>) -> ArrayRef {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid date");
+    Arc::new(Date32Array::from_iter(values.map(|date| {
+        date.and_then(|date| date.date()).map(|date| (date - epoch).num_days() as i32)
+    })))
+}
+
+fn timestamps<'a>(values: impl Iterator<Item = Option<&'a ApiDate>>) -> ArrayRef {
+    Arc::new(TimestampMillisecondArray::from_iter(values.map(|date| {
+        date.and_then(|date| date.value).map(|value| value.and_utc().timestamp_millis())
+    })))
+}
+
+/// Builds a list of structs from its flattened child columns, the offsets of the rows into them and
+/// the validity of the rows.
+fn list_of_structs(fields: Fields, children: Vec<ArrayRef>, offsets: Vec<i32>, validity: Vec<bool>) -> Result<ArrayRef, Error> {
+    let values = StructArray::try_new(fields.clone(), children, None)?;
+    let item = Arc::new(Field::new("item", DataType::Struct(fields), true));
+    let list = ListArray::try_new(item, OffsetBuffer::new(offsets.into()), Arc::new(values), Some(NullBuffer::from(validity)))?;
+    Ok(Arc::new(list))
+}
+
+/// Builds a list of structs with string fields, where every struct is given as its field values.
+fn string_struct_list(fields: Fields, rows: impl Iterator<Item = Option<Vec<Vec<Option<String>>>>>) -> Result<ArrayRef, Error> {
+    let mut columns: Vec<Vec<Option<String>>> = vec![vec![]; fields.len()];
+    let mut offsets = vec![0];
+    let mut validity = vec![];
+    for row in rows {
+        validity.push(row.is_some());
+        for item in row.into_iter().flatten() {
+            for (column, value) in columns.iter_mut().zip(item) {
+                column.push(value);
+            }
+        }
+        offsets.push(columns[0].len() as i32);
+    }
+    let children = columns.into_iter().map(|column| strings(column.into_iter())).collect();
+    list_of_structs(fields, children, offsets, validity)
+}
+
+fn data_provider_list(works: &[Work]) -> Result<ArrayRef, Error> {
+    let (mut ids, mut names, mut offsets, mut validity) = (vec![], vec![], vec![0], vec![]);
+    for work in works {
+        validity.push(work.data_providers.is_some());
+        for provider in work.data_providers.iter().flatten() {
+            ids.push(Some(provider.id));
+            names.push(Some(provider.name.clone()).filter(|name| !name.is_empty()));
+        }
+        offsets.push(ids.len() as i32);
+    }
+    let children: Vec<ArrayRef> = vec![Arc::new(Int32Array::from(ids)), strings(names.into_iter())];
+    list_of_structs(data_provider_fields(), children, offsets, validity)
+}
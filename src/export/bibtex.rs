@@ -0,0 +1,466 @@
+use std::{collections::HashMap, fmt, io::Write};
+
+use crate::{authors::name::{fold_diacritics, PersonName}, errors::Error, helpers::graph_writer::write_to_string, Author, DocumentType, IdentifierValue, JournalRef, Work};
+
+use super::{first_issn, journal_title};
+
+/// A single BibTeX entry, e.g. `@article{lecun2015deep, title = {Deep learning}, ...}`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BibtexEntry {
+    /// Entry type in lower case, e.g. `article`.
+    pub entry_type: String,
+
+    /// Citation key.
+    pub key: String,
+
+    /// Fields in output order, with lower case names and unescaped values.
+    pub fields: Vec<(String, String)>,
+}
+
+impl BibtexEntry {
+    /// Returns the (unescaped) value of a field.
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.iter()
+            .find(|(field, _)| field.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl fmt::Display for BibtexEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "@{}{{{},", self.entry_type, self.key)?;
+        for (name, value) in &self.fields {
+            let value = match name.as_str() {
+                "doi" | "url" => value.clone(),
+                _ => latex_escape(value),
+            };
+            writeln!(f, "  {} = {{{}}},", name, value)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl From<&Work> for BibtexEntry {
+    fn from(work: &Work) -> Self {
+        let journal = journal_title(work);
+        let entry_type = match (&work.document_type, &journal) {
+            (Some(DocumentType::Thesis), _) => "phdthesis",
+            (_, Some(_)) => "article",
+            _ => "misc",
+        };
+
+        let mut fields = vec![];
+        let mut push = |name: &str, value: Option<String>| {
+            if let Some(value) = value.filter(|v| !v.trim().is_empty()) {
+                fields.push((name.to_string(), value.trim().to_string()));
+            }
+        };
+        let authors: Vec<String> = work.authors.iter().flatten()
+            .map(|author| PersonName::parse(&author.name).to_string())
+            .filter(|name| !name.is_empty())
+            .collect();
+        push("author", Some(authors.join(" and ")));
+        push("title", work.title.clone());
+        push("journal", journal);
+        push("year", work.publication_year().map(|year| year.to_string()));
+        let publisher_field = if entry_type == "phdthesis" { "school" } else { "publisher" };
+        push(publisher_field, work.publisher.clone());
+        push("issn", first_issn(work).map(|issn| issn.to_string()));
+        push("doi", work.doi.as_ref().map(|doi| doi.to_string()));
+        push("url", work.download_url.clone());
+        push("abstract", work.abstract_text.clone());
+
+        BibtexEntry { entry_type: entry_type.to_string(), key: citation_key(work), fields }
+    }
+}
+
+impl From<&BibtexEntry> for Work {
+    fn from(entry: &BibtexEntry) -> Self {
+        let text = |name: &str| entry.field(name).map(|value| value.to_string());
+        let document_type = match entry.entry_type.as_str() {
+            "phdthesis" | "mastersthesis" => DocumentType::Thesis,
+            "article" | "inproceedings" | "conference" | "techreport" | "book" | "incollection" => DocumentType::Research,
+            _ => DocumentType::Unknown,
+        };
+        let journals = text("journal").map(|title| vec![JournalRef {
+            title: Some(title),
+            identifiers: text("issn").map(|issn| format!("issn:{}", issn)).into_iter().collect(),
+        }]);
+
+        Work {
+            authors: text("author").map(|authors| authors.split(" and ").map(|name| Author::from(name.trim())).collect()),
+            title: text("title"),
+            year_published: entry.field("year").and_then(|year| year.trim().parse().ok()),
+            journals,
+            publisher: text("publisher").or_else(|| text("school")),
+            doi: entry.field("doi").map(IdentifierValue::parse),
+            download_url: text("url"),
+            abstract_text: text("abstract"),
+            document_type: Some(document_type).filter(|kind| *kind != DocumentType::Unknown),
+            ..Default::default()
+        }
+    }
+}
+
+impl Work {
+    /// Formats the work as a BibTeX entry. The citation key is derived from the first author's family name,
+    /// the publication year and the first significant word of the title; use `write_bibtex` to export
+    /// several works with unique keys.
+    ///
+    /// ```
+    /// use core_api_client::{Author, Work};
+    ///
+    /// let work = Work {
+    ///     title: Some("Deep learning & you".to_string()),
+    ///     authors: Some(vec![Author::from("Yann LeCun")]),
+    ///     year_published: Some(2015),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     work.to_bibtex(),
+    ///     "@misc{lecun2015deep,\n  author = {LeCun, Yann},\n  title = {Deep learning \\& you},\n  year = {2015},\n}"
+    /// );
+    /// ```
+    pub fn to_bibtex(&self) -> String {
+        BibtexEntry::from(self).to_string()
+    }
+}
+
+/// Writes the works as BibTeX entries separated by blank lines. Colliding citation keys are made unique
+/// by appending `a`, `b`, ... in the order of the works.
+pub fn write_bibtex<'a, W: Write>(works: impl IntoIterator<Item = &'a Work>, writer: &mut W) -> Result<(), Error> {
+    let mut used: HashMap<String, usize> = HashMap::new();
+    for (i, work) in works.into_iter().enumerate() {
+        let mut entry = BibtexEntry::from(work);
+        let count = used.entry(entry.key.clone()).or_insert(0);
+        if *count > 0 {
+            entry.key = format!("{}{}", entry.key, key_suffix(*count));
+        }
+        *count += 1;
+        if i > 0 {
+            writeln!(writer)?;
+        }
+        writeln!(writer, "{}", entry)?;
+    }
+    Ok(())
+}
+
+/// Formats the works as BibTeX, see `write_bibtex`.
+pub fn to_bibtex_string<'a>(works: impl IntoIterator<Item = &'a Work>) -> String {
+    write_to_string(|buffer| write_bibtex(works, buffer))
+}
+
+/// Parses BibTeX entries. `@comment`, `@preamble` and `@string` blocks are skipped, `@string` macros
+/// are not expanded. Text outside of entries is ignored.
+///
+/// ```
+/// use core_api_client::export::bibtex::parse_bibtex;
+///
+/// let entries = parse_bibtex(r#"@Article{key1, title = {Caf{\'e} \& {B}ar}, year = 2020, journal = "Nature"}"#).unwrap();
+///
+/// assert_eq!(entries[0].entry_type, "article");
+/// assert_eq!(entries[0].field("title"), Some("Café & Bar"));
+/// assert_eq!(entries[0].field("year"), Some("2020"));
+/// assert_eq!(entries[0].field("journal"), Some("Nature"));
+/// ```
+pub fn parse_bibtex(input: &str) -> Result<Vec<BibtexEntry>, Error> {
+    let mut parser = Parser { chars: input.chars().collect(), pos: 0 };
+    let mut entries = vec![];
+    while parser.skip_to('@') {
+        parser.pos += 1;
+        let entry_type = parser.identifier().to_lowercase();
+        parser.skip_whitespace();
+        let close = match parser.next() {
+            Some('{') => '}',
+            Some('(') => ')',
+            _ => return Err(parser.error("expected '{' after entry type")),
+        };
+        if matches!(entry_type.as_str(), "comment" | "preamble" | "string") {
+            parser.pos -= 1;
+            parser.delimited()?;
+            continue;
+        }
+        parser.skip_whitespace();
+        let key = parser.until(&[',', close]).trim().to_string();
+        let mut fields = vec![];
+        loop {
+            parser.skip_whitespace();
+            match parser.next() {
+                Some(',') => {},
+                Some(c) if c == close => break,
+                _ => return Err(parser.error("expected ',' or end of entry")),
+            }
+            parser.skip_whitespace();
+            if parser.peek() == Some(close) {
+                parser.pos += 1;
+                break;
+            }
+            let name = parser.identifier().to_lowercase();
+            parser.skip_whitespace();
+            if name.is_empty() || parser.next() != Some('=') {
+                return Err(parser.error("expected field"));
+            }
+            let value = parser.value(close)?;
+            fields.push((name, value));
+        }
+        entries.push(BibtexEntry { entry_type, key, fields });
+    }
+    Ok(entries)
+}
+
+/// Derives a citation key like `lecun2015deep`, falling back to `core<id>` or `untitled`.
+pub(crate) fn citation_key(work: &Work) -> String {
+    let family = work.authors.iter().flatten()
+        .next()
+        .map(|author| key_part(&PersonName::parse(&author.name).family))
+        .unwrap_or_default();
+    let year = work.publication_year().map(|year| year.to_string()).unwrap_or_default();
+    let word = work.title.as_deref()
+        .and_then(|title| title.split_whitespace().map(key_part).find(|word| word.len() > 3 && !STOP_WORDS.contains(&word.as_str())))
+        .unwrap_or_default();
+    if family.is_empty() && word.is_empty() {
+        return work.id.map_or_else(|| "untitled".to_string(), |id| format!("core{}", id));
+    }
+    format!("{}{}{}", family, year, word)
+}
+
+const STOP_WORDS: [&str; 12] = ["about", "after", "among", "from", "into", "over", "their", "there", "these", "this", "with", "when"];
+
+fn key_part(value: &str) -> String {
+    fold_diacritics(value).to_lowercase().chars().filter(|c| c.is_ascii_alphanumeric()).collect()
+}
+
+fn key_suffix(mut count: usize) -> String {
+    let mut suffix = vec![];
+    loop {
+        suffix.push((b'a' + ((count - 1) % 26) as u8) as char);
+        count = (count - 1) / 26;
+        if count == 0 {
+            break;
+        }
+    }
+    suffix.iter().rev().collect()
+}
+
+/// Escapes LaTeX special characters.
+pub(crate) fn latex_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' | '}' | '&' | '%' | '$' | '#' | '_' => {
+                escaped.push('\\');
+                escaped.push(c);
+            },
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Reverts `latex_escape`, resolves common accent commands (e.g. `{\'e}`) and removes protecting braces.
+pub(crate) fn latex_unescape(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    let mut result = String::with_capacity(value.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '{' | '}' => i += 1,
+            '\\' if i + 1 < chars.len() => {
+                let c = chars[i + 1];
+                if c.is_ascii_alphabetic() {
+                    let command: String = chars[i + 1..].iter().take_while(|c| c.is_ascii_alphabetic()).collect();
+                    i += 1 + command.len();
+                    if chars.get(i) == Some(&'{') && chars.get(i + 1) == Some(&'}') {
+                        i += 2;
+                    }
+                    match command.as_str() {
+                        "textbackslash" => result.push('\\'),
+                        "textasciitilde" => result.push('~'),
+                        "textasciicircum" => result.push('^'),
+                        "ss" => result.push('ß'),
+                        "o" => result.push('ø'),
+                        "O" => result.push('Ø'),
+                        "aa" => result.push('å'),
+                        "AA" => result.push('Å'),
+                        "ae" => result.push('æ'),
+                        "l" => result.push('ł'),
+                        _ => {
+                            // accent commands taking a letter argument, e.g. `\c{c}` or `\v c`
+                            while chars.get(i) == Some(&' ') {
+                                i += 1;
+                            }
+                            if let Some(accented) = accent(&command, &chars, &mut i) {
+                                result.push_str(&accented);
+                            }
+                        },
+                    }
+                    continue;
+                }
+                i += 2;
+                match accent(&c.to_string(), &chars, &mut i) {
+                    Some(accented) => result.push_str(&accented),
+                    None => result.push(c),
+                }
+            },
+            c => {
+                result.push(c);
+                i += 1;
+            },
+        }
+    }
+    result.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Applies an accent command to the following letter (optionally in braces), advancing `i` past it.
+fn accent(command: &str, chars: &[char], i: &mut usize) -> Option<String> {
+    let mark = match command {
+        "'" => '\u{301}',
+        "`" => '\u{300}',
+        "^" => '\u{302}',
+        "\"" => '\u{308}',
+        "~" => '\u{303}',
+        "=" => '\u{304}',
+        "." => '\u{307}',
+        "u" => '\u{306}',
+        "v" => '\u{30C}',
+        "H" => '\u{30B}',
+        "c" => '\u{327}',
+        "k" => '\u{328}',
+        "r" => '\u{30A}',
+        _ => return None,
+    };
+    let braced = chars.get(*i) == Some(&'{');
+    let start = if braced { *i + 1 } else { *i };
+    let letter = match chars.get(start) {
+        Some('\\') if chars.get(start + 1) == Some(&'i') => {
+            *i = start + 2;
+            'i'
+        },
+        Some(c) if c.is_alphabetic() => {
+            *i = start + 1;
+            *c
+        },
+        _ => return Some(String::new()),
+    };
+    if braced && chars.get(*i) == Some(&'}') {
+        *i += 1;
+    }
+    use unicode_normalization::UnicodeNormalization;
+    Some([letter, mark].iter().collect::<String>().nfc().collect())
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::Parsing(format!("invalid BibTeX at character {}: {}", self.pos, message))
+    }
+
+    fn skip_to(&mut self, target: char) -> bool {
+        while let Some(c) = self.peek() {
+            if c == target {
+                return true;
+            }
+            self.pos += 1;
+        }
+        false
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn identifier(&mut self) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_alphanumeric() || "_-:.".contains(c)) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn until(&mut self, stops: &[char]) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| !stops.contains(&c)) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Reads a `{...}` block with nested braces, returning the content without the outer braces.
+    fn delimited(&mut self) -> Result<String, Error> {
+        let (open, close) = match self.next() {
+            Some('(') => ('(', ')'),
+            _ => ('{', '}'),
+        };
+        let start = self.pos;
+        let mut depth = 1;
+        while let Some(c) = self.next() {
+            match c {
+                '\\' => self.pos += 1,
+                c if c == open => depth += 1,
+                c if c == close => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(self.chars[start..self.pos - 1].iter().collect());
+                    }
+                },
+                _ => {},
+            }
+        }
+        Err(self.error("unbalanced braces"))
+    }
+
+    /// Reads a field value, which may be a braced or quoted string, a number or macro name, or a
+    /// concatenation of these with `#`.
+    fn value(&mut self, close: char) -> Result<String, Error> {
+        let mut value = String::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('{') => value.push_str(&self.delimited()?),
+                Some('"') => {
+                    self.pos += 1;
+                    let start = self.pos;
+                    let mut depth = 0;
+                    loop {
+                        match self.next() {
+                            Some('\\') => self.pos += 1,
+                            Some('{') => depth += 1,
+                            Some('}') => depth -= 1,
+                            Some('"') if depth == 0 => break,
+                            Some(_) => {},
+                            None => return Err(self.error("unterminated string")),
+                        }
+                    }
+                    value.extend(&self.chars[start..self.pos - 1]);
+                },
+                _ => value.push_str(self.until(&[',', '#', close]).trim()),
+            }
+            self.skip_whitespace();
+            if self.peek() == Some('#') {
+                self.pos += 1;
+            } else {
+                return Ok(latex_unescape(&value));
+            }
+        }
+    }
+}
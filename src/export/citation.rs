@@ -0,0 +1,425 @@
+use std::fmt;
+
+use crate::Work;
+
+use super::csl::{CslItem, CslName};
+
+/// Citation style of a formatted reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum CitationStyle {
+    /// APA, 7th edition.
+    #[default]
+    Apa,
+    /// MLA, 9th edition.
+    Mla,
+    /// Chicago, 17th edition (notes and bibliography).
+    Chicago,
+    /// IEEE.
+    Ieee,
+    /// Harvard (Cite Them Right).
+    Harvard,
+}
+
+/// Output format of a formatted reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum CitationFormat {
+    /// Plain text, without markup.
+    #[default]
+    Text,
+    /// HTML with escaped text, `<i>` for italics and `<a>` for links.
+    Html,
+}
+
+impl CslItem {
+    /// Formats the item as a bibliography entry.
+    ///
+    /// ```
+    /// use core_api_client::{export::citation::{CitationFormat, CitationStyle}, Author, IdentifierValue, JournalRef, Work};
+    ///
+    /// let work = Work {
+    ///     title: Some("Deep learning".to_string()),
+    ///     authors: Some(vec![Author::from("Yann LeCun"), Author::from("Yoshua Bengio"), Author::from("Geoffrey Hinton")]),
+    ///     journals: Some(vec![JournalRef { title: Some("Nature".to_string()), identifiers: vec![] }]),
+    ///     doi: Some(IdentifierValue::parse("10.1038/nature14539")),
+    ///     year_published: Some(2015),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     work.format_citation(CitationStyle::Apa, CitationFormat::Text),
+    ///     "LeCun, Y., Bengio, Y., & Hinton, G. (2015). Deep learning. Nature. https://doi.org/10.1038/nature14539"
+    /// );
+    /// assert_eq!(
+    ///     work.format_citation(CitationStyle::Ieee, CitationFormat::Html),
+    ///     "Y. LeCun, Y. Bengio, and G. Hinton, “Deep learning,” <i>Nature</i>, 2015, \
+    ///      doi: <a href=\"https://doi.org/10.1038/nature14539\">10.1038/nature14539</a>."
+    /// );
+    /// ```
+    pub fn format(&self, style: CitationStyle, format: CitationFormat) -> String {
+        let mut out = Output { format, text: String::new() };
+        match style {
+            CitationStyle::Apa => self.apa(&mut out),
+            CitationStyle::Mla => self.mla(&mut out),
+            CitationStyle::Chicago => self.chicago(&mut out),
+            CitationStyle::Ieee => self.ieee(&mut out),
+            CitationStyle::Harvard => self.harvard(&mut out),
+        }
+        out.text.trim().to_string()
+    }
+
+    fn year(&self) -> Option<i32> {
+        self.issued.as_ref().and_then(|date| date.year())
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref().map(str::trim).filter(|title| !title.is_empty())
+    }
+
+    fn doi_url(&self) -> Option<String> {
+        self.doi.as_ref().map(|doi| format!("https://doi.org/{}", doi))
+    }
+
+    fn link(&self) -> Option<String> {
+        self.doi_url().or_else(|| self.url.clone())
+    }
+
+    /// Title in quotes for items in a journal, in italics otherwise, with the given closing punctuation.
+    fn quoted_title(&self, out: &mut Output, open: &str, close: &str, punctuation: &str) {
+        if let Some(title) = self.title() {
+            if self.container_title.is_some() {
+                out.text(open);
+                out.text(&punctuate(title, punctuation));
+                out.text(close);
+            } else {
+                out.italic(title);
+                out.text(&punctuation_after(title, punctuation));
+            }
+        }
+    }
+
+    fn apa(&self, out: &mut Output) {
+        let authors = join_names(&self.author, 20, ", ", ", & ", ", & ", |name, _| inverted(name, true));
+        let year = self.year().map_or("n.d.".to_string(), |year| year.to_string());
+        match (authors.is_empty(), self.title()) {
+            (false, title) => {
+                out.text(&punctuate(&authors, "."));
+                out.text(&format!(" ({}). ", year));
+                if let Some(title) = title {
+                    self.apa_title(out, title);
+                }
+            },
+            (true, Some(title)) => {
+                self.apa_title(out, title);
+                out.text(&format!("({}). ", year));
+            },
+            (true, None) => out.text(&format!("({}). ", year)),
+        }
+        match (&self.container_title, &self.publisher) {
+            (Some(journal), _) => {
+                out.italic(journal);
+                out.text(". ");
+            },
+            (None, Some(publisher)) => out.text(&format!("{} ", punctuate(publisher, "."))),
+            _ => {},
+        }
+        if let Some(link) = self.link() {
+            out.link(&link, &link);
+        }
+    }
+
+    fn apa_title(&self, out: &mut Output, title: &str) {
+        if self.container_title.is_some() {
+            out.text(&punctuate(title, "."));
+        } else {
+            out.italic(title);
+            out.text(&punctuation_after(title, "."));
+        }
+        out.text(" ");
+    }
+
+    fn mla(&self, out: &mut Output) {
+        let authors = match self.author.len() {
+            0 => String::new(),
+            1 => inverted(&self.author[0], false),
+            2 => format!("{}, and {}", inverted(&self.author[0], false), direct(&self.author[1], false)),
+            _ => format!("{}, et al", inverted(&self.author[0], false)),
+        };
+        if !authors.is_empty() {
+            out.text(&format!("{} ", punctuate(&authors, ".")));
+        }
+        self.quoted_title(out, "“", "”", ".");
+        out.text(" ");
+        let mut parts = vec![];
+        if let Some(journal) = &self.container_title {
+            parts.push(Part::Italic(journal.clone()));
+        } else if let Some(publisher) = &self.publisher {
+            parts.push(Part::Text(publisher.clone()));
+        }
+        if let Some(year) = self.year() {
+            parts.push(Part::Text(year.to_string()));
+        }
+        if let Some(link) = self.link() {
+            parts.push(Part::Link(link));
+        }
+        out.parts(&parts, ", ");
+        if !parts.is_empty() {
+            out.text(".");
+        }
+    }
+
+    fn chicago(&self, out: &mut Output) {
+        let authors = match self.author.len() {
+            0 => String::new(),
+            n if n > 10 => format!("{}, et al", join_names(&self.author[..7], 7, ", ", ", ", "", chicago_name)),
+            _ => join_names(&self.author, 10, ", ", ", and ", ", and ", chicago_name),
+        };
+        if !authors.is_empty() {
+            out.text(&format!("{} ", punctuate(&authors, ".")));
+        }
+        self.quoted_title(out, "“", "”", ".");
+        out.text(" ");
+        match &self.container_title {
+            Some(journal) => {
+                out.italic(journal);
+                match self.year() {
+                    Some(year) => out.text(&format!(" ({}). ", year)),
+                    None => out.text(". "),
+                }
+            },
+            None => {
+                let place: Vec<String> = self.publisher.iter().cloned()
+                    .chain(Some(self.year().map_or("n.d.".to_string(), |year| year.to_string())))
+                    .collect();
+                out.text(&format!("{} ", punctuate(&place.join(", "), ".")));
+            },
+        }
+        if let Some(link) = self.link() {
+            out.link(&link, &link);
+            out.text(".");
+        }
+    }
+
+    fn ieee(&self, out: &mut Output) {
+        let authors = match self.author.len() {
+            n if n > 6 => format!("{} et al.", direct(&self.author[0], true)),
+            _ => join_names(&self.author, 6, ", ", ", and ", " and ", |name, _| direct(name, true)),
+        };
+        if !authors.is_empty() {
+            out.text(&format!("{}, ", authors));
+        }
+        self.quoted_title(out, "“", "”", ",");
+        out.text(" ");
+        let mut parts = vec![];
+        if let Some(journal) = &self.container_title {
+            parts.push(Part::Italic(journal.clone()));
+        } else if let Some(publisher) = &self.publisher {
+            parts.push(Part::Text(publisher.clone()));
+        }
+        if let Some(year) = self.year() {
+            parts.push(Part::Text(year.to_string()));
+        }
+        out.parts(&parts, ", ");
+        match (&self.doi, &self.url) {
+            (Some(doi), _) => {
+                out.text(if parts.is_empty() { "doi: " } else { ", doi: " });
+                out.link(&format!("https://doi.org/{}", doi), doi);
+            },
+            (None, Some(url)) => {
+                out.text(if parts.is_empty() { "[Online]. Available: " } else { ". [Online]. Available: " });
+                out.link(url, url);
+            },
+            _ => {},
+        }
+        out.text(".");
+    }
+
+    fn harvard(&self, out: &mut Output) {
+        let authors = match self.author.len() {
+            n if n > 3 => format!("{} et al.", inverted(&self.author[0], true)),
+            _ => join_names(&self.author, 3, ", ", " and ", " and ", |name, _| inverted(name, true)),
+        };
+        let year = self.year().map_or("no date".to_string(), |year| year.to_string());
+        if !authors.is_empty() {
+            out.text(&format!("{} ", authors));
+        }
+        out.text(&format!("({}) ", year));
+        if let Some(title) = self.title() {
+            if self.container_title.is_some() {
+                out.text(&format!("‘{}’, ", title));
+            } else {
+                out.italic(title);
+                out.text(&punctuation_after(title, "."));
+                out.text(" ");
+            }
+        }
+        match (&self.container_title, &self.publisher) {
+            (Some(journal), _) => {
+                out.italic(journal);
+                out.text(". ");
+            },
+            (None, Some(publisher)) => out.text(&format!("{} ", punctuate(publisher, "."))),
+            _ => {},
+        }
+        if let Some(link) = self.link() {
+            out.text("Available at: ");
+            out.link(&link, &link);
+            out.text(".");
+        }
+    }
+}
+
+impl Work {
+    /// Formats the work as a bibliography entry in the given style, see `CslItem::format`.
+    pub fn format_citation(&self, style: CitationStyle, format: CitationFormat) -> String {
+        CslItem::from(self).format(style, format)
+    }
+}
+
+/// Formats the works as a bibliography. IEEE entries are numbered (`[1] ...`) in the order of the works,
+/// entries of the other styles are sorted by author and year.
+pub fn format_bibliography<'a>(works: impl IntoIterator<Item = &'a Work>, style: CitationStyle, format: CitationFormat) -> Vec<String> {
+    let mut items: Vec<CslItem> = works.into_iter().map(CslItem::from).collect();
+    if style == CitationStyle::Ieee {
+        return items.iter()
+            .enumerate()
+            .map(|(i, item)| format!("[{}] {}", i + 1, item.format(style, format)))
+            .collect();
+    }
+    items.sort_by_cached_key(|item| {
+        let author = item.author.first().map(|name| inverted(name, false).to_lowercase());
+        (author.is_none(), author, item.year(), item.title.clone())
+    });
+    items.iter().map(|item| item.format(style, format)).collect()
+}
+
+impl fmt::Display for CitationStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CitationStyle::Apa => "APA",
+            CitationStyle::Mla => "MLA",
+            CitationStyle::Chicago => "Chicago",
+            CitationStyle::Ieee => "IEEE",
+            CitationStyle::Harvard => "Harvard",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+enum Part {
+    Text(String),
+    Italic(String),
+    Link(String),
+}
+
+struct Output {
+    format: CitationFormat,
+    text: String,
+}
+
+impl Output {
+    fn text(&mut self, value: &str) {
+        match self.format {
+            CitationFormat::Text => self.text.push_str(value),
+            CitationFormat::Html => self.text.push_str(&html_escape(value)),
+        }
+    }
+
+    fn italic(&mut self, value: &str) {
+        match self.format {
+            CitationFormat::Text => self.text.push_str(value),
+            CitationFormat::Html => self.text.push_str(&format!("<i>{}</i>", html_escape(value))),
+        }
+    }
+
+    fn link(&mut self, url: &str, label: &str) {
+        match self.format {
+            CitationFormat::Text => self.text.push_str(label),
+            CitationFormat::Html => self.text.push_str(&format!("<a href=\"{}\">{}</a>", html_escape(url), html_escape(label))),
+        }
+    }
+
+    fn parts(&mut self, parts: &[Part], separator: &str) {
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                self.text(separator);
+            }
+            match part {
+                Part::Text(value) => self.text(value),
+                Part::Italic(value) => self.italic(value),
+                Part::Link(url) => self.link(url, url),
+            }
+        }
+    }
+}
+
+fn html_escape(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Appends the punctuation unless the text already ends with a terminal punctuation mark.
+fn punctuate(text: &str, punctuation: &str) -> String {
+    format!("{}{}", text, punctuation_after(text, punctuation))
+}
+
+fn punctuation_after(text: &str, punctuation: &str) -> String {
+    match text.chars().last() {
+        Some('.') | Some('?') | Some('!') => String::new(),
+        _ => punctuation.to_string(),
+    }
+}
+
+/// Initials of given names, e.g. `Jean-Paul Yann` becomes `J.-P. Y.`.
+fn initials(given: &str) -> String {
+    given.split_whitespace()
+        .map(|name| name.split('-')
+            .filter_map(|part| part.chars().next())
+            .map(|c| format!("{}.", c))
+            .collect::<Vec<_>>()
+            .join("-"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `Family, Given` or, with `abbreviate`, `Family, G.`.
+fn inverted(name: &CslName, abbreviate: bool) -> String {
+    match (&name.family, &name.given, &name.literal) {
+        (Some(family), Some(given), _) if abbreviate => format!("{}, {}", family, initials(given)),
+        (Some(family), Some(given), _) => format!("{}, {}", family, given),
+        (Some(family), None, _) => family.clone(),
+        (None, Some(given), _) => given.clone(),
+        (None, None, literal) => literal.clone().unwrap_or_default(),
+    }
+}
+
+/// `Given Family` or, with `abbreviate`, `G. Family`.
+fn direct(name: &CslName, abbreviate: bool) -> String {
+    match (&name.family, &name.given, &name.literal) {
+        (Some(family), Some(given), _) if abbreviate => format!("{} {}", initials(given), family),
+        (Some(family), Some(given), _) => format!("{} {}", given, family),
+        (Some(family), None, _) => family.clone(),
+        (None, Some(given), _) => given.clone(),
+        (None, None, literal) => literal.clone().unwrap_or_default(),
+    }
+}
+
+/// Chicago inverts the first author only.
+fn chicago_name(name: &CslName, position: usize) -> String {
+    if position == 0 { inverted(name, false) } else { direct(name, false) }
+}
+
+/// Joins names with `separator`, using `last_separator` before the last of three or more names and
+/// `pair_separator` between exactly two names. With more than `max` names, the names after the first
+/// `max - 1` are replaced by an ellipsis followed by the last name.
+fn join_names<F>(names: &[CslName], max: usize, separator: &str, last_separator: &str, pair_separator: &str, format: F) -> String
+where
+    F: Fn(&CslName, usize) -> String,
+{
+    let formatted: Vec<String> = names.iter().enumerate().map(|(i, name)| format(name, i)).collect();
+    match formatted.len() {
+        0 => String::new(),
+        1 => formatted[0].clone(),
+        2 => format!("{}{}{}", formatted[0], pair_separator, formatted[1]),
+        n if n > max => format!("{}{}. . . {}", formatted[..max - 1].join(separator), separator, formatted[n - 1]),
+        n => format!("{}{}{}", formatted[..n - 1].join(separator), last_separator, formatted[n - 1]),
+    }
+}
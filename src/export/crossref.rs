@@ -0,0 +1,297 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{ApiDate, Author, DocumentType, IdentifierValue, JournalRef, Language, Reference, Work};
+
+use super::{csl::CslDate, first_issn, journal_title};
+
+/// Work metadata in the shape of a Crossref REST API work (`message` of `/works/{doi}`). Converts from
+/// and to `Work`; unknown Crossref fields are ignored when reading.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct CrossrefWork {
+    /// DOI of the work.
+    #[serde(rename = "DOI", default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+
+    /// Crossref type, e.g. `journal-article`, `dissertation` or `posted-content`.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub work_type: Option<String>,
+
+    /// Titles of the work.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub title: Vec<String>,
+
+    /// Authors of the work.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub author: Vec<CrossrefAuthor>,
+
+    /// Titles of the journal.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub container_title: Vec<String>,
+
+    /// Publisher of the work.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+
+    /// ISSNs of the journal.
+    #[serde(rename = "ISSN", default, skip_serializing_if = "Vec::is_empty")]
+    pub issn: Vec<String>,
+
+    /// Earliest publication date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issued: Option<CslDate>,
+
+    /// Abstract, in Crossref often as JATS XML.
+    #[serde(rename = "abstract", default, skip_serializing_if = "Option::is_none")]
+    pub abstract_text: Option<String>,
+
+    /// Language code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    /// Subjects of the work.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subject: Vec<String>,
+
+    /// URL of the work, the DOI resolver URL for registered works.
+    #[serde(rename = "URL", default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Full text links.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub link: Vec<CrossrefLink>,
+
+    /// Number of works citing the work.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_referenced_by_count: Option<i32>,
+
+    /// References of the work.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reference: Vec<CrossrefReference>,
+}
+
+/// Author of a Crossref work.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+pub struct CrossrefAuthor {
+    /// Given name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub given: Option<String>,
+
+    /// Family name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+
+    /// Name of an organisation or an unsplit name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// ORCID URL of the author.
+    #[serde(rename = "ORCID", default, skip_serializing_if = "Option::is_none")]
+    pub orcid: Option<String>,
+
+    /// `first` for the first author, `additional` otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<String>,
+}
+
+/// Full text link of a Crossref work.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct CrossrefLink {
+    /// URL of the full text.
+    #[serde(rename = "URL")]
+    pub url: String,
+
+    /// Content type, e.g. `application/pdf`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
+/// Reference of a Crossref work.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct CrossrefReference {
+    /// Key of the reference within the work.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+
+    /// DOI of the referenced work.
+    #[serde(rename = "DOI", default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+
+    /// Title of the referenced work.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub article_title: Option<String>,
+
+    /// Year of the referenced work.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<String>,
+
+    /// The reference as written in the work.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unstructured: Option<String>,
+}
+
+impl From<&Work> for CrossrefWork {
+    fn from(work: &Work) -> Self {
+        let author = work.authors.iter().flatten()
+            .filter(|author| !author.name.trim().is_empty())
+            .enumerate()
+            .map(|(i, author)| {
+                let name = author.parsed_name();
+                let sequence = Some(if i == 0 { "first" } else { "additional" }.to_string());
+                let orcid = author.orcid().map(|orcid| orcid.url());
+                if name.given.is_empty() {
+                    CrossrefAuthor { name: Some(author.name.trim().to_string()), orcid, sequence, ..Default::default() }
+                } else {
+                    CrossrefAuthor { given: Some(name.given.join(" ")), family: Some(name.family), orcid, sequence, name: None }
+                }
+            })
+            .collect();
+        let issued = match work.published_date.as_ref() {
+            Some(date) if date.year().is_some() => {
+                let parts = [date.year(), date.month().map(|m| m as i32), date.day().map(|d| d as i32)];
+                Some(CslDate { date_parts: vec![parts.iter().map_while(|part| *part).collect()] })
+            },
+            _ => work.publication_year().map(|year| CslDate { date_parts: vec![vec![year]] }),
+        };
+        let work_type = match work.document_type {
+            Some(DocumentType::Thesis) => "dissertation",
+            _ if journal_title(work).is_some() => "journal-article",
+            Some(DocumentType::Research) => "posted-content",
+            _ => "other",
+        };
+
+        CrossrefWork {
+            doi: work.doi.as_ref().map(|doi| doi.to_string()),
+            work_type: Some(work_type.to_string()),
+            title: work.title.iter().cloned().collect(),
+            author,
+            container_title: journal_title(work).into_iter().collect(),
+            publisher: work.publisher.clone(),
+            issn: first_issn(work).iter().map(|issn| issn.to_string()).collect(),
+            issued,
+            abstract_text: work.abstract_text.clone(),
+            language: work.language.as_ref().and_then(|language| language.code.clone()),
+            subject: work.field_of_study.iter().map(|field| field.as_str().to_string()).collect(),
+            url: work.doi().map(|doi| doi.url()),
+            link: work.download_url.iter().map(|url| CrossrefLink {
+                url: url.clone(),
+                content_type: Some("application/pdf".to_string()),
+            }).collect(),
+            is_referenced_by_count: work.citation_count,
+            reference: work.references.iter().flatten().enumerate().map(|(i, reference)| CrossrefReference {
+                key: Some(reference.id.map_or_else(|| format!("ref{}", i + 1), |id| id.to_string())),
+                doi: reference.doi.as_ref().map(|doi| doi.to_string()),
+                article_title: reference.title.clone(),
+                year: reference.date.as_ref().and_then(|date| date.year()).map(|year| year.to_string()),
+                unstructured: reference.raw.clone(),
+            }).collect(),
+        }
+    }
+}
+
+impl From<&CrossrefWork> for Work {
+    fn from(crossref: &CrossrefWork) -> Self {
+        let authors: Vec<Author> = crossref.author.iter()
+            .map(|author| Author {
+                name: match (&author.family, &author.given, &author.name) {
+                    (Some(family), Some(given), _) => format!("{}, {}", family, given),
+                    (Some(family), None, _) => family.clone(),
+                    (None, _, name) => name.clone().unwrap_or_default(),
+                },
+                orcid: author.orcid.as_deref().map(IdentifierValue::parse),
+            })
+            .filter(|author| !author.name.is_empty())
+            .collect();
+        let published_date = crossref.issued.as_ref()
+            .and_then(|issued| issued.date_parts.first())
+            .filter(|parts| !parts.is_empty())
+            .map(|parts| {
+                let raw = parts.iter().take(3).enumerate()
+                    .map(|(i, part)| if i == 0 { part.to_string() } else { format!("{:02}", part) })
+                    .collect::<Vec<_>>()
+                    .join("-");
+                ApiDate::parse(raw)
+            });
+        let document_type = crossref.work_type.as_deref().and_then(|kind| match kind {
+            "dissertation" => Some(DocumentType::Thesis),
+            "journal-article" | "proceedings-article" | "posted-content" | "report" | "book-chapter" => Some(DocumentType::Research),
+            _ => None,
+        });
+        let journals = crossref.container_title.first().map(|title| vec![JournalRef {
+            title: Some(title.clone()),
+            identifiers: crossref.issn.iter().map(|issn| format!("issn:{}", issn)).collect(),
+        }]);
+        let references: Vec<Reference> = crossref.reference.iter().map(|reference| Reference {
+            doi: reference.doi.as_deref().map(IdentifierValue::parse),
+            title: reference.article_title.clone(),
+            date: reference.year.as_deref().map(ApiDate::parse),
+            raw: reference.unstructured.clone(),
+            ..Default::default()
+        }).collect();
+
+        Work {
+            doi: crossref.doi.as_deref().map(IdentifierValue::parse),
+            title: crossref.title.first().cloned(),
+            authors: Some(authors).filter(|authors| !authors.is_empty()),
+            journals,
+            publisher: crossref.publisher.clone(),
+            year_published: published_date.as_ref().and_then(|date| date.year()),
+            published_date,
+            abstract_text: crossref.abstract_text.as_deref().map(strip_jats),
+            language: crossref.language.as_deref().map(Language::from),
+            field_of_study: crossref.subject.first().map(|subject| subject.as_str().into()),
+            document_type,
+            download_url: crossref.link.iter()
+                .find(|link| link.content_type.as_deref() == Some("application/pdf"))
+                .or(crossref.link.first())
+                .map(|link| link.url.clone()),
+            citation_count: crossref.is_referenced_by_count,
+            references: Some(references).filter(|references| !references.is_empty()),
+            ..Default::default()
+        }
+    }
+}
+
+impl Work {
+    /// Converts the work into the shape of a Crossref work.
+    ///
+    /// ```
+    /// use core_api_client::{export::crossref::CrossrefWork, Author, IdentifierValue, Work};
+    ///
+    /// let work = Work {
+    ///     title: Some("Deep learning".to_string()),
+    ///     authors: Some(vec![Author::from("Yann LeCun")]),
+    ///     doi: Some(IdentifierValue::parse("10.1038/nature14539")),
+    ///     year_published: Some(2015),
+    ///     ..Default::default()
+    /// };
+    /// let json = serde_json::to_value(work.to_crossref()).unwrap();
+    ///
+    /// assert_eq!(json["DOI"], "10.1038/nature14539");
+    /// assert_eq!(json["author"][0]["family"], "LeCun");
+    /// assert_eq!(json["issued"]["date-parts"][0][0], 2015);
+    /// ```
+    pub fn to_crossref(&self) -> CrossrefWork {
+        CrossrefWork::from(self)
+    }
+}
+
+/// Removes JATS markup (e.g. `<jats:p>`) from a Crossref abstract.
+fn strip_jats(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                stripped.push(' ');
+            },
+            _ if !in_tag => stripped.push(c),
+            _ => {},
+        }
+    }
+    stripped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{authors::name::PersonName, helpers::string_number_deserializer::deserialize_date_parts, DocumentType, Work};
+
+use super::{bibtex::citation_key, first_issn, journal_title};
+
+/// Name of a person in CSL-JSON. Names that can't be split into family and given names are kept in `literal`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+pub struct CslName {
+    /// Family name, including particles (e.g. `van Gogh`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+
+    /// Given names or initials.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub given: Option<String>,
+
+    /// Unparsed name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub literal: Option<String>,
+}
+
+impl From<&PersonName> for CslName {
+    fn from(name: &PersonName) -> Self {
+        let given = name.given.iter()
+            .map(|g| if g.chars().count() == 1 { format!("{}.", g) } else { g.clone() })
+            .collect::<Vec<_>>()
+            .join(" ");
+        CslName {
+            family: Some(name.family.clone()).filter(|f| !f.is_empty()),
+            given: Some(given).filter(|g| !g.is_empty()),
+            literal: None,
+        }
+    }
+}
+
+/// Date in CSL-JSON, e.g. `{"date-parts": [[2015, 5, 28]]}`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+pub struct CslDate {
+    /// Year, month and day, where month and day are optional. Dates given as `null` (e.g. `[[null]]`
+    /// from Crossref) are read as empty.
+    #[serde(rename = "date-parts", default, deserialize_with = "deserialize_date_parts")]
+    pub date_parts: Vec<Vec<i32>>,
+}
+
+impl CslDate {
+    /// Returns the year of the date.
+    pub fn year(&self) -> Option<i32> {
+        self.date_parts.first().and_then(|parts| parts.first()).copied()
+    }
+}
+
+/// Bibliographic item in CSL-JSON, the input format of citeproc processors.
+///
+/// ```
+/// use core_api_client::{Author, Work};
+///
+/// let work = Work {
+///     id: Some(42),
+///     title: Some("Deep learning".to_string()),
+///     authors: Some(vec![Author::from("Yann LeCun")]),
+///     year_published: Some(2015),
+///     ..Default::default()
+/// };
+/// let json = serde_json::to_string(&work.to_csl()).unwrap();
+///
+/// assert_eq!(
+///     json,
+///     r#"{"id":"42","type":"article","title":"Deep learning","author":[{"family":"LeCun","given":"Yann"}],"issued":{"date-parts":[[2015]]}}"#
+/// );
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+pub struct CslItem {
+    /// Identifier of the item, the CORE id if there is one.
+    pub id: String,
+
+    /// CSL item type, e.g. `article-journal` or `thesis`.
+    #[serde(rename = "type")]
+    pub item_type: String,
+
+    /// Title of the item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Authors of the item.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub author: Vec<CslName>,
+
+    /// Publication date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issued: Option<CslDate>,
+
+    /// Title of the journal the item was published in.
+    #[serde(rename = "container-title", default, skip_serializing_if = "Option::is_none")]
+    pub container_title: Option<String>,
+
+    /// Publisher of the item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+
+    /// DOI of the item.
+    #[serde(rename = "DOI", default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+
+    /// URL of the item.
+    #[serde(rename = "URL", default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// ISSN of the journal.
+    #[serde(rename = "ISSN", default, skip_serializing_if = "Option::is_none")]
+    pub issn: Option<String>,
+
+    /// Abstract of the item.
+    #[serde(rename = "abstract", default, skip_serializing_if = "Option::is_none")]
+    pub abstract_text: Option<String>,
+
+    /// Language code of the item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+/// Returns the CSL item type of a work.
+fn csl_type(work: &Work) -> &'static str {
+    match &work.document_type {
+        Some(DocumentType::Thesis) => "thesis",
+        Some(DocumentType::Slides) | Some(DocumentType::Presentation) => "speech",
+        _ if journal_title(work).is_some() => "article-journal",
+        _ => "article",
+    }
+}
+
+impl From<&Work> for CslItem {
+    fn from(work: &Work) -> Self {
+        let author = work.authors.iter().flatten()
+            .filter(|author| !author.name.trim().is_empty())
+            .map(|author| {
+                let name = author.parsed_name();
+                if name.given.is_empty() {
+                    CslName { literal: Some(author.name.trim().to_string()), ..Default::default() }
+                } else {
+                    CslName::from(&name)
+                }
+            })
+            .collect();
+        let issued = match work.published_date.as_ref() {
+            Some(date) if date.year().is_some() && date.year() == work.publication_year() => {
+                let parts = [date.year(), date.month().map(|m| m as i32), date.day().map(|d| d as i32)];
+                Some(CslDate { date_parts: vec![parts.iter().map_while(|part| *part).collect()] })
+            },
+            _ => work.publication_year().map(|year| CslDate { date_parts: vec![vec![year]] }),
+        };
+
+        CslItem {
+            id: work.id.map_or_else(|| citation_key(work), |id| id.to_string()),
+            item_type: csl_type(work).to_string(),
+            title: work.title.clone(),
+            author,
+            issued,
+            container_title: journal_title(work),
+            publisher: work.publisher.clone().filter(|p| !p.trim().is_empty()),
+            doi: work.doi.as_ref().map(|doi| doi.to_string()),
+            url: work.download_url.clone(),
+            issn: first_issn(work).map(|issn| issn.to_string()),
+            abstract_text: work.abstract_text.clone(),
+            language: work.language.as_ref().and_then(|language| language.code.clone()),
+        }
+    }
+}
+
+impl Work {
+    /// Converts the work into a CSL-JSON item.
+    pub fn to_csl(&self) -> CslItem {
+        CslItem::from(self)
+    }
+}
+
+/// Converts works into a CSL-JSON array.
+pub fn to_csl_json<'a>(works: impl IntoIterator<Item = &'a Work>) -> serde_json::Value {
+    let items: Vec<CslItem> = works.into_iter().map(CslItem::from).collect();
+    serde_json::to_value(items).unwrap_or_default()
+}
@@ -0,0 +1,148 @@
+use std::io::Write;
+
+use crate::{errors::Error, Work};
+
+use super::fields::{FieldSelection, FieldValue};
+
+/// How multi-valued fields are written into a single CSV cell.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListFormat {
+    /// Values joined with a separator, e.g. `Ada Lovelace; Charles Babbage`.
+    Joined(String),
+    /// Values as a JSON array, e.g. `["Ada Lovelace","Charles Babbage"]`, which keeps separators inside values.
+    Json,
+}
+
+impl Default for ListFormat {
+    fn default() -> Self {
+        ListFormat::Joined("; ".to_string())
+    }
+}
+
+/// Streams works as CSV (RFC 4180) with one row per work and one column per selected field.
+///
+/// The header row is written before the first work. Cells containing the delimiter, quotes, line breaks
+/// or surrounding whitespace are quoted, with quotes doubled. Missing values are empty cells and
+/// multi-valued fields are written according to the `ListFormat`.
+///
+/// ```
+/// use core_api_client::{export::{csv::CsvWriter, fields::FieldSelection}, Author, Work};
+///
+/// let work = Work {
+///     id: Some(1),
+///     title: Some("Notes, \"annotated\"".to_string()),
+///     authors: Some(vec![Author::from("Ada Lovelace"), Author::from("Charles Babbage")]),
+///     ..Default::default()
+/// };
+/// let mut writer = CsvWriter::new(vec![]).fields(FieldSelection::parse("id,title,authors,doi").unwrap());
+/// writer.write(&work).unwrap();
+///
+/// let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+/// assert_eq!(output, "id,title,authors,doi\r\n1,\"Notes, \"\"annotated\"\"\",Ada Lovelace; Charles Babbage,\r\n");
+/// ```
+#[derive(Debug)]
+pub struct CsvWriter<W: Write> {
+    writer: W,
+    fields: FieldSelection,
+    delimiter: char,
+    list_format: ListFormat,
+    header: bool,
+    started: bool,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Creates a writer with the default field selection, `,` as delimiter and lists joined with `; `.
+    pub fn new(writer: W) -> Self {
+        CsvWriter {
+            writer,
+            fields: FieldSelection::default(),
+            delimiter: ',',
+            list_format: ListFormat::default(),
+            header: true,
+            started: false,
+        }
+    }
+
+    /// Sets the exported fields.
+    pub fn fields(self, fields: FieldSelection) -> Self {
+        Self { fields, ..self }
+    }
+
+    /// Sets the cell delimiter, e.g. `\t` for TSV.
+    pub fn delimiter(self, delimiter: char) -> Self {
+        Self { delimiter, ..self }
+    }
+
+    /// Sets how multi-valued fields are written.
+    pub fn list_format(self, list_format: ListFormat) -> Self {
+        Self { list_format, ..self }
+    }
+
+    /// Sets whether a header row is written (default true).
+    pub fn header(self, header: bool) -> Self {
+        Self { header, ..self }
+    }
+
+    /// Writes a work as a row, preceded by the header row if this is the first row.
+    pub fn write(&mut self, work: &Work) -> Result<(), Error> {
+        self.start()?;
+        let cells: Vec<String> = self.fields.fields().iter()
+            .map(|field| match field.value(work) {
+                FieldValue::Null => String::new(),
+                FieldValue::Number(number) => number.to_string(),
+                FieldValue::Text(text) => text,
+                FieldValue::List(values) => match &self.list_format {
+                    ListFormat::Joined(separator) => values.join(separator),
+                    ListFormat::Json => serde_json::Value::from(values).to_string(),
+                },
+            })
+            .collect();
+        self.write_row(&cells)
+    }
+
+    /// Writes all works of an iterator (e.g. `Api::iter_works`), stopping at the first error. Returns the
+    /// number of written works.
+    pub fn write_all(&mut self, works: impl IntoIterator<Item = Result<Work, Error>>) -> Result<usize, Error> {
+        let mut count = 0;
+        for work in works {
+            self.write(&work?)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Writes the header row if nothing was written yet, flushes and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W, Error> {
+        self.start()?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        if !self.started {
+            self.started = true;
+            if self.header {
+                let names: Vec<String> = self.fields.fields().iter().map(|field| field.name().to_string()).collect();
+                self.write_row(&names)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_row(&mut self, cells: &[String]) -> Result<(), Error> {
+        let row: Vec<String> = cells.iter().map(|cell| quote(cell, self.delimiter)).collect();
+        write!(self.writer, "{}\r\n", row.join(&self.delimiter.to_string()))?;
+        Ok(())
+    }
+}
+
+fn quote(cell: &str, delimiter: char) -> String {
+    let needs_quotes = cell.contains([delimiter, '"', '\n', '\r'])
+        || cell.starts_with(char::is_whitespace)
+        || cell.ends_with(char::is_whitespace);
+    if needs_quotes {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
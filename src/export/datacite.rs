@@ -0,0 +1,580 @@
+use roxmltree::Node;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+use crate::{
+    errors::Error,
+    helpers::{graph_writer::{write_to_string, xml_escape}, string_number_deserializer::deserialize_as_year},
+    ApiDate, Author, DocumentType, FieldOfStudy, IdentifierValue, JournalRef, Language, OaiId, Work,
+};
+
+use super::{first_issn, iso_date, journal_title};
+
+/// Metadata record in the DataCite metadata schema (kernel 4), in the JSON shape of the DataCite REST API
+/// attributes. Converts from and to `Work`, and reads and writes DataCite XML.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataCiteRecord {
+    /// DOI of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+
+    /// Other identifiers of the resource (`alternateIdentifiers` in XML).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub identifiers: Vec<DataCiteIdentifier>,
+
+    /// Creators (authors) of the resource.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub creators: Vec<DataCiteCreator>,
+
+    /// Titles of the resource.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub titles: Vec<DataCiteTitle>,
+
+    /// Publisher of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+
+    /// Year the resource was published.
+    #[serde(default, deserialize_with = "deserialize_as_year", skip_serializing_if = "Option::is_none")]
+    pub publication_year: Option<i32>,
+
+    /// Resource type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub types: Option<DataCiteTypes>,
+
+    /// Subjects of the resource.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subjects: Vec<DataCiteSubject>,
+
+    /// Dates of the resource, e.g. `Issued` or `Updated`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dates: Vec<DataCiteDate>,
+
+    /// Language code of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    /// Descriptions of the resource, e.g. the abstract.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub descriptions: Vec<DataCiteDescription>,
+
+    /// Journal the resource was published in (`relatedItem` in XML).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<DataCiteContainer>,
+
+    /// URL of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Identifier of a resource with its type, e.g. `arXiv`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataCiteIdentifier {
+    /// The identifier.
+    pub identifier: String,
+
+    /// Type of the identifier.
+    pub identifier_type: String,
+}
+
+/// Creator of a resource.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataCiteCreator {
+    /// Full name, `Family, Given` for persons.
+    pub name: String,
+
+    /// `Personal` or `Organizational`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_type: Option<String>,
+
+    /// Given name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+
+    /// Family name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+
+    /// Identifiers of the creator, e.g. the ORCID.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub name_identifiers: Vec<DataCiteNameIdentifier>,
+}
+
+/// Identifier of a creator.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataCiteNameIdentifier {
+    /// The identifier, e.g. `https://orcid.org/0000-0002-1825-0097`.
+    pub name_identifier: String,
+
+    /// Scheme of the identifier, e.g. `ORCID`.
+    pub name_identifier_scheme: String,
+
+    /// URI of the scheme.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheme_uri: Option<String>,
+}
+
+/// Title of a resource.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataCiteTitle {
+    /// The title.
+    pub title: String,
+
+    /// Type of the title, `None` for the main title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_type: Option<String>,
+}
+
+/// Type of a resource.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataCiteTypes {
+    /// General type from the DataCite vocabulary, e.g. `Text` or `Dissertation`.
+    pub resource_type_general: String,
+
+    /// Free text type, the CORE document type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+}
+
+/// Subject of a resource.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataCiteSubject {
+    /// The subject.
+    pub subject: String,
+}
+
+/// Date of a resource.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataCiteDate {
+    /// The date, as an ISO 8601 date.
+    pub date: String,
+
+    /// Type of the date, e.g. `Issued`, `Accepted`, `Submitted`, `Created` or `Updated`.
+    pub date_type: String,
+}
+
+/// Description of a resource.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataCiteDescription {
+    /// The description.
+    pub description: String,
+
+    /// Type of the description, e.g. `Abstract`.
+    pub description_type: String,
+}
+
+/// Journal or other container a resource was published in.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DataCiteContainer {
+    /// Type of the container, e.g. `Journal`.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub container_type: Option<String>,
+
+    /// Title of the container.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Identifier of the container, e.g. its ISSN.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+
+    /// Type of the identifier, e.g. `ISSN`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier_type: Option<String>,
+}
+
+impl DataCiteRecord {
+    /// Returns the value of the first date of the given type.
+    pub fn date(&self, date_type: &str) -> Option<&str> {
+        self.dates.iter()
+            .find(|date| date.date_type.eq_ignore_ascii_case(date_type))
+            .map(|date| date.date.as_str())
+    }
+
+    /// Writes the record as a DataCite XML `resource` document. The URL is not part of the DataCite metadata
+    /// schema and is not written.
+    pub fn write_xml<W: Write>(&self, w: &mut W) -> Result<(), Error> {
+        writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            w,
+            "<resource xmlns=\"http://datacite.org/schema/kernel-4\" \
+             xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+             xsi:schemaLocation=\"http://datacite.org/schema/kernel-4 http://schema.datacite.org/meta/kernel-4/metadata.xsd\">"
+        )?;
+        if let Some(doi) = &self.doi {
+            writeln!(w, "  <identifier identifierType=\"DOI\">{}</identifier>", xml_escape(doi))?;
+        }
+        if !self.creators.is_empty() {
+            writeln!(w, "  <creators>")?;
+            for creator in &self.creators {
+                writeln!(w, "    <creator>")?;
+                match &creator.name_type {
+                    Some(name_type) => writeln!(w, "      <creatorName nameType=\"{}\">{}</creatorName>", xml_escape(name_type), xml_escape(&creator.name))?,
+                    None => writeln!(w, "      <creatorName>{}</creatorName>", xml_escape(&creator.name))?,
+                }
+                if let Some(given) = &creator.given_name {
+                    writeln!(w, "      <givenName>{}</givenName>", xml_escape(given))?;
+                }
+                if let Some(family) = &creator.family_name {
+                    writeln!(w, "      <familyName>{}</familyName>", xml_escape(family))?;
+                }
+                for id in &creator.name_identifiers {
+                    let scheme_uri = id.scheme_uri.as_ref().map_or(String::new(), |uri| format!(" schemeURI=\"{}\"", xml_escape(uri)));
+                    writeln!(
+                        w,
+                        "      <nameIdentifier nameIdentifierScheme=\"{}\"{}>{}</nameIdentifier>",
+                        xml_escape(&id.name_identifier_scheme), scheme_uri, xml_escape(&id.name_identifier)
+                    )?;
+                }
+                writeln!(w, "    </creator>")?;
+            }
+            writeln!(w, "  </creators>")?;
+        }
+        if !self.titles.is_empty() {
+            writeln!(w, "  <titles>")?;
+            for title in &self.titles {
+                match &title.title_type {
+                    Some(title_type) => writeln!(w, "    <title titleType=\"{}\">{}</title>", xml_escape(title_type), xml_escape(&title.title))?,
+                    None => writeln!(w, "    <title>{}</title>", xml_escape(&title.title))?,
+                }
+            }
+            writeln!(w, "  </titles>")?;
+        }
+        if let Some(publisher) = &self.publisher {
+            writeln!(w, "  <publisher>{}</publisher>", xml_escape(publisher))?;
+        }
+        if let Some(year) = self.publication_year {
+            writeln!(w, "  <publicationYear>{}</publicationYear>", year)?;
+        }
+        if let Some(types) = &self.types {
+            writeln!(
+                w,
+                "  <resourceType resourceTypeGeneral=\"{}\">{}</resourceType>",
+                xml_escape(&types.resource_type_general), xml_escape(types.resource_type.as_deref().unwrap_or_default())
+            )?;
+        }
+        list(w, "subjects", self.subjects.iter().map(|subject| format!("<subject>{}</subject>", xml_escape(&subject.subject))))?;
+        list(w, "dates", self.dates.iter().map(|date| {
+            format!("<date dateType=\"{}\">{}</date>", xml_escape(&date.date_type), xml_escape(&date.date))
+        }))?;
+        if let Some(language) = &self.language {
+            writeln!(w, "  <language>{}</language>", xml_escape(language))?;
+        }
+        list(w, "alternateIdentifiers", self.identifiers.iter().map(|id| {
+            format!(
+                "<alternateIdentifier alternateIdentifierType=\"{}\">{}</alternateIdentifier>",
+                xml_escape(&id.identifier_type), xml_escape(&id.identifier)
+            )
+        }))?;
+        list(w, "descriptions", self.descriptions.iter().map(|description| {
+            format!(
+                "<description descriptionType=\"{}\">{}</description>",
+                xml_escape(&description.description_type), xml_escape(&description.description)
+            )
+        }))?;
+        if let Some(container) = &self.container {
+            writeln!(w, "  <relatedItems>")?;
+            writeln!(
+                w,
+                "    <relatedItem relatedItemType=\"{}\" relationType=\"IsPublishedIn\">",
+                xml_escape(container.container_type.as_deref().unwrap_or("Journal"))
+            )?;
+            if let Some(identifier) = &container.identifier {
+                writeln!(
+                    w,
+                    "      <relatedItemIdentifier relatedItemIdentifierType=\"{}\">{}</relatedItemIdentifier>",
+                    xml_escape(container.identifier_type.as_deref().unwrap_or("ISSN")), xml_escape(identifier)
+                )?;
+            }
+            if let Some(title) = &container.title {
+                writeln!(w, "      <titles><title>{}</title></titles>", xml_escape(title))?;
+            }
+            writeln!(w, "    </relatedItem>")?;
+            writeln!(w, "  </relatedItems>")?;
+        }
+        writeln!(w, "</resource>")?;
+        Ok(())
+    }
+
+    /// Formats the record as a DataCite XML document, see `write_xml`.
+    pub fn to_xml(&self) -> String {
+        write_to_string(|buffer| self.write_xml(buffer))
+    }
+
+    /// Reads a record from a DataCite XML `resource` document. Elements the record has no field for are
+    /// ignored.
+    pub fn from_xml(xml: &str) -> Result<Self, Error> {
+        let document = roxmltree::Document::parse(xml).map_err(|e| Error::Parsing(format!("invalid DataCite XML: {}", e)))?;
+        let root = document.root_element();
+        if root.tag_name().name() != "resource" {
+            return Err(Error::Parsing(format!("expected a DataCite resource, found <{}>", root.tag_name().name())));
+        }
+        let nested = |list: &str, item: &str| child(root, list).map(|l| children(l, item)).unwrap_or_default();
+
+        let mut record = DataCiteRecord {
+            doi: child(root, "identifier")
+                .filter(|id| id.attribute("identifierType").map(|t| t.eq_ignore_ascii_case("DOI")).unwrap_or(true))
+                .map(text),
+            publisher: child(root, "publisher").map(text),
+            publication_year: child(root, "publicationYear").and_then(|year| text(year).parse().ok()),
+            language: child(root, "language").map(text),
+            types: child(root, "resourceType").map(|node| DataCiteTypes {
+                resource_type_general: node.attribute("resourceTypeGeneral").unwrap_or_default().to_string(),
+                resource_type: Some(text(node)).filter(|t| !t.is_empty()),
+            }),
+            ..Default::default()
+        };
+        record.creators = nested("creators", "creator").into_iter().map(|creator| DataCiteCreator {
+            name: child(creator, "creatorName").map(text).unwrap_or_default(),
+            name_type: child(creator, "creatorName").and_then(|n| n.attribute("nameType")).map(str::to_string),
+            given_name: child(creator, "givenName").map(text),
+            family_name: child(creator, "familyName").map(text),
+            name_identifiers: children(creator, "nameIdentifier").into_iter().map(|id| DataCiteNameIdentifier {
+                name_identifier: text(id),
+                name_identifier_scheme: id.attribute("nameIdentifierScheme").unwrap_or_default().to_string(),
+                scheme_uri: id.attribute("schemeURI").map(str::to_string),
+            }).collect(),
+        }).collect();
+        record.titles = nested("titles", "title").into_iter().map(|title| DataCiteTitle {
+            title: text(title),
+            title_type: title.attribute("titleType").map(str::to_string),
+        }).collect();
+        record.subjects = nested("subjects", "subject").into_iter().map(|s| DataCiteSubject { subject: text(s) }).collect();
+        record.dates = nested("dates", "date").into_iter().map(|date| DataCiteDate {
+            date: text(date),
+            date_type: date.attribute("dateType").unwrap_or_default().to_string(),
+        }).collect();
+        record.identifiers = nested("alternateIdentifiers", "alternateIdentifier").into_iter().map(|id| DataCiteIdentifier {
+            identifier: text(id),
+            identifier_type: id.attribute("alternateIdentifierType").unwrap_or_default().to_string(),
+        }).collect();
+        record.descriptions = nested("descriptions", "description").into_iter().map(|description| DataCiteDescription {
+            description: text(description),
+            description_type: description.attribute("descriptionType").unwrap_or_default().to_string(),
+        }).collect();
+        record.container = nested("relatedItems", "relatedItem").into_iter()
+            .find(|item| item.attribute("relationType") == Some("IsPublishedIn"))
+            .map(|item| {
+                let identifier = child(item, "relatedItemIdentifier");
+                DataCiteContainer {
+                    container_type: item.attribute("relatedItemType").map(str::to_string),
+                    title: child(item, "titles").and_then(|titles| child(titles, "title")).map(text),
+                    identifier: identifier.map(text),
+                    identifier_type: identifier.and_then(|id| id.attribute("relatedItemIdentifierType")).map(str::to_string),
+                }
+            });
+        Ok(record)
+    }
+}
+
+fn children<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Vec<Node<'a, 'input>> {
+    node.children().filter(|child| child.is_element() && child.tag_name().name() == name).collect()
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.is_element() && child.tag_name().name() == name)
+}
+
+fn text(node: Node) -> String {
+    node.text().map(|text| text.trim().to_string()).unwrap_or_default()
+}
+
+fn list<W: Write>(w: &mut W, name: &str, items: impl Iterator<Item = String>) -> Result<(), Error> {
+    let items: Vec<String> = items.collect();
+    if !items.is_empty() {
+        writeln!(w, "  <{}>", name)?;
+        for item in items {
+            writeln!(w, "    {}", item)?;
+        }
+        writeln!(w, "  </{}>", name)?;
+    }
+    Ok(())
+}
+
+impl From<&Work> for DataCiteRecord {
+    fn from(work: &Work) -> Self {
+        let mut identifiers = vec![];
+        let mut identifier = |identifier: String, identifier_type: &str| {
+            identifiers.push(DataCiteIdentifier { identifier, identifier_type: identifier_type.to_string() });
+        };
+        if let Some(id) = work.id {
+            identifier(id.to_string(), "CORE");
+        }
+        if let Some(arxiv_id) = &work.arxiv_id {
+            identifier(arxiv_id.to_string(), "arXiv");
+        }
+        if let Some(pmid) = &work.pubmed_id {
+            identifier(pmid.to_string(), "PMID");
+        }
+        for oai in work.oai_ids.iter().flatten() {
+            identifier(oai.to_string(), "OAI");
+        }
+
+        let creators = work.authors.iter().flatten()
+            .filter(|author| !author.name.trim().is_empty())
+            .map(|author| {
+                let name = author.parsed_name();
+                let personal = !name.given.is_empty();
+                DataCiteCreator {
+                    name: if personal { name.to_string() } else { author.name.trim().to_string() },
+                    name_type: Some(if personal { "Personal" } else { "Organizational" }.to_string()),
+                    given_name: Some(name.given.join(" ")).filter(|_| personal),
+                    family_name: Some(name.family.clone()).filter(|_| personal),
+                    name_identifiers: author.orcid().into_iter().map(|orcid| DataCiteNameIdentifier {
+                        name_identifier: orcid.url(),
+                        name_identifier_scheme: "ORCID".to_string(),
+                        scheme_uri: Some("https://orcid.org".to_string()),
+                    }).collect(),
+                }
+            })
+            .collect();
+
+        let mut dates = vec![];
+        for (date, date_type) in [
+            (&work.published_date, "Issued"),
+            (&work.accepted_date, "Accepted"),
+            (&work.deposited_date, "Submitted"),
+            (&work.created_date, "Created"),
+            (&work.updated_date, "Updated"),
+        ] {
+            if let Some(date) = date.as_ref().and_then(iso_date) {
+                dates.push(DataCiteDate { date, date_type: date_type.to_string() });
+            }
+        }
+
+        let resource_type_general = match work.document_type {
+            Some(DocumentType::Thesis) => "Dissertation",
+            _ if journal_title(work).is_some() => "JournalArticle",
+            _ => "Text",
+        };
+        let container = journal_title(work).map(|title| {
+            let issn = first_issn(work);
+            DataCiteContainer {
+                container_type: Some("Journal".to_string()),
+                title: Some(title),
+                identifier_type: issn.as_ref().map(|_| "ISSN".to_string()),
+                identifier: issn.map(|issn| issn.to_string()),
+            }
+        });
+
+        DataCiteRecord {
+            doi: work.doi.as_ref().map(|doi| doi.to_string()),
+            identifiers,
+            creators,
+            titles: work.title.iter().map(|title| DataCiteTitle { title: title.clone(), title_type: None }).collect(),
+            publisher: work.publisher.clone(),
+            publication_year: work.publication_year(),
+            types: Some(DataCiteTypes {
+                resource_type_general: resource_type_general.to_string(),
+                resource_type: work.document_type.as_ref().map(|kind| kind.as_str().to_string()),
+            }),
+            subjects: work.field_of_study.iter().map(|field| DataCiteSubject { subject: field.as_str().to_string() }).collect(),
+            dates,
+            language: work.language.as_ref().and_then(|language| language.code.clone()),
+            descriptions: work.abstract_text.iter().map(|description| DataCiteDescription {
+                description: description.clone(),
+                description_type: "Abstract".to_string(),
+            }).collect(),
+            container,
+            url: work.download_url.clone(),
+        }
+    }
+}
+
+impl From<&DataCiteRecord> for Work {
+    fn from(record: &DataCiteRecord) -> Self {
+        let identifiers = |kind: &'static str| record.identifiers.iter()
+            .filter(move |id| id.identifier_type.eq_ignore_ascii_case(kind))
+            .map(|id| id.identifier.as_str());
+        let date = |date_type: &str| record.date(date_type).map(ApiDate::parse);
+
+        let authors = record.creators.iter().map(|creator| Author {
+            name: match (&creator.family_name, &creator.given_name) {
+                (Some(family), Some(given)) => format!("{}, {}", family, given),
+                _ => creator.name.clone(),
+            },
+            orcid: creator.name_identifiers.iter()
+                .filter(|id| id.name_identifier_scheme.eq_ignore_ascii_case("ORCID"))
+                .map(|id| IdentifierValue::parse(&id.name_identifier))
+                .next(),
+        }).collect::<Vec<_>>();
+        let document_type = match &record.types {
+            Some(types) if types.resource_type.is_some() => types.resource_type.as_deref().map(DocumentType::from),
+            Some(types) => match types.resource_type_general.as_str() {
+                "Dissertation" => Some(DocumentType::Thesis),
+                "JournalArticle" | "Preprint" | "Text" | "ConferencePaper" | "Report" => Some(DocumentType::Research),
+                _ => None,
+            },
+            None => None,
+        };
+        let journals = record.container.as_ref().filter(|container| container.title.is_some()).map(|container| vec![JournalRef {
+            title: container.title.clone(),
+            identifiers: container.identifier.iter()
+                .filter(|_| container.identifier_type.as_deref().map(|t| t.eq_ignore_ascii_case("ISSN")).unwrap_or(true))
+                .map(|issn| format!("issn:{}", issn))
+                .collect(),
+        }]);
+        let oai_ids: Vec<IdentifierValue<OaiId>> = identifiers("OAI").map(IdentifierValue::parse).collect();
+
+        Work {
+            id: identifiers("CORE").find_map(|id| id.parse().ok()),
+            doi: record.doi.as_deref().map(IdentifierValue::parse),
+            arxiv_id: identifiers("arXiv").next().map(IdentifierValue::parse),
+            pubmed_id: identifiers("PMID").next().map(IdentifierValue::parse),
+            oai_ids: Some(oai_ids).filter(|ids| !ids.is_empty()),
+            authors: Some(authors).filter(|authors| !authors.is_empty()),
+            title: record.titles.iter().find(|title| title.title_type.is_none()).or(record.titles.first()).map(|title| title.title.clone()),
+            publisher: record.publisher.clone(),
+            year_published: record.publication_year,
+            published_date: date("Issued"),
+            accepted_date: date("Accepted"),
+            deposited_date: date("Submitted"),
+            created_date: date("Created"),
+            updated_date: date("Updated"),
+            document_type,
+            field_of_study: record.subjects.first().map(|subject| FieldOfStudy::from(subject.subject.as_str())),
+            language: record.language.as_deref().map(Language::from),
+            abstract_text: record.descriptions.iter()
+                .find(|description| description.description_type.eq_ignore_ascii_case("Abstract"))
+                .map(|description| description.description.clone()),
+            journals,
+            download_url: record.url.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Work {
+    /// Converts the work into a DataCite metadata record.
+    ///
+    /// ```
+    /// use core_api_client::{export::datacite::DataCiteRecord, Author, IdentifierValue, Work};
+    ///
+    /// let work = Work {
+    ///     title: Some("Deep learning".to_string()),
+    ///     authors: Some(vec![Author::from("Yann LeCun")]),
+    ///     doi: Some(IdentifierValue::parse("10.1038/nature14539")),
+    ///     year_published: Some(2015),
+    ///     ..Default::default()
+    /// };
+    /// let record = work.to_datacite();
+    /// assert_eq!(record.creators[0].name, "LeCun, Yann");
+    ///
+    /// let xml = record.to_xml();
+    /// assert!(xml.contains("<identifier identifierType=\"DOI\">10.1038/nature14539</identifier>"));
+    /// assert_eq!(DataCiteRecord::from_xml(&xml).unwrap(), record);
+    /// ```
+    pub fn to_datacite(&self) -> DataCiteRecord {
+        DataCiteRecord::from(self)
+    }
+}
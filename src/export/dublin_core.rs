@@ -0,0 +1,73 @@
+use std::io::Write;
+
+use crate::{errors::Error, helpers::graph_writer::{write_to_string, xml_escape}, Work};
+
+use super::{journal_title, publication_date};
+
+/// Writes the work as a Dublin Core record in the `oai_dc` format used by OAI-PMH. No XML declaration
+/// is written, so the record can be embedded in the `metadata` element of an OAI-PMH response.
+///
+/// Identifiers are written as resolvable URLs where possible (`https://doi.org/...`, `https://arxiv.org/abs/...`),
+/// the journal title as `dc:source` and the field of study as `dc:subject`.
+pub fn write_oai_dc<W: Write>(work: &Work, writer: &mut W) -> Result<(), Error> {
+    writeln!(
+        writer,
+        "<oai_dc:dc xmlns:oai_dc=\"http://www.openarchives.org/OAI/2.0/oai_dc/\" \
+         xmlns:dc=\"http://purl.org/dc/elements/1.1/\" \
+         xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+         xsi:schemaLocation=\"http://www.openarchives.org/OAI/2.0/oai_dc/ http://www.openarchives.org/OAI/2.0/oai_dc.xsd\">"
+    )?;
+    let mut element = |name: &str, value: &str| -> Result<(), Error> {
+        let value = value.trim();
+        if !value.is_empty() {
+            writeln!(writer, "  <dc:{name}>{}</dc:{name}>", xml_escape(value))?;
+        }
+        Ok(())
+    };
+
+    element("title", work.title.as_deref().unwrap_or_default())?;
+    for author in work.authors.iter().flatten() {
+        element("creator", &author.name)?;
+    }
+    for contributor in work.contributors.iter().flatten() {
+        element("contributor", contributor)?;
+    }
+    element("subject", work.field_of_study.as_ref().map_or("", |field| field.as_str()))?;
+    element("description", work.abstract_text.as_deref().unwrap_or_default())?;
+    element("publisher", work.publisher.as_deref().unwrap_or_default())?;
+    element("date", &publication_date(work).unwrap_or_default())?;
+    element("type", work.document_type.as_ref().map_or("", |kind| kind.as_str()))?;
+    element("identifier", &work.doi().map(|doi| doi.url()).unwrap_or_default())?;
+    element("identifier", &work.arxiv_id().map(|id| id.url()).unwrap_or_default())?;
+    element("identifier", &work.pubmed_id().map(|pmid| pmid.url()).unwrap_or_default())?;
+    for oai in work.oai_ids.iter().flatten() {
+        element("identifier", oai.as_str())?;
+    }
+    element("identifier", work.download_url.as_deref().unwrap_or_default())?;
+    element("source", &journal_title(work).unwrap_or_default())?;
+    element("language", work.language.as_ref().and_then(|language| language.code.as_deref()).unwrap_or_default())?;
+    writeln!(writer, "</oai_dc:dc>")?;
+    Ok(())
+}
+
+impl Work {
+    /// Formats the work as an `oai_dc` Dublin Core record, see `write_oai_dc`.
+    ///
+    /// ```
+    /// use core_api_client::{Author, Work};
+    ///
+    /// let work = Work {
+    ///     title: Some("Fish & chips".to_string()),
+    ///     authors: Some(vec![Author::from("Doe, Jane")]),
+    ///     year_published: Some(2020),
+    ///     ..Default::default()
+    /// };
+    /// let xml = work.to_oai_dc();
+    ///
+    /// assert!(xml.contains("  <dc:title>Fish &amp; chips</dc:title>\n  <dc:creator>Doe, Jane</dc:creator>\n"));
+    /// assert!(xml.contains("<dc:date>2020</dc:date>"));
+    /// ```
+    pub fn to_oai_dc(&self) -> String {
+        write_to_string(|buffer| write_oai_dc(self, buffer))
+    }
+}
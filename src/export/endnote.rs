@@ -0,0 +1,128 @@
+use std::io::Write;
+
+use crate::{errors::Error, helpers::graph_writer::{write_to_string, xml_escape}, responses::search::SearchResponse, DocumentType, Work};
+
+use super::{first_issn, journal_title};
+
+/// Returns the EndNote reference type of a work as `(number, name)`.
+pub fn endnote_type(work: &Work) -> (u32, &'static str) {
+    match &work.document_type {
+        Some(DocumentType::Thesis) => (32, "Thesis"),
+        Some(DocumentType::Slides) | Some(DocumentType::Presentation) => (3, "Audiovisual Material"),
+        _ if journal_title(work).is_some() => (17, "Journal Article"),
+        _ => (13, "Generic"),
+    }
+}
+
+/// Streams works as an EndNote XML document. The document header is written by `new`, every record
+/// is written as soon as it is passed to `write` and `finish` closes the document.
+///
+/// ```
+/// use core_api_client::{export::endnote::EndnoteXmlWriter, Work};
+///
+/// let mut buffer = vec![];
+/// let mut writer = EndnoteXmlWriter::new(&mut buffer).unwrap();
+/// writer.write(&Work { title: Some("Fish & chips".to_string()), ..Default::default() }).unwrap();
+/// writer.finish().unwrap();
+///
+/// let xml = String::from_utf8(buffer).unwrap();
+/// assert!(xml.contains("<titles><title>Fish &amp; chips</title></titles>"));
+/// assert!(xml.ends_with("</records></xml>\n"));
+/// ```
+#[derive(Debug)]
+pub struct EndnoteXmlWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> EndnoteXmlWriter<W> {
+    /// Writes the document header and returns the writer.
+    pub fn new(mut writer: W) -> Result<Self, Error> {
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(writer, "<xml><records>")?;
+        Ok(EndnoteXmlWriter { writer })
+    }
+
+    /// Writes a work as a record.
+    pub fn write(&mut self, work: &Work) -> Result<(), Error> {
+        let w = &mut self.writer;
+        let (number, name) = endnote_type(work);
+        write!(w, "<record>")?;
+        write!(w, "<source-app name=\"CORE\">CORE</source-app>")?;
+        write!(w, "<ref-type name=\"{}\">{}</ref-type>", name, number)?;
+
+        let authors: Vec<&str> = work.authors.iter().flatten().map(|author| author.name.trim()).filter(|name| !name.is_empty()).collect();
+        if !authors.is_empty() {
+            write!(w, "<contributors><authors>")?;
+            for author in authors {
+                write!(w, "<author>{}</author>", xml_escape(author))?;
+            }
+            write!(w, "</authors></contributors>")?;
+        }
+
+        let journal = journal_title(work);
+        if work.title.is_some() || journal.is_some() {
+            write!(w, "<titles>")?;
+            element(w, "title", work.title.as_deref())?;
+            element(w, "secondary-title", journal.as_deref())?;
+            write!(w, "</titles>")?;
+        }
+        if let Some(journal) = &journal {
+            write!(w, "<periodical><full-title>{}</full-title></periodical>", xml_escape(journal))?;
+        }
+        if let Some(year) = work.publication_year() {
+            write!(w, "<dates><year>{}</year>", year)?;
+            if let Some(date) = work.published_date.as_ref().and_then(|date| date.date()) {
+                write!(w, "<pub-dates><date>{}</date></pub-dates>", date.format("%Y-%m-%d"))?;
+            }
+            write!(w, "</dates>")?;
+        }
+        element(w, "publisher", work.publisher.as_deref())?;
+        element(w, "isbn", first_issn(work).as_ref().map(|issn| issn.as_str()))?;
+        element(w, "electronic-resource-num", work.doi.as_ref().map(|doi| doi.as_str()))?;
+        element(w, "abstract", work.abstract_text.as_deref())?;
+        element(w, "language", work.language.as_ref().and_then(|language| language.name.as_deref()))?;
+        if let Some(url) = &work.download_url {
+            write!(w, "<urls><related-urls><url>{}</url></related-urls></urls>", xml_escape(url))?;
+        }
+        writeln!(w, "</record>")?;
+        Ok(())
+    }
+
+    /// Closes the document and returns the underlying writer.
+    pub fn finish(mut self) -> Result<W, Error> {
+        writeln!(self.writer, "</records></xml>")?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+fn element<W: Write>(w: &mut W, name: &str, value: Option<&str>) -> Result<(), Error> {
+    match value.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(value) => Ok(write!(w, "<{name}>{}</{name}>", xml_escape(value))?),
+        None => Ok(()),
+    }
+}
+
+/// Streams the works as an EndNote XML document to a writer.
+pub fn write_endnote_xml<'a, W: Write>(works: impl IntoIterator<Item = &'a Work>, writer: &mut W) -> Result<(), Error> {
+    let mut endnote = EndnoteXmlWriter::new(writer)?;
+    for work in works {
+        endnote.write(work)?;
+    }
+    endnote.finish()?;
+    Ok(())
+}
+
+impl Work {
+    /// Formats the work as an EndNote XML document with a single record.
+    pub fn to_endnote_xml(&self) -> String {
+        write_to_string(|buffer| write_endnote_xml([self], buffer))
+    }
+}
+
+impl SearchResponse<Work> {
+    /// Streams the returned works as an EndNote XML document to a writer.
+    pub fn write_endnote_xml<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        write_endnote_xml(self.results.iter().flatten(), writer)
+    }
+}
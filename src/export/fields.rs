@@ -0,0 +1,280 @@
+use std::{fmt, str::FromStr};
+
+use crate::{errors::Error, ApiDate, Work};
+
+/// A column of a tabular export of works. Nested fields are flattened into lists of strings:
+/// `Authors` into names, `Identifiers` into `TYPE:value` pairs, `DataProviders` into names,
+/// `Journals` into titles and `Links` into URLs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WorkField {
+    /// CORE id.
+    Id,
+    /// Title.
+    Title,
+    /// DOI.
+    Doi,
+    /// arXiv identifier.
+    ArxivId,
+    /// PubMed id.
+    PubmedId,
+    /// Microsoft Academic Graph id.
+    MagId,
+    /// OAI identifiers.
+    OaiIds,
+    /// Author names.
+    Authors,
+    /// ORCIDs of the authors that have one.
+    AuthorOrcids,
+    /// Identifiers as `TYPE:value`.
+    Identifiers,
+    /// Data provider names.
+    DataProviders,
+    /// Journal titles.
+    Journals,
+    /// Publisher.
+    Publisher,
+    /// Publication year.
+    YearPublished,
+    /// Publication date as returned by the API.
+    PublishedDate,
+    /// Acceptance date as returned by the API.
+    AcceptedDate,
+    /// Deposit date as returned by the API.
+    DepositedDate,
+    /// Creation date of the record as returned by the API.
+    CreatedDate,
+    /// Last update of the record as returned by the API.
+    UpdatedDate,
+    /// Document type.
+    DocumentType,
+    /// Field of study.
+    FieldOfStudy,
+    /// Language code (or name when the code is missing).
+    Language,
+    /// Citation count.
+    CitationCount,
+    /// Abstract.
+    Abstract,
+    /// Download URL.
+    DownloadUrl,
+    /// Link URLs.
+    Links,
+    /// Full text URLs at the data providers.
+    SourceFulltextUrls,
+    /// Full text.
+    FullText,
+}
+
+/// Value of a field for a single work.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    /// The work has no value for the field.
+    Null,
+    /// A number.
+    Number(i64),
+    /// A single string.
+    Text(String),
+    /// A multi-valued field.
+    List(Vec<String>),
+}
+
+impl WorkField {
+    /// All fields, in the order of the API.
+    pub const ALL: [WorkField; 28] = [
+        WorkField::Id, WorkField::Title, WorkField::Doi, WorkField::ArxivId, WorkField::PubmedId, WorkField::MagId,
+        WorkField::OaiIds, WorkField::Authors, WorkField::AuthorOrcids, WorkField::Identifiers, WorkField::DataProviders,
+        WorkField::Journals, WorkField::Publisher, WorkField::YearPublished, WorkField::PublishedDate,
+        WorkField::AcceptedDate, WorkField::DepositedDate, WorkField::CreatedDate, WorkField::UpdatedDate,
+        WorkField::DocumentType, WorkField::FieldOfStudy, WorkField::Language, WorkField::CitationCount,
+        WorkField::Abstract, WorkField::DownloadUrl, WorkField::Links, WorkField::SourceFulltextUrls, WorkField::FullText,
+    ];
+
+    /// Column name of the field, matching the API name where there is one (e.g. `yearPublished`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            WorkField::Id => "id",
+            WorkField::Title => "title",
+            WorkField::Doi => "doi",
+            WorkField::ArxivId => "arxivId",
+            WorkField::PubmedId => "pubmedId",
+            WorkField::MagId => "magId",
+            WorkField::OaiIds => "oaiIds",
+            WorkField::Authors => "authors",
+            WorkField::AuthorOrcids => "authorOrcids",
+            WorkField::Identifiers => "identifiers",
+            WorkField::DataProviders => "dataProviders",
+            WorkField::Journals => "journals",
+            WorkField::Publisher => "publisher",
+            WorkField::YearPublished => "yearPublished",
+            WorkField::PublishedDate => "publishedDate",
+            WorkField::AcceptedDate => "acceptedDate",
+            WorkField::DepositedDate => "depositedDate",
+            WorkField::CreatedDate => "createdDate",
+            WorkField::UpdatedDate => "updatedDate",
+            WorkField::DocumentType => "documentType",
+            WorkField::FieldOfStudy => "fieldOfStudy",
+            WorkField::Language => "language",
+            WorkField::CitationCount => "citationCount",
+            WorkField::Abstract => "abstract",
+            WorkField::DownloadUrl => "downloadUrl",
+            WorkField::Links => "links",
+            WorkField::SourceFulltextUrls => "sourceFulltextUrls",
+            WorkField::FullText => "fullText",
+        }
+    }
+
+    /// Extracts the (flattened) value of the field from a work.
+    pub fn value(&self, work: &Work) -> FieldValue {
+        let text = |value: Option<String>| value.map_or(FieldValue::Null, FieldValue::Text);
+        let list = |values: Vec<String>| if values.is_empty() { FieldValue::Null } else { FieldValue::List(values) };
+        let date = |date: &Option<ApiDate>| text(date.as_ref().map(|date| date.raw.clone()));
+        match self {
+            WorkField::Id => work.id.map_or(FieldValue::Null, |id| FieldValue::Number(id as i64)),
+            WorkField::Title => text(work.title.clone()),
+            WorkField::Doi => text(work.doi.as_ref().map(|doi| doi.to_string())),
+            WorkField::ArxivId => text(work.arxiv_id.as_ref().map(|id| id.to_string())),
+            WorkField::PubmedId => text(work.pubmed_id.as_ref().map(|id| id.to_string())),
+            WorkField::MagId => text(work.mag_id.clone()),
+            WorkField::OaiIds => list(work.oai_ids.iter().flatten().map(|id| id.to_string()).collect()),
+            WorkField::Authors => list(work.authors.iter().flatten().map(|author| author.name.clone()).collect()),
+            WorkField::AuthorOrcids => list(work.orcids().iter().map(|orcid| orcid.to_string()).collect()),
+            WorkField::Identifiers => list(work.identifiers.iter().flatten()
+                .map(|id| format!("{}:{}", id.identifier_type.as_str(), id.identifier))
+                .collect()),
+            WorkField::DataProviders => list(work.data_providers.iter().flatten()
+                .map(|provider| if provider.name.is_empty() { provider.id.to_string() } else { provider.name.clone() })
+                .collect()),
+            WorkField::Journals => list(work.journals.iter().flatten().filter_map(|journal| journal.title.clone()).collect()),
+            WorkField::Publisher => text(work.publisher.clone()),
+            WorkField::YearPublished => work.publication_year().map_or(FieldValue::Null, |year| FieldValue::Number(year as i64)),
+            WorkField::PublishedDate => date(&work.published_date),
+            WorkField::AcceptedDate => date(&work.accepted_date),
+            WorkField::DepositedDate => date(&work.deposited_date),
+            WorkField::CreatedDate => date(&work.created_date),
+            WorkField::UpdatedDate => date(&work.updated_date),
+            WorkField::DocumentType => text(work.document_type.as_ref().map(|kind| kind.as_str().to_string())),
+            WorkField::FieldOfStudy => text(work.field_of_study.as_ref().map(|field| field.as_str().to_string())),
+            WorkField::Language => text(work.language.as_ref().and_then(|language| language.code.clone().or(language.name.clone()))),
+            WorkField::CitationCount => work.citation_count.map_or(FieldValue::Null, |count| FieldValue::Number(count as i64)),
+            WorkField::Abstract => text(work.abstract_text.clone()),
+            WorkField::DownloadUrl => text(work.download_url.clone()),
+            WorkField::Links => list(work.links.iter().flatten().map(|link| link.url().to_string()).collect()),
+            WorkField::SourceFulltextUrls => list(work.source_fulltext_urls.clone().unwrap_or_default()),
+            WorkField::FullText => text(work.full_text.clone()),
+        }
+    }
+}
+
+impl fmt::Display for WorkField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for WorkField {
+    type Err = Error;
+
+    /// Parses a field from its column name, ignoring case, `_` and `-` (so `year_published` works too).
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let normalized = |name: &str| name.chars().filter(|c| *c != '_' && *c != '-').collect::<String>().to_lowercase();
+        let value = normalized(value.trim());
+        WorkField::ALL.iter()
+            .find(|field| normalized(field.name()) == value)
+            .copied()
+            .ok_or_else(|| Error::Parsing(format!("unknown work field: {}", value)))
+    }
+}
+
+/// Selection of the exported fields and their order.
+///
+/// ```
+/// use core_api_client::export::fields::{FieldSelection, WorkField};
+///
+/// let selection = FieldSelection::parse("id, title,year_published").unwrap();
+/// assert_eq!(selection.fields(), &[WorkField::Id, WorkField::Title, WorkField::YearPublished]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSelection {
+    fields: Vec<WorkField>,
+}
+
+impl Default for FieldSelection {
+    /// Bibliographic fields, without abstracts, full texts and links.
+    fn default() -> Self {
+        FieldSelection::new(vec![
+            WorkField::Id, WorkField::Title, WorkField::Authors, WorkField::YearPublished, WorkField::Doi,
+            WorkField::Journals, WorkField::Publisher, WorkField::DocumentType, WorkField::Language,
+            WorkField::CitationCount, WorkField::DownloadUrl,
+        ])
+    }
+}
+
+impl FieldSelection {
+    /// Selects the given fields, in the given order.
+    pub fn new(fields: Vec<WorkField>) -> Self {
+        FieldSelection { fields }
+    }
+
+    /// Selects all fields.
+    pub fn all() -> Self {
+        FieldSelection::new(WorkField::ALL.to_vec())
+    }
+
+    /// Parses a comma separated list of field names.
+    pub fn parse(fields: &str) -> Result<Self, Error> {
+        let fields = fields.split(',')
+            .filter(|name| !name.trim().is_empty())
+            .map(WorkField::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(FieldSelection::new(fields))
+    }
+
+    /// Returns the selected fields.
+    pub fn fields(&self) -> &[WorkField] {
+        &self.fields
+    }
+}
+
+/// A field with different values in two works.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDifference {
+    /// The field.
+    pub field: WorkField,
+
+    /// Value in the first work.
+    pub left: FieldValue,
+
+    /// Value in the second work.
+    pub right: FieldValue,
+}
+
+/// Compares the selected fields of two works, e.g. a CORE work and a work imported from Crossref or
+/// DataCite, and returns the fields with different values. Text is compared ignoring surrounding
+/// whitespace, lists ignoring their order.
+///
+/// ```
+/// use core_api_client::{export::fields::{compare_works, FieldSelection, WorkField}, Work};
+///
+/// let core = Work { title: Some("Deep learning".to_string()), year_published: Some(2015), ..Default::default() };
+/// let other = Work { title: Some("Deep learning ".to_string()), year_published: Some(2016), ..Default::default() };
+/// let differences = compare_works(&core, &other, &FieldSelection::default());
+///
+/// assert_eq!(differences.len(), 1);
+/// assert_eq!(differences[0].field, WorkField::YearPublished);
+/// ```
+pub fn compare_works(left: &Work, right: &Work, fields: &FieldSelection) -> Vec<FieldDifference> {
+    let normalized = |value: FieldValue| match value {
+        FieldValue::Text(text) => FieldValue::Text(text.trim().to_string()),
+        FieldValue::List(mut values) => {
+            values.iter_mut().for_each(|value| *value = value.trim().to_string());
+            values.sort();
+            FieldValue::List(values)
+        },
+        value => value,
+    };
+    fields.fields().iter()
+        .map(|field| (field, field.value(left), field.value(right)))
+        .filter(|(_, left, right)| normalized(left.clone()) != normalized(right.clone()))
+        .map(|(field, left, right)| FieldDifference { field: *field, left, right })
+        .collect()
+}
@@ -0,0 +1,78 @@
+use std::io::Write;
+
+use crate::{errors::Error, Work};
+
+use super::fields::{FieldSelection, FieldValue};
+
+/// Streams works as JSON Lines, one JSON object per line.
+///
+/// Without a field selection every work is written as returned by the API. With a selection only the
+/// selected fields are written, in the selected order, with nested fields flattened (see `WorkField`);
+/// missing values are written as `null` and multi-valued fields as arrays.
+///
+/// ```
+/// use core_api_client::{export::{fields::FieldSelection, jsonl::JsonLinesWriter}, Author, Work};
+///
+/// let work = Work { id: Some(1), authors: Some(vec![Author::from("Ada Lovelace")]), ..Default::default() };
+/// let mut writer = JsonLinesWriter::new(vec![]).fields(FieldSelection::parse("id,title,authors").unwrap());
+/// writer.write(&work).unwrap();
+///
+/// let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+/// assert_eq!(output, "{\"id\":1,\"title\":null,\"authors\":[\"Ada Lovelace\"]}\n");
+/// ```
+#[derive(Debug)]
+pub struct JsonLinesWriter<W: Write> {
+    writer: W,
+    fields: Option<FieldSelection>,
+}
+
+impl<W: Write> JsonLinesWriter<W> {
+    /// Creates a writer exporting complete works.
+    pub fn new(writer: W) -> Self {
+        JsonLinesWriter { writer, fields: None }
+    }
+
+    /// Restricts the export to the selected fields.
+    pub fn fields(self, fields: FieldSelection) -> Self {
+        Self { fields: Some(fields), ..self }
+    }
+
+    /// Writes a work as a line.
+    pub fn write(&mut self, work: &Work) -> Result<(), Error> {
+        match &self.fields {
+            None => serde_json::to_writer(&mut self.writer, work)?,
+            Some(selection) => {
+                let mut object = vec![];
+                for field in selection.fields() {
+                    let value = match field.value(work) {
+                        FieldValue::Null => serde_json::Value::Null,
+                        FieldValue::Number(number) => serde_json::Value::from(number),
+                        FieldValue::Text(text) => serde_json::Value::from(text),
+                        FieldValue::List(values) => serde_json::Value::from(values),
+                    };
+                    object.push(format!("{}:{}", serde_json::Value::from(field.name()), value));
+                }
+                write!(self.writer, "{{{}}}", object.join(","))?;
+            },
+        }
+        writeln!(self.writer)?;
+        Ok(())
+    }
+
+    /// Writes all works of an iterator (e.g. `Api::iter_works`), stopping at the first error. Returns the
+    /// number of written works.
+    pub fn write_all(&mut self, works: impl IntoIterator<Item = Result<Work, Error>>) -> Result<usize, Error> {
+        let mut count = 0;
+        for work in works {
+            self.write(&work?)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Flushes and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W, Error> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
@@ -0,0 +1,44 @@
+pub mod bibtex;
+pub mod ris;
+pub mod endnote;
+pub mod csl;
+pub mod citation;
+pub mod fields;
+pub mod jsonl;
+pub mod csv;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod schema_org;
+pub mod dublin_core;
+pub mod datacite;
+pub mod crossref;
+
+use crate::{Issn, Work};
+
+/// Title of the first journal of a work that has one.
+pub(crate) fn journal_title(work: &Work) -> Option<String> {
+    work.journals.iter().flatten().find_map(|journal| journal.title.clone())
+}
+
+/// First ISSN listed for the journals of a work.
+pub(crate) fn first_issn(work: &Work) -> Option<Issn> {
+    work.journals.iter().flatten().flat_map(|journal| journal.issns()).next()
+}
+
+/// ISO 8601 date with the precision of the API date (`2019`, `2019-05` or `2019-05-21`).
+pub(crate) fn iso_date(date: &crate::ApiDate) -> Option<String> {
+    use crate::DatePrecision;
+    let value = date.value?;
+    match date.precision {
+        Some(DatePrecision::Year) => Some(value.format("%Y").to_string()),
+        Some(DatePrecision::Month) => Some(value.format("%Y-%m").to_string()),
+        _ => Some(value.format("%Y-%m-%d").to_string()),
+    }
+}
+
+/// Publication date of a work, falling back to its publication year.
+pub(crate) fn publication_date(work: &Work) -> Option<String> {
+    work.published_date.as_ref()
+        .and_then(iso_date)
+        .or_else(|| work.publication_year().map(|year| year.to_string()))
+}
@@ -0,0 +1,90 @@
+use std::io::Write;
+
+use crate::{errors::Error, helpers::graph_writer::write_to_string, responses::search::SearchResponse, DocumentType, Work};
+
+use super::{first_issn, journal_title};
+
+/// Returns the RIS reference type of a work: `THES` for theses, `SLIDE` for slides and presentations,
+/// `JOUR` for works published in a journal and `GEN` otherwise.
+pub fn ris_type(work: &Work) -> &'static str {
+    match &work.document_type {
+        Some(DocumentType::Thesis) => "THES",
+        Some(DocumentType::Slides) | Some(DocumentType::Presentation) => "SLIDE",
+        _ if journal_title(work).is_some() => "JOUR",
+        _ => "GEN",
+    }
+}
+
+/// Writes a single work as a RIS record, ending with the `ER` tag. Lines end with CR LF as required by
+/// the RIS specification.
+pub fn write_ris_record<W: Write>(work: &Work, writer: &mut W) -> Result<(), Error> {
+    let mut tag = |tag: &str, value: &str| -> Result<(), Error> {
+        let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !value.is_empty() {
+            write!(writer, "{}  - {}\r\n", tag, value)?;
+        }
+        Ok(())
+    };
+    tag("TY", ris_type(work))?;
+    for author in work.authors.iter().flatten() {
+        tag("AU", &author.name)?;
+    }
+    tag("TI", work.title.as_deref().unwrap_or_default())?;
+    tag("PY", &work.publication_year().map(|year| year.to_string()).unwrap_or_default())?;
+    if let Some(date) = work.published_date.as_ref().and_then(|date| date.date()) {
+        tag("DA", &date.format("%Y/%m/%d").to_string())?;
+    }
+    tag("JO", &journal_title(work).unwrap_or_default())?;
+    tag("PB", work.publisher.as_deref().unwrap_or_default())?;
+    tag("SN", &first_issn(work).map(|issn| issn.to_string()).unwrap_or_default())?;
+    tag("DO", &work.doi.as_ref().map(|doi| doi.to_string()).unwrap_or_default())?;
+    tag("UR", work.download_url.as_deref().unwrap_or_default())?;
+    if let Some(language) = &work.language {
+        tag("LA", language.name.as_deref().unwrap_or_default())?;
+    }
+    tag("AB", work.abstract_text.as_deref().unwrap_or_default())?;
+    if let Some(id) = work.id {
+        tag("ID", &id.to_string())?;
+    }
+    write!(writer, "ER  - \r\n")?;
+    Ok(())
+}
+
+/// Streams the works as RIS records to a writer, one record at a time.
+pub fn write_ris<'a, W: Write>(works: impl IntoIterator<Item = &'a Work>, writer: &mut W) -> Result<(), Error> {
+    for work in works {
+        write_ris_record(work, writer)?;
+        write!(writer, "\r\n")?;
+    }
+    Ok(())
+}
+
+impl Work {
+    /// Formats the work as a RIS record.
+    ///
+    /// ```
+    /// use core_api_client::{Author, Work};
+    ///
+    /// let work = Work {
+    ///     title: Some("Deep learning".to_string()),
+    ///     authors: Some(vec![Author::from("LeCun, Yann"), Author::from("Bengio, Yoshua")]),
+    ///     year_published: Some(2015),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     work.to_ris(),
+    ///     "TY  - GEN\r\nAU  - LeCun, Yann\r\nAU  - Bengio, Yoshua\r\nTI  - Deep learning\r\nPY  - 2015\r\nER  - \r\n"
+    /// );
+    /// ```
+    pub fn to_ris(&self) -> String {
+        write_to_string(|buffer| write_ris_record(self, buffer))
+    }
+}
+
+impl SearchResponse<Work> {
+    /// Streams the returned works as RIS records to a writer.
+    pub fn write_ris<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        write_ris(self.results.iter().flatten(), writer)
+    }
+}
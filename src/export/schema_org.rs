@@ -0,0 +1,169 @@
+use serde_json::{json, Map, Value};
+
+use crate::{DataProvider, DocumentType, Work};
+
+use super::{first_issn, journal_title, publication_date};
+
+impl Work {
+    /// Describes the work as a schema.org `ScholarlyArticle` (`Thesis` for theses) in JSON-LD, e.g. for
+    /// embedding in a landing page. Empty properties are left out.
+    ///
+    /// ```
+    /// use core_api_client::{Author, IdentifierValue, Work};
+    ///
+    /// let work = Work {
+    ///     title: Some("Deep learning".to_string()),
+    ///     authors: Some(vec![Author::from("LeCun, Yann")]),
+    ///     doi: Some(IdentifierValue::parse("10.1038/nature14539")),
+    ///     year_published: Some(2015),
+    ///     ..Default::default()
+    /// };
+    /// let json_ld = work.to_json_ld();
+    ///
+    /// assert_eq!(json_ld["@type"], "ScholarlyArticle");
+    /// assert_eq!(json_ld["name"], "Deep learning");
+    /// assert_eq!(json_ld["author"][0]["familyName"], "LeCun");
+    /// assert_eq!(json_ld["datePublished"], "2015");
+    /// assert_eq!(json_ld["sameAs"], "https://doi.org/10.1038/nature14539");
+    /// ```
+    pub fn to_json_ld(&self) -> Value {
+        let kind = match self.document_type {
+            Some(DocumentType::Thesis) => "Thesis",
+            _ => "ScholarlyArticle",
+        };
+        let mut object = Map::new();
+        object.insert("@context".to_string(), json!("https://schema.org"));
+        object.insert("@type".to_string(), json!(kind));
+
+        let mut identifiers = vec![];
+        if let Some(id) = self.id {
+            identifiers.push(property_value("CORE", &id.to_string()));
+        }
+        if let Some(doi) = &self.doi {
+            identifiers.push(property_value("DOI", doi.as_str()));
+        }
+        if let Some(arxiv_id) = &self.arxiv_id {
+            identifiers.push(property_value("arXiv", arxiv_id.as_str()));
+        }
+        if let Some(pmid) = &self.pubmed_id {
+            identifiers.push(property_value("PMID", pmid.as_str()));
+        }
+        for oai in self.oai_ids.iter().flatten() {
+            identifiers.push(property_value("OAI", oai.as_str()));
+        }
+
+        let authors: Vec<Value> = self.authors.iter().flatten()
+            .filter(|author| !author.name.trim().is_empty())
+            .map(|author| {
+                let name = author.parsed_name();
+                let mut person = Map::new();
+                person.insert("@type".to_string(), json!("Person"));
+                person.insert("name".to_string(), json!(author.name.trim()));
+                insert(&mut person, "givenName", Some(name.given.join(" ")));
+                insert(&mut person, "familyName", Some(name.family));
+                insert(&mut person, "identifier", author.orcid().map(|orcid| orcid.url()));
+                Value::Object(person)
+            })
+            .collect();
+
+        insert(&mut object, "name", self.title.clone());
+        insert(&mut object, "headline", self.title.clone());
+        if !authors.is_empty() {
+            object.insert("author".to_string(), Value::Array(authors));
+        }
+        insert(&mut object, "datePublished", publication_date(self));
+        insert(&mut object, "abstract", self.abstract_text.clone());
+        insert(&mut object, "inLanguage", self.language.as_ref().and_then(|language| language.code.clone()));
+        insert(&mut object, "about", self.field_of_study.as_ref().map(|field| field.as_str().to_string()));
+        if let Some(publisher) = self.publisher.as_ref().filter(|p| !p.trim().is_empty()) {
+            object.insert("publisher".to_string(), json!({ "@type": "Organization", "name": publisher }));
+        }
+        if let Some(journal) = journal_title(self) {
+            let mut periodical = Map::new();
+            periodical.insert("@type".to_string(), json!("Periodical"));
+            periodical.insert("name".to_string(), json!(journal));
+            insert(&mut periodical, "issn", first_issn(self).map(|issn| issn.to_string()));
+            object.insert("isPartOf".to_string(), Value::Object(periodical));
+        }
+        if !identifiers.is_empty() {
+            object.insert("identifier".to_string(), Value::Array(identifiers));
+        }
+        insert(&mut object, "sameAs", self.doi().map(|doi| doi.url()));
+        insert(&mut object, "url", self.display_url().or(self.reader_url()).map(str::to_string));
+        insert(&mut object, "thumbnailUrl", self.thumbnail_url().map(str::to_string));
+        if let Some(download) = self.download_link().map(str::to_string).or(self.download_url.clone()) {
+            object.insert("encoding".to_string(), json!({ "@type": "MediaObject", "contentUrl": download }));
+        }
+        if let Some(count) = self.citation_count {
+            object.insert("citationCount".to_string(), json!(count));
+        }
+        Value::Object(object)
+    }
+}
+
+impl DataProvider {
+    /// Describes the data provider as a schema.org `Organization` in JSON-LD. Empty properties are left out.
+    ///
+    /// ```
+    /// use core_api_client::DataProvider;
+    ///
+    /// let provider = DataProvider {
+    ///     id: 86,
+    ///     name: Some("Open Research Online".to_string()),
+    ///     homepage_url: Some("https://oro.open.ac.uk".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let json_ld = provider.to_json_ld();
+    ///
+    /// assert_eq!(json_ld["@type"], "Organization");
+    /// assert_eq!(json_ld["url"], "https://oro.open.ac.uk");
+    /// assert_eq!(json_ld["identifier"][0]["value"], "86");
+    /// ```
+    pub fn to_json_ld(&self) -> Value {
+        let mut object = Map::new();
+        object.insert("@context".to_string(), json!("https://schema.org"));
+        object.insert("@type".to_string(), json!("Organization"));
+        insert(&mut object, "name", self.name.clone());
+
+        let aliases: Vec<&String> = self.aliases.iter().flatten().filter(|alias| !alias.trim().is_empty()).collect();
+        if !aliases.is_empty() {
+            object.insert("alternateName".to_string(), json!(aliases));
+        }
+        insert(&mut object, "url", self.homepage_url.clone());
+        insert(&mut object, "email", self.email.clone());
+        insert(&mut object, "logo", self.logo.clone());
+
+        let mut identifiers = vec![property_value("CORE", &self.id.to_string())];
+        if let Some(open_doar_id) = self.open_doar_id {
+            identifiers.push(property_value("OpenDOAR", &open_doar_id.to_string()));
+        }
+        object.insert("identifier".to_string(), Value::Array(identifiers));
+        if let Some(ror) = self.ror_id.as_ref().filter(|ror| !ror.trim().is_empty()) {
+            let ror = if ror.starts_with("http") { ror.clone() } else { format!("https://ror.org/{}", ror) };
+            object.insert("sameAs".to_string(), json!(ror));
+        }
+
+        if let Some(location) = &self.location {
+            if let Some(country) = location.country_code.as_ref().filter(|c| !c.trim().is_empty()) {
+                object.insert("address".to_string(), json!({ "@type": "PostalAddress", "addressCountry": country.to_uppercase() }));
+            }
+            if let (Some(latitude), Some(longitude)) = (location.latitude, location.longitude) {
+                object.insert("geo".to_string(), json!({ "@type": "GeoCoordinates", "latitude": latitude, "longitude": longitude }));
+            }
+        }
+        if let Some(institution) = self.institution_name.as_ref().filter(|i| !i.trim().is_empty()) {
+            object.insert("parentOrganization".to_string(), json!({ "@type": "Organization", "name": institution }));
+        }
+        Value::Object(object)
+    }
+}
+
+fn property_value(property: &str, value: &str) -> Value {
+    json!({ "@type": "PropertyValue", "propertyID": property, "value": value })
+}
+
+fn insert(object: &mut Map<String, Value>, key: &str, value: Option<String>) {
+    if let Some(value) = value.filter(|value| !value.trim().is_empty()) {
+        object.insert(key.to_string(), Value::String(value.trim().to_string()));
+    }
+}
@@ -0,0 +1,174 @@
+use std::io::Write;
+
+use crate::errors::Error;
+
+/// Type of a node attribute in the exported graph.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum AttributeType {
+    String,
+    Int,
+    Double,
+}
+
+impl AttributeType {
+    /// Name of the type in GraphML `attr.type`.
+    fn graphml_name(&self) -> &'static str {
+        match self {
+            AttributeType::String => "string",
+            AttributeType::Int => "int",
+            AttributeType::Double => "double",
+        }
+    }
+
+    /// Name of the type in GEXF 1.2 attribute declarations.
+    fn gexf_name(&self) -> &'static str {
+        match self {
+            AttributeType::String => "string",
+            AttributeType::Int => "integer",
+            AttributeType::Double => "double",
+        }
+    }
+}
+
+/// Format independent view of a graph, used by the GraphML, GEXF and DOT writers.
+/// Node attribute values are given in the order of `node_attributes`.
+#[derive(Debug, Clone)]
+pub(crate) struct GraphData {
+    pub(crate) name: String,
+    pub(crate) directed: bool,
+    pub(crate) node_attributes: Vec<(&'static str, AttributeType)>,
+    pub(crate) nodes: Vec<GraphNode>,
+    pub(crate) edges: Vec<GraphEdge>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct GraphNode {
+    pub(crate) id: String,
+    pub(crate) label: String,
+    pub(crate) attributes: Vec<Option<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct GraphEdge {
+    pub(crate) source: String,
+    pub(crate) target: String,
+    pub(crate) weight: Option<f64>,
+}
+
+pub(crate) fn write_graphml<W: Write>(graph: &GraphData, mut w: W) -> Result<(), Error> {
+    writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(w, r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#)?;
+    writeln!(w, r#"  <key id="label" for="node" attr.name="label" attr.type="string"/>"#)?;
+    for (i, (name, kind)) in graph.node_attributes.iter().enumerate() {
+        writeln!(w, r#"  <key id="d{}" for="node" attr.name="{}" attr.type="{}"/>"#, i, xml_escape(name), kind.graphml_name())?;
+    }
+    writeln!(w, r#"  <key id="weight" for="edge" attr.name="weight" attr.type="double"/>"#)?;
+    let edge_default = if graph.directed { "directed" } else { "undirected" };
+    writeln!(w, r#"  <graph id="{}" edgedefault="{}">"#, xml_escape(&graph.name), edge_default)?;
+    for node in graph.nodes.iter() {
+        writeln!(w, r#"    <node id="{}">"#, xml_escape(&node.id))?;
+        writeln!(w, r#"      <data key="label">{}</data>"#, xml_escape(&node.label))?;
+        for (i, value) in node.attributes.iter().enumerate() {
+            if let Some(value) = value {
+                writeln!(w, r#"      <data key="d{}">{}</data>"#, i, xml_escape(value))?;
+            }
+        }
+        writeln!(w, "    </node>")?;
+    }
+    for edge in graph.edges.iter() {
+        match edge.weight {
+            Some(weight) => {
+                writeln!(w, r#"    <edge source="{}" target="{}">"#, xml_escape(&edge.source), xml_escape(&edge.target))?;
+                writeln!(w, r#"      <data key="weight">{}</data>"#, weight)?;
+                writeln!(w, "    </edge>")?;
+            },
+            None => writeln!(w, r#"    <edge source="{}" target="{}"/>"#, xml_escape(&edge.source), xml_escape(&edge.target))?,
+        }
+    }
+    writeln!(w, "  </graph>")?;
+    writeln!(w, "</graphml>")?;
+    Ok(())
+}
+
+pub(crate) fn write_gexf<W: Write>(graph: &GraphData, mut w: W) -> Result<(), Error> {
+    writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(w, r#"<gexf xmlns="http://gexf.net/1.3" version="1.3">"#)?;
+    let edge_type = if graph.directed { "directed" } else { "undirected" };
+    writeln!(w, r#"  <graph mode="static" defaultedgetype="{}">"#, edge_type)?;
+    writeln!(w, r#"    <attributes class="node">"#)?;
+    for (i, (name, kind)) in graph.node_attributes.iter().enumerate() {
+        writeln!(w, r#"      <attribute id="{}" title="{}" type="{}"/>"#, i, xml_escape(name), kind.gexf_name())?;
+    }
+    writeln!(w, "    </attributes>")?;
+    writeln!(w, "    <nodes>")?;
+    for node in graph.nodes.iter() {
+        writeln!(w, r#"      <node id="{}" label="{}">"#, xml_escape(&node.id), xml_escape(&node.label))?;
+        writeln!(w, "        <attvalues>")?;
+        for (i, value) in node.attributes.iter().enumerate() {
+            if let Some(value) = value {
+                writeln!(w, r#"          <attvalue for="{}" value="{}"/>"#, i, xml_escape(value))?;
+            }
+        }
+        writeln!(w, "        </attvalues>")?;
+        writeln!(w, "      </node>")?;
+    }
+    writeln!(w, "    </nodes>")?;
+    writeln!(w, "    <edges>")?;
+    for (i, edge) in graph.edges.iter().enumerate() {
+        let weight = edge.weight.map_or(String::new(), |weight| format!(r#" weight="{}""#, weight));
+        writeln!(w, r#"      <edge id="{}" source="{}" target="{}"{}/>"#, i, xml_escape(&edge.source), xml_escape(&edge.target), weight)?;
+    }
+    writeln!(w, "    </edges>")?;
+    writeln!(w, "  </graph>")?;
+    writeln!(w, "</gexf>")?;
+    Ok(())
+}
+
+pub(crate) fn write_dot<W: Write>(graph: &GraphData, mut w: W) -> Result<(), Error> {
+    let (kind, arrow) = if graph.directed { ("digraph", "->") } else { ("graph", "--") };
+    writeln!(w, "{} \"{}\" {{", kind, dot_escape(&graph.name))?;
+    for node in graph.nodes.iter() {
+        writeln!(w, "  \"{}\" [label=\"{}\"];", dot_escape(&node.id), dot_escape(&node.label))?;
+    }
+    for edge in graph.edges.iter() {
+        match edge.weight {
+            Some(weight) => writeln!(w, "  \"{}\" {} \"{}\" [weight={}];", dot_escape(&edge.source), arrow, dot_escape(&edge.target), weight)?,
+            None => writeln!(w, "  \"{}\" {} \"{}\";", dot_escape(&edge.source), arrow, dot_escape(&edge.target))?,
+        }
+    }
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+/// Runs a writer function against an in-memory buffer and returns the written text.
+pub(crate) fn write_to_string<F>(write: F) -> String
+where
+    F: FnOnce(&mut Vec<u8>) -> Result<(), Error>,
+{
+    let mut buf = vec![];
+    // writing into a Vec can not fail
+    let _ = write(&mut buf);
+    String::from_utf8_lossy(&buf).into_owned()
+}
+
+/// Escapes the XML special characters of a value and drops characters not allowed in XML 1.0.
+pub(crate) fn xml_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if (c as u32) < 0x20 => (),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
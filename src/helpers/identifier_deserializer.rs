@@ -0,0 +1,39 @@
+use serde::{Deserializer, Deserialize};
+use std::str::FromStr;
+
+use crate::IdentifierValue;
+
+/// Custom deserialization function for optional identifier fields. Values that are not valid
+/// identifiers are kept as `IdentifierValue::Unparsed` instead of failing the whole response,
+/// empty values are `None`.
+pub(crate) fn deserialize_identifier<'de, D, T>(deserializer: D) -> Result<Option<IdentifierValue<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    let opt = Option::deserialize(deserializer)?;
+    Ok(opt.and_then(|value| parse_value(&value)))
+}
+
+/// Custom deserialization function for identifier lists. Invalid entries are kept as
+/// `IdentifierValue::Unparsed`, empty entries are skipped.
+pub(crate) fn deserialize_identifiers<'de, D, T>(deserializer: D) -> Result<Option<Vec<IdentifierValue<T>>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    let opt: Option<Vec<serde_json::Value>> = Option::deserialize(deserializer)?;
+    Ok(opt.map(|values| values.iter().filter_map(parse_value).collect()))
+}
+
+fn parse_value<T: FromStr>(value: &serde_json::Value) -> Option<IdentifierValue<T>> {
+    let raw = match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Number(n) => n.to_string(),
+        _ => return None,
+    };
+    if raw.trim().is_empty() {
+        return None;
+    }
+    Some(IdentifierValue::parse(&raw))
+}
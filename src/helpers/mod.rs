@@ -1,2 +1,4 @@
 pub mod response_handler;
-pub(crate) mod string_number_deserializer;
\ No newline at end of file
+pub(crate) mod string_number_deserializer;
+pub(crate) mod identifier_deserializer;
+pub(crate) mod graph_writer;
\ No newline at end of file
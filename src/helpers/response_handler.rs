@@ -6,24 +6,33 @@ use serde_path_to_error::deserialize;
 use crate::errors::Error;
 
 
-pub(crate) fn parse_raw_response(
+/// Reads the status, body and remaining rate limit of a response. The status is checked separately by
+/// `parse_raw_response`, so that responses can be recorded as they were received.
+pub(crate) fn read_raw_response(
     resp: Response
-) -> Result<(String, Option<i32>), crate::errors::Error> {
-    match resp.status() {
-        StatusCode::UNAUTHORIZED => return Err(crate::errors::Error::InvalidApiKey),
-        StatusCode::INTERNAL_SERVER_ERROR => return Err(extract_error(resp)),
-        _ => ()
-    };
-
+) -> Result<(u16, String, Option<i32>), crate::errors::Error> {
+    let status = resp.status().as_u16();
     let rate_limit = extraxt_rate_limit(&resp);    
 
     // Get the response text
     match resp.text() {
-        Ok(t) => Ok((t, rate_limit)),
+        Ok(t) => Ok((status, t, rate_limit)),
         Err(e) => Err(crate::errors::Error::Request(e)),
     }
 }
 
+pub(crate) fn parse_raw_response(
+    status: u16,
+    data: String,
+    rate_limit: Option<i32>,
+) -> Result<(String, Option<i32>), crate::errors::Error> {
+    match StatusCode::from_u16(status) {
+        Ok(StatusCode::UNAUTHORIZED) => Err(crate::errors::Error::InvalidApiKey),
+        Ok(StatusCode::INTERNAL_SERVER_ERROR) => Err(crate::errors::Error::Parsing(data)),
+        _ => Ok((data, rate_limit)),
+    }
+}
+
 pub(crate) fn parse_json<T>(data: &str) -> Result<T, crate::errors::Error> where T: DeserializeOwned {
     let deserializer = &mut Deserializer::from_str(data);
     let res: Result<T, _> = deserialize(deserializer);
@@ -33,13 +42,6 @@ pub(crate) fn parse_json<T>(data: &str) -> Result<T, crate::errors::Error> where
     }
 }
 
-fn extract_error(resp: Response) -> crate::errors::Error {
-    match resp.text() {
-        Ok(t) => crate::errors::Error::Parsing(t),
-        Err(e) => crate::errors::Error::Request(e),
-    }
-}
-
 fn extraxt_rate_limit(resp: &Response) -> Option<i32> {
     if let Some(rate) = resp.headers().get("x-ratelimit-remaining") {
         let rate_str = match rate.to_str() {
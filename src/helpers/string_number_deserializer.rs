@@ -12,3 +12,49 @@ where
         _ => Ok(None),
     }
 }
+
+/// Custom deserialization function that deserializes a year given either as a number or as a string.
+/// Strings holding a full date (e.g. `2019-05-21`) are reduced to their year, unparseable values are `None`.
+pub(crate) fn deserialize_as_year<'de, D>(deserializer: D) -> Result<Option<i32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt = Option::deserialize(deserializer)?;
+    match opt {
+        Some(serde_json::Value::Number(n)) => Ok(n.as_i64().and_then(|n| i32::try_from(n).ok())),
+        Some(serde_json::Value::String(s)) => Ok(crate::ApiDate::parse(s).year()),
+        _ => Ok(None),
+    }
+}
+
+/// Custom deserialization function that deserializes a number given either as a number or as a string.
+pub(crate) fn deserialize_as_number<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt = Option::deserialize(deserializer)?;
+    match opt {
+        Some(serde_json::Value::Number(n)) => Ok(n.as_i64()),
+        Some(serde_json::Value::String(s)) => Ok(s.trim().parse().ok()),
+        _ => Ok(None),
+    }
+}
+
+/// Custom deserialization function for CSL/Crossref `date-parts`. Parts may be numbers or strings,
+/// each date is cut off at its first `null` or unparseable part, so `[[null]]` gives an empty date.
+pub(crate) fn deserialize_date_parts<'de, D>(deserializer: D) -> Result<Vec<Vec<i32>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt: Option<Vec<Option<Vec<serde_json::Value>>>> = Option::deserialize(deserializer)?;
+    let dates = opt.unwrap_or_default().into_iter().map(|parts| {
+        parts.unwrap_or_default().iter()
+            .map_while(|part| match part {
+                serde_json::Value::Number(n) => n.as_i64().and_then(|n| i32::try_from(n).ok()),
+                serde_json::Value::String(s) => s.trim().parse().ok(),
+                _ => None,
+            })
+            .collect()
+    });
+    Ok(dates.collect())
+}
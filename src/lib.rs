@@ -2,14 +2,47 @@ mod helpers;
 mod models;
 pub mod errors;
 pub mod responses;
+pub mod citations;
+pub mod authors;
+pub mod dedup;
+pub mod export;
+pub mod cache;
+pub mod cassette;
+#[cfg(feature = "sqlite")]
+pub mod store;
+pub mod sync;
 
 pub use models::api::Api;
 pub use models::query_models::search_query::SearchQuery;
 pub use models::query_models::filter_operator::FilterOperator;
 pub use models::work_models::work::Work;
+pub use models::output_models::output::Output;
+pub use models::work_models::language::Language;
+pub use models::work_models::identifier::{Identifier, IdentifierKind};
+pub use models::work_models::link::{Link, LinkKind, LinkType};
+pub use models::work_models::document_type::DocumentType;
+pub use models::work_models::field_of_study::FieldOfStudy;
+pub use models::date_models::date::{ApiDate, DatePrecision};
+pub use models::identifier_models::{
+    doi::Doi,
+    orcid::Orcid,
+    issn::Issn,
+    arxiv::ArxivId,
+    pmid::Pmid,
+    oai::OaiId,
+    work_identifier::WorkIdentifier,
+    value::IdentifierValue,
+};
 pub use models::data_provider_models::data_provider::DataProvider;
+pub use models::data_provider_models::stats::DataProviderStats;
+pub use models::data_provider_models::data_provider_type::DataProviderType;
+pub use models::data_provider_models::software::RepositorySoftware;
 pub use models::discovery_models::discovery::Discovery;
 pub use models::journal_models::journal::Journal;
+pub use models::work_models::data_provider::DataProviderRef;
+pub use models::work_models::journal::JournalRef;
+pub use models::work_models::reference::Reference;
+pub use models::work_models::author::Author;
 
 #[cfg(test)]
 mod tests {
@@ -18,7 +51,8 @@ mod tests {
         data_provider_models::data_provider::DataProvider,
         journal_models::journal::Journal, 
         query_models::query::Query,
-        work_models::work::Work
+        work_models::work::Work,
+        output_models::output::Output,
     }, responses::response::ApiResponse};
 
     use static_assertions::assert_impl_all;
@@ -48,8 +82,1119 @@ mod tests {
         assert_impl_all!(Work: Send, Sync);
     }
 
+    #[test]
+    fn test_send_sync_output() {
+        assert_impl_all!(Output: Send, Sync);
+    }
+
     #[test]
     fn test_send_sync_api_response() {
         assert_impl_all!(ApiResponse<Work>: Send, Sync);
     }
+
+    #[test]
+    fn test_output_into_work() {
+        let data = r#"{
+            "id": 42,
+            "abstract": "An abstract",
+            "dataProvider": { "id": 86, "name": "Provider", "url": "https://api.core.ac.uk/v3/data-providers/86", "logo": "" },
+            "identifiers": { "doi": "10.1000/xyz", "oai": "oai:provider:1" },
+            "language": { "code": "en", "name": "English" },
+            "oai": "oai:provider:1",
+            "yearPublished": 2019
+        }"#;
+        let output: Output = crate::helpers::response_handler::parse_json(data).unwrap();
+        let work: Work = output.into();
+
+        assert_eq!(work.abstract_text.as_deref(), Some("An abstract"));
+        assert_eq!(work.outputs, Some(vec!["https://api.core.ac.uk/v3/outputs/42".to_string()]));
+        assert_eq!(work.data_providers.as_ref().map(|dp| dp.len()), Some(1));
+        assert_eq!(work.oai_ids(), vec![&crate::OaiId::parse("oai:provider:1").unwrap()]);
+        assert_eq!(work.identifiers.map(|ids| ids.len()), Some(2));
+        assert_eq!(work.language.and_then(|l| l.code).as_deref(), Some("en"));
+        assert_eq!(work.year_published, Some(2019));
+    }
+
+    #[test]
+    fn test_work_dates() {
+        let data = r#"{
+            "publishedDate": "2019-05-21T00:00:00",
+            "updatedDate": "2021-03-15T12:34:56+00:00",
+            "depositedDate": "2019-05",
+            "acceptedDate": "not a date",
+            "yearPublished": "2019"
+        }"#;
+        let work: Work = crate::helpers::response_handler::parse_json(data).unwrap();
+
+        assert_eq!(work.year_published, Some(2019));
+        assert_eq!(work.published_date.as_ref().and_then(|d| d.day()), Some(21));
+        assert_eq!(work.deposited_date.as_ref().and_then(|d| d.month()), Some(5));
+        assert_eq!(work.deposited_date.as_ref().and_then(|d| d.day()), None);
+        assert!(!work.accepted_date.as_ref().unwrap().is_parsed());
+        assert_eq!(work.accepted_date.as_ref().unwrap().raw, "not a date");
+        assert!(work.updated_date > work.published_date);
+        assert_eq!(serde_json::to_value(&work).unwrap()["depositedDate"], "2019-05");
+    }
+
+    #[test]
+    fn test_work_identifiers() {
+        let data = r#"{
+            "doi": "https://doi.org/10.1000/XYZ",
+            "arxivId": "not an arxiv id",
+            "pubmedId": 31452104,
+            "oaiIds": ["oai:arXiv.org:2101.00001", "invalid"]
+        }"#;
+        let work: Work = crate::helpers::response_handler::parse_json(data).unwrap();
+
+        assert_eq!(work.doi().map(|d| d.as_str()), Some("10.1000/xyz"));
+        assert_eq!(work.arxiv_id(), None);
+        assert_eq!(work.arxiv_id, Some(crate::IdentifierValue::Unparsed("not an arxiv id".to_string())));
+        assert_eq!(work.pubmed_id().map(|p| p.as_str()), Some("31452104"));
+        assert_eq!(work.oai_ids().len(), 1);
+        assert_eq!(work.oai_ids.as_ref().map(|ids| ids.len()), Some(2));
+
+        let round_trip: Work = crate::helpers::response_handler::parse_json(&serde_json::to_string(&work).unwrap()).unwrap();
+        assert_eq!(round_trip.arxiv_id.as_ref().map(|id| id.as_str()), Some("not an arxiv id"));
+        assert_eq!(round_trip.oai_ids, work.oai_ids);
+    }
+
+    #[test]
+    fn test_work_links_and_identifier_kinds() {
+        let data = r#"{
+            "identifiers": [
+                { "identifier": "10.1000/xyz", "type": "DOI" },
+                { "identifier": "123", "type": "CORE_ID" },
+                { "identifier": "abc", "type": "SOMETHING_NEW" }
+            ],
+            "links": [
+                "https://core.ac.uk/raw",
+                { "type": "thumbnail_m", "url": "https://core.ac.uk/image/1/medium" },
+                { "type": "reader", "url": "https://core.ac.uk/reader/1" },
+                { "type": "download", "url": "https://core.ac.uk/download/1.pdf" }
+            ]
+        }"#;
+        let work: Work = crate::helpers::response_handler::parse_json(data).unwrap();
+
+        assert_eq!(work.identifiers_of(&crate::IdentifierKind::CoreId), vec!["123"]);
+        assert_eq!(work.identifiers_of(&crate::IdentifierKind::Other("SOMETHING_NEW".to_string())), vec!["abc"]);
+        assert_eq!(work.thumbnail_url(), Some("https://core.ac.uk/image/1/medium"));
+        assert_eq!(work.reader_url(), Some("https://core.ac.uk/reader/1"));
+        assert_eq!(work.download_link(), Some("https://core.ac.uk/download/1.pdf"));
+        assert_eq!(work.display_url(), None);
+    }
+
+    #[test]
+    fn test_page_document_type_counts() {
+        let data = r#"{
+            "totalHits": 3, "limit": 10, "offset": 0,
+            "results": [
+                { "documentType": "research", "fieldOfStudy": "computer science" },
+                { "documentType": "Thesis", "fieldOfStudy": "Computer Science" },
+                { "documentType": "poster" }
+            ]
+        }"#;
+        let resp: crate::responses::search::SearchResponse<Work> = crate::helpers::response_handler::parse_json(data).unwrap();
+
+        let types = resp.page_document_type_counts();
+        assert_eq!(types.get(&crate::DocumentType::Research), Some(&1));
+        assert_eq!(types.get(&crate::DocumentType::Thesis), Some(&1));
+        assert_eq!(types.get(&crate::DocumentType::Other("poster".to_string())), Some(&1));
+        assert_eq!(resp.page_field_of_study_counts().get(&crate::FieldOfStudy::ComputerScience), Some(&2));
+    }
+
+    #[test]
+    fn test_sparse_data_provider() {
+        let data = r#"{
+            "id": 86,
+            "name": "Provider",
+            "email": null,
+            "software": "DSpace",
+            "type": "REPOSITORY",
+            "createdDate": "2012-01-01",
+            "stats": "{\"countMetadata\": \"120\", \"countFulltext\": 30}"
+        }"#;
+        let provider: DataProvider = crate::helpers::response_handler::parse_json(data).unwrap();
+
+        assert_eq!(provider.email, None);
+        assert_eq!(provider.logo, None);
+        assert_eq!(provider.location, None);
+        assert_eq!(provider.software, Some(crate::RepositorySoftware::DSpace));
+        assert_eq!(provider.data_provider_type, Some(crate::DataProviderType::Repository));
+        let stats = provider.stats.unwrap();
+        assert_eq!(stats.count_metadata, Some(120));
+        assert_eq!(stats.fulltext_ratio(), Some(0.25));
+    }
+
+    #[test]
+    fn test_data_provider_without_name() {
+        use crate::{helpers::response_handler::parse_json, DataProviderRef};
+
+        let providers: Vec<DataProvider> = parse_json(r#"[{"id": 1, "name": null}, {"id": 2}, {"id": 3, "name": "Provider"}]"#).unwrap();
+        let names: Vec<Option<&str>> = providers.iter().map(|p| p.name.as_deref()).collect();
+        assert_eq!(names, vec![None, None, Some("Provider")]);
+        assert_eq!(DataProviderRef::from(&providers[0]).name, "");
+    }
+
+    #[test]
+    fn test_citation_graph_dedup_and_export() {
+        use crate::citations::graph::CitationGraph;
+
+        let doi = crate::Doi::parse("10.1000/cited").unwrap();
+        let mut graph = CitationGraph::new();
+        let seed = graph.add_work(&Work { id: Some(1), title: Some("Seed & <co>".to_string()), ..Default::default() }, 0);
+        let reference = crate::Reference { doi: Some(doi.clone().into()), raw: Some("Raw citation".to_string()), ..Default::default() };
+        let unresolved = graph.add_reference(&reference, 1).unwrap();
+        let resolved = graph.add_work(&Work { id: Some(2), doi: Some(doi.clone().into()), title: Some("Cited".to_string()), ..Default::default() }, 1);
+        graph.add_citation(seed, resolved);
+        graph.add_citation(seed, unresolved);
+
+        assert_eq!(unresolved, resolved);
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.find_by_id(2), graph.find_by_doi(&doi));
+        assert_eq!(graph.nodes()[resolved].title.as_deref(), Some("Cited"));
+        assert!(graph.to_graphml().contains("<data key=\"label\">Seed &amp; &lt;co&gt;</data>"));
+        assert!(graph.to_gexf().contains("<edge id=\"0\" source=\"n0\" target=\"n1\"/>"));
+        // GraphML and GEXF name the integer type differently
+        assert!(graph.to_graphml().contains("attr.name=\"year\" attr.type=\"int\""));
+        assert!(graph.to_gexf().contains("title=\"year\" type=\"integer\""));
+
+        // a work whose id and DOI were seen on two different nodes merges them
+        let other = crate::Doi::parse("10.1000/other").unwrap();
+        let by_id = graph.add_work(&Work { id: Some(3), ..Default::default() }, 1);
+        let by_doi = graph.add_reference(&crate::Reference { doi: Some(other.clone().into()), ..Default::default() }, 2).unwrap();
+        let citing = graph.add_work(&Work { id: Some(4), ..Default::default() }, 1);
+        graph.add_citation(seed, by_id);
+        graph.add_citation(seed, by_doi);
+        graph.add_citation(citing, by_doi);
+        let merged = graph.add_work(&Work { id: Some(3), doi: Some(other.clone().into()), ..Default::default() }, 1);
+
+        assert_eq!(merged, by_id);
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.find_by_doi(&other), Some(merged));
+        assert_eq!(graph.find_by_id(4), Some(citing - 1));
+        assert_eq!(graph.citations_of(merged), vec![seed, citing - 1]);
+        assert_eq!(graph.edge_count(), 3);
+        assert!(graph.nodes()[merged].resolved);
+    }
+
+    #[test]
+    fn test_coauthor_centrality() {
+        use crate::{authors::coauthors::CoAuthorGraph, Author};
+
+        let work = |names: &[&str]| Work {
+            authors: Some(names.iter().map(|n| Author::from(*n)).collect()),
+            ..Default::default()
+        };
+        let works = vec![work(&["Ana Novak", "Bor Kranjc"]), work(&["B. Kranjc", "Čeh, Cene"]), work(&["Novak, A.", "Kranjc, Bor"])];
+        let graph = CoAuthorGraph::from_works(&works);
+
+        let kranjc = graph.find("Kranjc B").unwrap();
+        let novak = graph.find("Ana Novak").unwrap();
+        let ceh = graph.find("Cene Ceh").unwrap();
+        assert_eq!(graph.weight(kranjc, novak), 2);
+        assert_eq!(graph.weighted_degree()[kranjc], 3);
+        assert_eq!(graph.degree_centrality()[kranjc], 1.0);
+        assert_eq!(graph.betweenness_centrality(), vec![0.0, 1.0, 0.0]);
+        assert!(graph.closeness_centrality()[kranjc] > graph.closeness_centrality()[ceh]);
+        assert_eq!(graph.nodes()[ceh].name(), "Čeh, Cene");
+    }
+
+    #[test]
+    fn test_author_clusters_with_orcid() {
+        use crate::{authors::disambiguation::AuthorClusterer, Author, Orcid};
+
+        let orcid = Orcid::parse("0000-0002-1825-0097").unwrap();
+        let other = Orcid::parse("0000-0001-5109-3700").unwrap();
+        let work = |name: &str, orcid: Option<Orcid>| Work {
+            authors: Some(vec![Author { name: name.to_string(), orcid: orcid.map(Into::into) }]),
+            ..Default::default()
+        };
+        let works = vec![
+            work("Carberry, Josiah", Some(orcid.clone())),
+            work("J. Carberry", Some(orcid.clone())),
+            work("Josiah Carberry", Some(other.clone())),
+        ];
+        let clusters = AuthorClusterer::new().cluster(&works);
+
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].orcid, Some(orcid));
+        assert_eq!(clusters[0].works().len(), 2);
+        assert_eq!(clusters[1].orcid, Some(other));
+    }
+
+    #[test]
+    fn test_author_clusters_by_journal_not_publisher() {
+        use crate::{authors::disambiguation::AuthorClusterer, Author, JournalRef};
+
+        let work = |journal: Option<&str>| Work {
+            authors: Some(vec![Author::from("J. Smith")]),
+            journals: journal.map(|title| vec![JournalRef { title: Some(title.to_string()), identifiers: vec![] }]),
+            publisher: Some("Elsevier".to_string()),
+            ..Default::default()
+        };
+        let works = vec![work(Some("Cell")), work(Some("cell ")), work(None)];
+        let clusters = AuthorClusterer::new().cluster(&works);
+
+        assert_eq!(clusters.iter().map(|c| c.works().len()).collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(AuthorClusterer::new().use_venues(false).cluster(&works).len(), 3);
+    }
+
+    #[test]
+    fn test_dedup_merges_arxiv_versions() {
+        use crate::{dedup::deduplicator::{Deduplicator, MatchReason}, ArxivId};
+
+        let works = vec![
+            Work { id: Some(7), arxiv_id: ArxivId::parse("2101.00001v1").ok().map(Into::into), citation_count: Some(2), ..Default::default() },
+            Work { title: Some("Unrelated".to_string()), ..Default::default() },
+            Work {
+                arxiv_id: ArxivId::parse("2101.00001v2").ok().map(Into::into),
+                title: Some("A Title".to_string()),
+                citation_count: Some(4),
+                ..Default::default()
+            },
+        ];
+        let (merged, report) = Deduplicator::new().fuzzy(false).dedup(works);
+
+        assert_eq!((report.input_count, report.output_count), (3, 2));
+        assert_eq!(report.groups[0].members, vec![0, 2]);
+        assert!(matches!(report.groups[0].matches[0].reason, MatchReason::ArxivId(_)));
+        assert_eq!(merged[0].id, Some(7));
+        assert_eq!(merged[0].title.as_deref(), Some("A Title"));
+        assert_eq!(merged[0].citation_count, Some(4));
+    }
+
+    #[test]
+    fn test_dedup_title_matches_respect_identifiers() {
+        use crate::{dedup::deduplicator::Deduplicator, Author, Doi, IdentifierKind};
+
+        let work = |id: Option<i32>, doi: Option<&str>, authors: &[&str]| Work {
+            id,
+            doi: doi.map(|doi| Doi::parse(doi).unwrap().into()),
+            title: Some("Attention is all you need".to_string()),
+            year_published: Some(2017),
+            authors: Some(authors.iter().map(|name| Author::from(*name)).collect()),
+            ..Default::default()
+        };
+        let works = vec![
+            work(None, Some("10.1000/a"), &["Vaswani, Ashish"]),
+            // a different DOI is a different work, even with the same title
+            work(None, Some("10.1000/b"), &["A. Vaswani"]),
+            // records without authors are not matched by title
+            work(Some(1), None, &[]),
+            work(Some(2), None, &[]),
+            // the same CORE id joins records with different DOIs, both DOIs are kept
+            work(Some(3), Some("10.1000/c"), &["Shazeer, Noam"]),
+            work(Some(3), Some("10.1000/d"), &["Shazeer, Noam"]),
+        ];
+        let (merged, report) = Deduplicator::new().dedup(works);
+
+        assert_eq!(report.output_count, 5);
+        assert_eq!(report.groups.len(), 1);
+        assert_eq!(report.groups[0].members, vec![4, 5]);
+        assert_eq!(merged[4].doi().map(Doi::as_str), Some("10.1000/c"));
+        assert_eq!(merged[4].identifiers_of(&IdentifierKind::Doi), vec!["10.1000/d"]);
+    }
+
+    #[test]
+    fn test_dedup_title_matches_do_not_bridge_identifiers() {
+        use crate::{dedup::deduplicator::Deduplicator, Author, Doi};
+
+        let work = |doi: Option<&str>| Work {
+            doi: doi.map(|doi| Doi::parse(doi).unwrap().into()),
+            title: Some("Attention is all you need".to_string()),
+            year_published: Some(2017),
+            authors: Some(vec![Author::from("Vaswani, Ashish")]),
+            ..Default::default()
+        };
+        // the record without a DOI matches both others by title, but may only join one of them
+        let works = vec![work(Some("10.1/a")), work(None), work(Some("10.1/b"))];
+        let groups = Deduplicator::new().group(&works);
+
+        let members: Vec<Vec<usize>> = groups.into_iter().map(|group| group.members).collect();
+        assert_eq!(members, vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn test_bibtex_round_trip() {
+        use crate::{export::bibtex::{parse_bibtex, to_bibtex_string}, Author, DocumentType, Doi, JournalRef};
+
+        let work = Work {
+            title: Some("Graphs, 50% off & {more}".to_string()),
+            authors: Some(vec![Author::from("Anna Müller"), Author::from("Smith, John")]),
+            year_published: Some(2020),
+            journals: Some(vec![JournalRef { title: Some("Journal of Tests".to_string()), identifiers: vec!["issn:1234-5679".to_string()] }]),
+            doi: Doi::parse("10.1000/a_b").ok().map(Into::into),
+            publisher: Some("ACME".to_string()),
+            document_type: Some(DocumentType::Research),
+            ..Default::default()
+        };
+        let exported = to_bibtex_string([&work, &work]);
+        let entries = parse_bibtex(&exported).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].key, "muller2020graphs");
+        assert_eq!(entries[1].key, "muller2020graphsa");
+        assert_eq!(entries[0].entry_type, "article");
+
+        let parsed = Work::from(&entries[0]);
+        assert_eq!(parsed.title, work.title);
+        assert_eq!(parsed.doi, work.doi);
+        assert_eq!(parsed.year_published, Some(2020));
+        assert_eq!(parsed.publisher.as_deref(), Some("ACME"));
+        assert_eq!(parsed.journals, work.journals);
+        let names: Vec<_> = parsed.authors.unwrap().into_iter().map(|a| a.name).collect();
+        assert_eq!(names, vec!["Müller, Anna", "Smith, John"]);
+    }
+
+    #[test]
+    fn test_ris_and_endnote_export() {
+        let data = r#"{
+            "totalHits": 2, "limit": 10, "offset": 0,
+            "results": [
+                {
+                    "id": 1, "title": "Tides <and> currents", "documentType": "research", "doi": "10.1000/tides",
+                    "authors": [{ "name": "Doe, Jane" }], "yearPublished": 2019,
+                    "journals": [{ "title": "Ocean Letters", "identifiers": ["issn:1234-5679"] }],
+                    "abstractText": "Line one.\nLine two."
+                },
+                { "id": 2, "title": "A thesis", "documentType": "thesis" }
+            ]
+        }"#;
+        let resp: crate::responses::search::SearchResponse<Work> = crate::helpers::response_handler::parse_json(data).unwrap();
+
+        let mut ris = vec![];
+        resp.write_ris(&mut ris).unwrap();
+        let ris = String::from_utf8(ris).unwrap();
+        assert!(ris.starts_with("TY  - JOUR\r\nAU  - Doe, Jane\r\nTI  - Tides <and> currents\r\nPY  - 2019\r\nJO  - Ocean Letters\r\n"));
+        assert!(ris.contains("SN  - 1234-5679\r\nDO  - 10.1000/tides\r\nAB  - Line one. Line two.\r\n"));
+        assert!(ris.contains("TY  - THES\r\nTI  - A thesis\r\nID  - 2\r\nER  - \r\n"));
+
+        let mut xml = vec![];
+        resp.write_endnote_xml(&mut xml).unwrap();
+        let xml = String::from_utf8(xml).unwrap();
+        assert_eq!(xml.matches("<record>").count(), 2);
+        assert!(xml.contains(r#"<ref-type name="Journal Article">17</ref-type>"#));
+        assert!(xml.contains("<title>Tides &lt;and&gt; currents</title><secondary-title>Ocean Letters</secondary-title>"));
+        assert!(xml.contains("<electronic-resource-num>10.1000/tides</electronic-resource-num>"));
+        assert!(xml.contains(r#"<ref-type name="Thesis">32</ref-type>"#));
+    }
+
+    #[test]
+    fn test_citation_styles() {
+        use crate::{export::citation::{format_bibliography, CitationFormat, CitationStyle}, Author, DocumentType, JournalRef};
+
+        let thesis = Work {
+            title: Some("On things?".to_string()),
+            authors: Some(vec![Author::from("Smith, Jane"), Author::from("Jean-Paul Sartre")]),
+            publisher: Some("MIT".to_string()),
+            document_type: Some(DocumentType::Thesis),
+            download_url: Some("https://example.org/a".to_string()),
+            ..Default::default()
+        };
+        let paper = Work {
+            title: Some("Fish & chips".to_string()),
+            authors: Some(vec![Author::from("Adams, Douglas")]),
+            journals: Some(vec![JournalRef { title: Some("Food".to_string()), identifiers: vec![] }]),
+            year_published: Some(1979),
+            ..Default::default()
+        };
+
+        assert_eq!(thesis.to_csl().item_type, "thesis");
+        assert_eq!(
+            thesis.format_citation(CitationStyle::Chicago, CitationFormat::Text),
+            "Smith, Jane, and Jean-Paul Sartre. On things? MIT, n.d. https://example.org/a."
+        );
+        assert_eq!(
+            thesis.format_citation(CitationStyle::Harvard, CitationFormat::Html),
+            "Smith, J. and Sartre, J.-P. (no date) <i>On things?</i> MIT. Available at: <a href=\"https://example.org/a\">https://example.org/a</a>."
+        );
+        assert_eq!(paper.format_citation(CitationStyle::Mla, CitationFormat::Html), "Adams, Douglas. “Fish &amp; chips.” <i>Food</i>, 1979.");
+
+        let apa = format_bibliography([&thesis, &paper], CitationStyle::Apa, CitationFormat::Text);
+        assert_eq!(apa[0], "Adams, D. (1979). Fish & chips. Food.");
+        let ieee = format_bibliography([&thesis, &paper], CitationStyle::Ieee, CitationFormat::Text);
+        assert!(ieee[0].starts_with("[1] J. Smith and J.-P. Sartre, On things?"));
+        assert_eq!(ieee[1], "[2] D. Adams, “Fish & chips,” Food, 1979.");
+    }
+
+    #[test]
+    fn test_jsonl_and_csv_export() {
+        use crate::export::{csv::{CsvWriter, ListFormat}, fields::{FieldSelection, WorkField}, jsonl::JsonLinesWriter};
+
+        let data = r#"{
+            "id": 5, "title": "Tabs\tand; semicolons",
+            "identifiers": [{ "identifier": "10.1000/xyz", "type": "DOI" }],
+            "dataProviders": [{ "id": 86, "name": "Provider", "url": "", "logo": null }],
+            "links": [{ "type": "download", "url": "https://core.ac.uk/download/5.pdf" }]
+        }"#;
+        let work: Work = crate::helpers::response_handler::parse_json(data).unwrap();
+        let works = vec![Ok(work.clone()), Err(crate::errors::Error::Parsing("boom".to_string())), Ok(work.clone())];
+
+        let fields = FieldSelection::new(vec![WorkField::Id, WorkField::Title, WorkField::Identifiers, WorkField::DataProviders, WorkField::Links]);
+        let mut csv = CsvWriter::new(vec![]).fields(fields).delimiter('\t').list_format(ListFormat::Json);
+        assert!(csv.write_all(works).is_err());
+        let csv = String::from_utf8(csv.into_inner().unwrap()).unwrap();
+        assert_eq!(
+            csv,
+            "id\ttitle\tidentifiers\tdataProviders\tlinks\r\n\
+             5\t\"Tabs\tand; semicolons\"\t\"[\"\"DOI:10.1000/xyz\"\"]\"\t\"[\"\"Provider\"\"]\"\t\"[\"\"https://core.ac.uk/download/5.pdf\"\"]\"\r\n"
+        );
+
+        let mut jsonl = JsonLinesWriter::new(vec![]);
+        assert_eq!(jsonl.write_all(vec![Ok(work.clone()), Ok(work.clone())]).unwrap(), 2);
+        let jsonl = String::from_utf8(jsonl.into_inner().unwrap()).unwrap();
+        let lines: Vec<Work> = jsonl.lines().map(|line| crate::helpers::response_handler::parse_json(line).unwrap()).collect();
+        assert_eq!(lines, vec![work.clone(), work]);
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_arrow_and_parquet_export() {
+        use crate::export::arrow::{ArrowRecord, ParquetWriter};
+        use arrow::array::{Array, Date32Array, ListArray};
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        let data = r#"{
+            "id": 5, "title": "A title", "publishedDate": "2020-01-02",
+            "authors": [{ "name": "Ada Lovelace" }, { "name": "Charles Babbage" }],
+            "identifiers": [{ "identifier": "10.1000/xyz", "type": "DOI" }]
+        }"#;
+        let work: Work = crate::helpers::response_handler::parse_json(data).unwrap();
+        let works = vec![work.clone(), Work::default(), work];
+
+        let batch = Work::to_record_batch(&works).unwrap();
+        assert_eq!(batch.schema(), Work::arrow_schema());
+        assert_eq!(batch.num_rows(), 3);
+        let authors = batch.column_by_name("authors").unwrap().as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(authors.value_length(0), 2);
+        assert!(authors.is_null(1));
+        let published = batch.column_by_name("publishedDate").unwrap().as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(published.value_as_date(0), chrono::NaiveDate::from_ymd_opt(2020, 1, 2));
+
+        let path = std::env::temp_dir().join(format!("core_api_client_test_{}.parquet", std::process::id()));
+        let mut writer = ParquetWriter::new(std::fs::File::create(&path).unwrap()).row_group_size(2);
+        writer.write_all(works.into_iter().map(Ok)).unwrap();
+        writer.finish().unwrap();
+
+        let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+        let row_groups: Vec<i64> = reader.metadata().row_groups().iter().map(|group| group.num_rows()).collect();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(row_groups, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_json_ld_and_dublin_core() {
+        let data = r#"{
+            "id": 86, "name": "Provider", "rorId": "02bfwt286", "aliases": ["ORO"],
+            "location": { "countryCode": "gb", "latitude": 52.02, "longitude": -0.71 }
+        }"#;
+        let provider: DataProvider = crate::helpers::response_handler::parse_json(data).unwrap();
+        let json_ld = provider.to_json_ld();
+        assert_eq!(json_ld["sameAs"], "https://ror.org/02bfwt286");
+        assert_eq!(json_ld["alternateName"][0], "ORO");
+        assert_eq!(json_ld["address"]["addressCountry"], "GB");
+        assert_eq!(json_ld["geo"]["@type"], "GeoCoordinates");
+
+        let data = r#"{
+            "id": 1, "title": "A thesis", "documentType": "thesis", "publishedDate": "2019-05-21T00:00:00",
+            "authors": [{ "name": "Doe, Jane", "orcid": "0000-0002-1825-0097" }],
+            "oaiIds": ["oai:oro.open.ac.uk:1"], "language": { "code": "en", "name": "English" },
+            "journals": [{ "title": "Journal <1>", "identifiers": ["issn:1234-5679"] }]
+        }"#;
+        let work: Work = crate::helpers::response_handler::parse_json(data).unwrap();
+        let json_ld = work.to_json_ld();
+        assert_eq!(json_ld["@type"], "Thesis");
+        assert_eq!(json_ld["datePublished"], "2019-05-21");
+        assert_eq!(json_ld["author"][0]["identifier"], "https://orcid.org/0000-0002-1825-0097");
+        assert_eq!(json_ld["isPartOf"]["issn"], "1234-5679");
+        assert_eq!(json_ld["identifier"][1]["propertyID"], "OAI");
+
+        let xml = work.to_oai_dc();
+        assert!(xml.starts_with("<oai_dc:dc "));
+        assert!(xml.contains("  <dc:date>2019-05-21</dc:date>\n  <dc:type>thesis</dc:type>\n  <dc:identifier>oai:oro.open.ac.uk:1</dc:identifier>\n"));
+        assert!(xml.contains("  <dc:source>Journal &lt;1&gt;</dc:source>\n  <dc:language>en</dc:language>\n</oai_dc:dc>\n"));
+    }
+
+    #[test]
+    fn test_crossref_and_datacite_mapping() {
+        use crate::export::{crossref::CrossrefWork, datacite::DataCiteRecord, fields::{compare_works, FieldSelection, WorkField}};
+
+        let crossref = r#"{
+            "DOI": "10.1000/xyz", "type": "journal-article", "title": ["Tides"],
+            "author": [{ "given": "Jane", "family": "Doe", "ORCID": "http://orcid.org/0000-0002-1825-0097", "sequence": "first" }],
+            "container-title": ["Ocean Letters"], "ISSN": ["1234-5679"], "publisher": "ACME",
+            "issued": { "date-parts": [[2019, 5, 21]] }, "abstract": "<jats:p>Waves.</jats:p>",
+            "is-referenced-by-count": 7, "link": [{ "URL": "https://example.org/x.pdf", "content-type": "application/pdf" }],
+            "reference": [{ "key": "r1", "DOI": "10.1000/abc", "year": "2001" }],
+            "indexed": { "date-parts": [[2024, 1, 1]] }
+        }"#;
+        let crossref: CrossrefWork = crate::helpers::response_handler::parse_json(crossref).unwrap();
+        let imported = Work::from(&crossref);
+        assert_eq!(imported.authors.as_ref().unwrap()[0].name, "Doe, Jane");
+        assert!(imported.authors.as_ref().unwrap()[0].orcid.is_some());
+        assert_eq!(imported.abstract_text.as_deref(), Some("Waves."));
+        assert_eq!(imported.published_date.as_ref().and_then(|d| d.day()), Some(21));
+        assert_eq!(imported.references.as_ref().unwrap()[0].doi.as_ref().map(|d| d.as_str()), Some("10.1000/abc"));
+
+        let core = Work { citation_count: Some(9), ..imported.clone() };
+        let differences = compare_works(&core, &imported, &FieldSelection::default());
+        assert_eq!(differences.iter().map(|d| d.field).collect::<Vec<_>>(), vec![WorkField::CitationCount]);
+
+        let exported = serde_json::to_value(imported.to_crossref()).unwrap();
+        assert_eq!(exported["container-title"][0], "Ocean Letters");
+        assert_eq!(exported["issued"]["date-parts"][0], serde_json::json!([2019, 5, 21]));
+
+        let record = imported.to_datacite();
+        assert_eq!(record.types.as_ref().unwrap().resource_type_general, "JournalArticle");
+        assert_eq!(record.date("Issued"), Some("2019-05-21"));
+        let from_xml = DataCiteRecord::from_xml(&record.to_xml()).unwrap();
+        assert_eq!(from_xml, DataCiteRecord { url: None, ..record.clone() });
+        let json: DataCiteRecord = serde_json::from_value(serde_json::to_value(&record).unwrap()).unwrap();
+        assert_eq!(json, record);
+
+        let round_trip = Work::from(&from_xml);
+        let fields = FieldSelection::parse("title,doi,authors,authorOrcids,journals,publisher,yearPublished,publishedDate,abstract").unwrap();
+        assert!(compare_works(&imported, &round_trip, &fields).is_empty());
+        assert!(DataCiteRecord::from_xml("<other/>").is_err());
+    }
+
+    #[test]
+    fn test_crossref_null_date() {
+        use crate::export::crossref::CrossrefWork;
+
+        // Crossref reports works without a date as `[[null]]`
+        let crossref = r#"{ "DOI": "10.1000/undated", "title": ["Undated"], "issued": { "date-parts": [[null]] } }"#;
+        let crossref: CrossrefWork = crate::helpers::response_handler::parse_json(crossref).unwrap();
+        assert_eq!(crossref.issued.as_ref().and_then(|issued| issued.year()), None);
+        let imported = Work::from(&crossref);
+        assert_eq!((imported.published_date, imported.year_published), (None, None));
+
+        let crossref = r#"{ "issued": { "date-parts": [[2019, null]] } }"#;
+        let crossref: CrossrefWork = crate::helpers::response_handler::parse_json(crossref).unwrap();
+        assert_eq!(crossref.issued.unwrap().date_parts, vec![vec![2019]]);
+    }
+
+    #[test]
+    fn test_response_cache_lru_and_ttl() {
+        use std::time::Duration;
+        use crate::{cache::{memory::ResponseCache, Endpoint, RawResponse, RequestKey}, Api};
+        use crate::models::query_models::request_type::QueryRequestType;
+
+        let key = |uri: &str| RequestKey { method: QueryRequestType::Get, uri: uri.to_string(), body: None };
+        let raw = |data: &str| RawResponse { data: data.to_string(), rate_limit: Some(10) };
+
+        let mut cache = ResponseCache::new(2)
+            .endpoint_ttl(Endpoint::Search, Duration::ZERO)
+            .endpoint_ttl(Endpoint::Journals, Duration::from_millis(20));
+        cache.insert(key("works/1"), raw("one"));
+        cache.insert(key("works/2"), raw("two"));
+        assert_eq!(cache.get(&key("works/1")).unwrap().data, "one");
+        cache.insert(key("works/3"), raw("three"));
+
+        // works/2 was the least recently used entry
+        assert!(cache.get(&key("works/2")).is_none());
+        assert_eq!(cache.get(&key("works/3")).unwrap().data, "three");
+
+        cache.insert(key("search/works/?q=x"), raw("search"));
+        assert!(cache.get(&key("search/works/?q=x")).is_none());
+
+        cache.insert(key("journals/issn:1"), raw("journal"));
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(cache.get(&key("journals/issn:1")).is_none());
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.expired, 1);
+        assert_eq!(stats.evictions, 2);
+        assert_eq!(stats.entries, 1);
+        assert_eq!(stats.hit_ratio(), Some(0.5));
+
+        let api = Api::from("API_KEY").cache(ResponseCache::new(10));
+        assert_eq!(api.cache_stats().unwrap().hit_ratio(), None);
+        assert!(Api::from("API_KEY").cache_stats().is_none());
+    }
+
+    #[test]
+    fn test_disk_cache_and_offline_mode() {
+        use std::time::Duration;
+        use crate::{cache::{disk::DiskCache, RawResponse, RequestKey}, errors::Error, Api};
+        use crate::models::query_models::request_type::QueryRequestType;
+
+        let dir = std::env::temp_dir().join(format!("core-api-disk-cache-{}", std::process::id()));
+        let cache = DiskCache::open(&dir).unwrap();
+        cache.clear().unwrap();
+
+        let key = RequestKey { method: QueryRequestType::Get, uri: "works/42".to_string(), body: None };
+        let data = r#"{"id": 42, "title": "Cached work"}"#;
+        cache.insert(&key, &RawResponse { data: data.to_string(), rate_limit: Some(7) }).unwrap();
+        assert_eq!(cache.len().unwrap(), 1);
+
+        let entry = &cache.entries().unwrap()[0];
+        assert_eq!((entry.method.as_str(), entry.uri.as_str(), entry.rate_limit), ("GET", "works/42", Some(7)));
+        assert_eq!(entry.data, data);
+
+        let api = Api::from("API_KEY").disk_cache(cache.clone()).offline(true);
+        let work = api.get_work(42).unwrap();
+        assert_eq!(work.response.title.as_deref(), Some("Cached work"));
+        assert_eq!(work.ratelimit_remaining, None);
+        match api.get_work(43) {
+            Err(Error::NotCached(uri)) => assert_eq!(uri, "works/43"),
+            other => panic!("expected NotCached, got {:?}", other),
+        }
+
+        let expired = Api::from("API_KEY").disk_cache(cache.clone().ttl(Duration::ZERO)).offline(true);
+        assert!(matches!(expired.get_work(42), Err(Error::NotCached(_))));
+
+        // corrupt entries are dropped and fetched again
+        let file = std::fs::read_dir(&dir).unwrap().next().unwrap().unwrap().path();
+        std::fs::write(&file, b"not gzip").unwrap();
+        assert!(matches!(api.get_work(42), Err(Error::NotCached(_))));
+        assert!(!file.exists());
+
+        let interaction = crate::cassette::Interaction {
+            request: crate::cassette::RecordedRequest {
+                method: "GET".to_string(),
+                uri: "works/42".to_string(),
+                headers: Default::default(),
+                body: None,
+            },
+            response: crate::cassette::RecordedResponse { status: 200, rate_limit: None, body: data.to_string() },
+        };
+        let replayed = Api::from("API_KEY")
+            .disk_cache(cache.clone())
+            .replay(crate::cassette::Cassette { interactions: vec![interaction] });
+        assert!(replayed.get_work(42).is_ok());
+        assert_eq!(cache.len().unwrap(), 1);
+
+        // a cache that can not be written does not fail the request
+        cache.clear().unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(replayed.get_work(42).is_ok());
+    }
+
+    #[test]
+    fn test_cassette_record_and_replay() {
+        use std::collections::BTreeMap;
+        use crate::{cache::RequestKey, errors::Error, Api};
+        use crate::cassette::{recorder::Recorder, Cassette, Interaction, RecordedRequest, RecordedResponse, REDACTED};
+        use crate::models::query_models::request_type::QueryRequestType;
+
+        let path = std::env::temp_dir().join(format!("core-api-cassette-{}.jsonl", std::process::id()));
+        let mut recorder = Recorder::create(&path).unwrap();
+        let key = RequestKey { method: QueryRequestType::Get, uri: "search/works/?q=%20AND%20title=deep&offset=0&limit=1".to_string(), body: None };
+        let body = r#"{"totalHits": 1, "limit": 1, "offset": 0, "results": [{"id": 7, "title": "Deep learning"}]}"#;
+        recorder.record(&key, RecordedResponse { status: 200, rate_limit: Some(9), body: body.to_string() }).unwrap();
+
+        let json = std::fs::read_to_string(&path).unwrap();
+        assert!(json.contains(REDACTED));
+        let cassette = Cassette::load(&path).unwrap();
+        assert_eq!(&cassette, recorder.cassette());
+
+        // interactions are appended one line at a time
+        let mut appending = Recorder::append(&path).unwrap();
+        appending.record(&key, RecordedResponse { status: 200, rate_limit: Some(8), body: body.to_string() }).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+        assert_eq!(&Cassette::load(&path).unwrap(), appending.cassette());
+        std::fs::remove_file(&path).unwrap();
+
+        let request = |method: &str, uri: &str, body: Option<&str>| RecordedRequest {
+            method: method.to_string(),
+            uri: uri.to_string(),
+            headers: BTreeMap::new(),
+            body: body.map(str::to_string),
+        };
+        let response = |status: u16, body: &str| RecordedResponse { status, rate_limit: None, body: body.to_string() };
+        let mut cassette = cassette;
+        cassette.interactions.push(Interaction {
+            request: request("POST", "discover", Some(r#"{ "doi":"10.1/x" }"#)),
+            response: response(200, r#"{"fullTextLink": "https://example.org/x.pdf", "source": "core"}"#),
+        });
+        cassette.interactions.push(Interaction { request: request("GET", "works/1", None), response: response(401, "") });
+
+        let api = Api::from("API_KEY").replay(cassette);
+
+        // query parameters may come in another order
+        let query = api.paged_search(1, 0).and(crate::FilterOperator::Eq("title", "deep"));
+        let works = api.search_works(query).unwrap();
+        assert_eq!(works.ratelimit_remaining, Some(9));
+        assert_eq!(works.response.results.unwrap()[0].title.as_deref(), Some("Deep learning"));
+
+        // the discovery body is compared as JSON
+        assert_eq!(api.discover("10.1/x").unwrap().response.full_text_link, "https://example.org/x.pdf");
+        assert!(matches!(api.discover("10.1/y"), Err(Error::NotRecorded(_))));
+        assert!(matches!(api.get_work(1), Err(Error::InvalidApiKey)));
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_store() {
+        use crate::{errors::Error, helpers::response_handler::parse_json, store::Store, DataProvider, Doi, IdentifierKind, Journal, Work};
+
+        let work = |json: &str| parse_json::<Work>(json).unwrap();
+        let first = work(r#"{
+            "id": 1, "title": "Deep learning", "doi": "10.1038/NATURE14539",
+            "authors": [{"name": "LeCun, Yann"}, {"name": "Hinton, Geoffrey"}],
+            "identifiers": [{"identifier": "oai:arxiv.org:1", "type": "OAI_ID"}],
+            "references": [{"id": 5, "title": "Backpropagation", "doi": "10.1/bp"}],
+            "links": [{"type": "download", "url": "https://core.ac.uk/download/1.pdf"}],
+            "dataProviders": [{"id": 86, "name": "arXiv", "url": ""}],
+            "updatedDate": "2023-05-01T10:00:00"
+        }"#);
+        let second = work(r#"{"id": 2, "title": "Graph networks", "authors": [{"name": "Battaglia, Peter"}], "updatedDate": "2023-06-01T10:00:00"}"#);
+
+        let mut store = Store::open_in_memory().unwrap();
+        let imported = store.import(vec![Ok(first.clone()), Ok(second.clone()), Err(Error::Parsing("page".to_string()))]);
+        assert!(matches!(imported, Err(Error::Parsing(_))));
+        assert_eq!(store.work_count().unwrap(), 2);
+        assert_eq!(store.work(1).unwrap(), Some(first.clone()));
+
+        let doi = Doi::parse("10.1038/nature14539").unwrap();
+        assert_eq!(store.works_by_doi(&doi).unwrap(), vec![first.clone()]);
+        assert_eq!(store.works_by_identifier(&IdentifierKind::Oai, "oai:arxiv.org:1").unwrap().len(), 1);
+        assert_eq!(store.works_by_author("hinton").unwrap()[0].id, Some(1));
+        assert_eq!(store.works_by_title("graph").unwrap()[0].id, Some(2));
+        assert_eq!(store.works_by_data_provider(86).unwrap().len(), 1);
+        let since = chrono::NaiveDate::from_ymd_opt(2023, 5, 15).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(store.works_updated_since(since).unwrap(), vec![second.clone()]);
+        assert_eq!(store.latest_updated_date().unwrap().map(|date| date.to_string()), Some("2023-06-01 10:00:00".to_string()));
+
+        // upserting replaces the record and its normalized rows
+        let renamed = Work { title: Some("Deep learning (revised)".to_string()), authors: None, ..first };
+        store.upsert(&renamed).unwrap();
+        assert_eq!(store.work_count().unwrap(), 2);
+        assert!(store.works_by_author("hinton").unwrap().is_empty());
+        let references: i64 = store.connection().query_row("SELECT COUNT(*) FROM work_references", [], |row| row.get(0)).unwrap();
+        assert_eq!(references, 1);
+        assert!(store.remove_work(1).unwrap());
+        assert!(store.work(1).unwrap().is_none());
+        assert!(store.upsert(&Work::default()).is_err());
+
+        // a failing upsert keeps the records of the batch written before it
+        let third = Work { id: Some(3), ..Work::default() };
+        let imported = store.import(vec![Ok(third), Ok(Work::default())]);
+        assert!(matches!(imported, Err(Error::InvalidIdentifier(_))));
+        assert!(store.work(3).unwrap().is_some());
+        let duplicate = store.connection().execute("INSERT INTO works (id, data) VALUES (3, '{}')", []);
+        assert!(matches!(duplicate.map_err(Error::from), Err(Error::Sqlite(_))));
+
+        let provider = parse_json::<DataProvider>(r#"{"id": 86, "name": "arXiv", "institutionName": "Cornell University"}"#).unwrap();
+        let journal = parse_json::<Journal>(r#"{"identifiers": ["issn:0028-0836", "1476-4687"], "title": "Nature", "language": "en", "publisher": "Springer", "subjects": [], "dataProviderId": 1}"#).unwrap();
+        store.upsert_all([&provider]).unwrap();
+        store.upsert(&journal).unwrap();
+        assert_eq!(store.data_provider(86).unwrap(), Some(provider.clone()));
+        assert_eq!(store.data_providers_by_name("cornell").unwrap(), vec![provider]);
+        assert_eq!(store.journal("1476-4687").unwrap(), Some(journal.clone()));
+        assert_eq!(store.journals().unwrap(), vec![journal]);
+    }
+
+    #[test]
+    fn test_incremental_sync() {
+        use std::collections::BTreeMap;
+        use crate::{cache::memory::ResponseCache, errors::Error, Api, FilterOperator};
+        use crate::cassette::{Cassette, Interaction, RecordedRequest, RecordedResponse};
+        use crate::helpers::response_handler::parse_json;
+        use crate::sync::{sink::{Change, JsonLinesSink, SyncSink}, SyncEngine};
+
+        let query = || Api::from("API_KEY")
+            .paged_search(2, 0)
+            .and(FilterOperator::Eq("publisher", "OJS"))
+            .or(FilterOperator::Eq("publisher", "PKP"));
+        let uri = |offset: i32, cursor: Option<&str>| {
+            let mut query = query().grouped().sorted("updatedDate:asc");
+            if let Some(cursor) = cursor {
+                query = query.and_raw(format!("updatedDate>=%22{}%22", cursor));
+            }
+            format!("search/works/{}", query.with_page(2, offset).parse())
+        };
+        let page = |total: i32, works: &[(i32, &str, &str)]| {
+            let results: Vec<String> = works
+                .iter()
+                .map(|(id, title, updated)| format!(r#"{{"id": {}, "title": "{}", "updatedDate": "{}"}}"#, id, title, updated))
+                .collect();
+            format!(r#"{{"totalHits": {}, "limit": 2, "offset": 0, "results": [{}]}}"#, total, results.join(","))
+        };
+        let cassette = |pages: Vec<(String, String)>| Cassette {
+            interactions: pages
+                .into_iter()
+                .map(|(uri, body)| Interaction {
+                    request: RecordedRequest { method: "GET".to_string(), uri, headers: BTreeMap::new(), body: None },
+                    response: RecordedResponse { status: 200, rate_limit: None, body },
+                })
+                .collect(),
+        };
+        // the base query is grouped, so the updatedDate bound applies to both alternatives
+        assert!(uri(0, Some("2023-06-01T00:00:00")).contains("sort=updatedDate:asc"));
+        assert!(uri(0, Some("2023-06-01T00:00:00")).ends_with("q=%20AND%20(publisher=OJS%20OR%20publisher=PKP)%20AND%20updatedDate>=%222023-06-01T00:00:00%22"));
+
+        let first_page = (uri(0, None), page(3, &[(1, "A", "2023-05-01T00:00:00"), (2, "B", "2023-06-01T00:00:00")]));
+        // the next page starts at the last updatedDate, skipping the work already applied at it
+        let second_page = (uri(1, Some("2023-06-01T00:00:00")), page(3, &[(2, "B", "2023-06-01T00:00:00"), (4, "D", "2023-06-15T00:00:00")]));
+
+        let state = std::env::temp_dir().join(format!("core-api-sync-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&state);
+        let mut changes = vec![];
+        let mut sink = |change: Change<'_>| -> Result<(), Error> {
+            changes.push(match change {
+                Change::Upsert(work) => format!("upsert {}", work.id.unwrap()),
+                Change::Delete(id) => format!("delete {}", id),
+            });
+            Ok(())
+        };
+
+        // the second page is missing, the run fails after the first one and is resumed
+        let api = Api::from("API_KEY").replay(cassette(vec![first_page.clone()]));
+        let failed = SyncEngine::new(&api, query(), &state).run(&mut sink);
+        assert!(matches!(failed, Err(Error::NotRecorded(_))), "{:?}", failed);
+        let api = Api::from("API_KEY").replay(cassette(vec![first_page.clone(), second_page.clone()]));
+        let report = SyncEngine::new(&api, query(), &state).run(&mut sink).unwrap();
+        assert!(report.resumed);
+        assert_eq!((report.pages, report.upserted, report.since), (1, 1, None));
+        assert_eq!(report.last_updated.unwrap().to_string(), "2023-06-15 00:00:00");
+
+        // the next run only asks for works updated since, bypasses the response cache and applies tombstones
+        let updates = uri(0, Some("2023-06-15T00:00:00"));
+        let stale = (updates.clone(), page(0, &[]));
+        let fresh = (updates, page(2, &[(4, "D", "2023-06-15T00:00:00"), (1, "Withdrawn", "2023-07-01T00:00:00")]));
+        let api = Api::from("API_KEY").cache(ResponseCache::new(10)).replay(cassette(vec![stale, fresh]));
+        let cached = query().grouped().sorted("updatedDate:asc")
+            .and_raw("updatedDate>=%222023-06-15T00:00:00%22".to_string());
+        assert_eq!(api.search_works(cached).unwrap().response.total_hits, Some(0));
+        let engine = SyncEngine::new(&api, query(), &state).tombstone_if(|work| work.title.as_deref() == Some("Withdrawn"));
+        let report = engine.run(&mut sink).unwrap();
+        assert_eq!((report.upserted, report.deleted, report.resumed), (1, 1, false));
+        assert_eq!(engine.last_updated().unwrap().unwrap().to_string(), "2023-07-01 00:00:00");
+
+        // a full sync deletes works that no longer match the query
+        let api = Api::from("API_KEY").replay(cassette(vec![(uri(0, None), page(1, &[(2, "B", "2023-06-01T00:00:00")]))]));
+        let report = SyncEngine::new(&api, query(), &state).full_sync(&mut sink).unwrap();
+        assert_eq!((report.upserted, report.deleted), (1, 1));
+        assert_eq!(changes, ["upsert 1", "upsert 2", "upsert 4", "upsert 4", "delete 1", "upsert 2", "delete 4"]);
+
+        let mut lines = JsonLinesSink::new(vec![]);
+        let work = parse_json::<Work>(r#"{"id": 9, "title": "X"}"#).unwrap();
+        lines.apply( Change::Upsert(&work)).unwrap();
+        lines.apply( Change::Delete(9)).unwrap();
+        let written = String::from_utf8(lines.into_inner()).unwrap();
+        assert!(written.starts_with(r#"{"action":"upsert","id":9,"work":{"#));
+        assert!(written.ends_with("{\"action\":\"delete\",\"id\":9}\n"));
+
+        engine.reset().unwrap();
+        assert!(engine.last_updated().unwrap().is_none());
+        std::fs::remove_file(&state).unwrap();
+        std::fs::remove_file(state.with_extension("ids.json")).unwrap();
+    }
+
+    #[test]
+    fn test_reference_resolver() {
+        use std::collections::BTreeMap;
+        use crate::{citations::resolver::ReferenceResolver, errors::Error, Api, Doi, Reference, SearchQuery, WorkIdentifier};
+        use crate::cassette::{Cassette, Interaction, RecordedRequest, RecordedResponse};
+
+        let doi = |suffix: &str| Doi::parse(&format!("10.1000/{}", suffix)).unwrap();
+        let interaction = |terms: &[String], limit: i32, offset: i32, total: i32, works: &[(i32, &str)]| {
+            let query = SearchQuery::<String, String>::paged(limit, offset)
+                .and_raw(format!("({})", terms.join("%20OR%20")));
+            let results: Vec<String> = works
+                .iter()
+                .map(|(id, suffix)| format!(r#"{{"id": {}, "doi": "10.1000/{}"}}"#, id, suffix))
+                .collect();
+            Interaction {
+                request: RecordedRequest { method: "GET".to_string(), uri: format!("search/works/{}", query.parse()), headers: BTreeMap::new(), body: None },
+                response: RecordedResponse {
+                    status: 200,
+                    rate_limit: None,
+                    body: format!(r#"{{"totalHits": {}, "limit": {}, "offset": {}, "results": [{}]}}"#, total, limit, offset, results.join(",")),
+                },
+            }
+        };
+        let id = |id: i32| format!("id:{}", id);
+        let by_doi = |suffix: &str| doi(suffix).search_expression();
+
+        let mut interactions = vec![
+            interaction(&[id(1), id(99)], 2, 0, 1, &[(1, "x")]),
+            // several CORE records share DOI a, so c is only found on the second page
+            interaction(&[by_doi("a"), by_doi("c")], 2, 0, 3, &[(2, "a"), (3, "a")]),
+            interaction(&[by_doi("a"), by_doi("c")], 2, 2, 3, &[(5, "c")]),
+            interaction(&[by_doi("d")], 1, 0, 0, &[]),
+            // id 99 is not in CORE, its reference falls back to DOI b
+            interaction(&[by_doi("b")], 1, 0, 1, &[(4, "b")]),
+        ];
+        // any repeated request fails, so resolving again has to be served from the cache
+        let repeated: Vec<Interaction> = interactions
+            .iter()
+            .map(|i| Interaction { request: i.request.clone(), response: RecordedResponse { status: 401, rate_limit: None, body: String::new() } })
+            .collect();
+        interactions.extend(repeated);
+        let api = Api::from("API_KEY").replay(Cassette { interactions });
+
+        let reference = |id: Option<i32>, suffix: Option<&str>| Reference { id, doi: suffix.map(|s| doi(s).into()), ..Default::default() };
+        let references = vec![
+            reference(Some(1), None),
+            reference(None, Some("a")),
+            reference(None, Some("a")),
+            reference(Some(99), Some("b")),
+            reference(None, Some("c")),
+            Reference { raw: Some("Unlinked citation".to_string()), ..Default::default() },
+            reference(None, Some("d")),
+        ];
+
+        let mut resolver = ReferenceResolver::new(&api).batch_size(2);
+        let resolved = resolver.resolve_all(&references).unwrap();
+        let ids: Vec<Option<i32>> = resolved.references.iter().map(|(_, work)| work.as_ref().and_then(|w| w.id)).collect();
+        assert_eq!(ids, vec![Some(1), Some(2), Some(2), Some(4), Some(5), None, None]);
+        assert_eq!(resolved.references[3].0, references[3]);
+        assert_eq!(resolved.resolved().len(), 5);
+        assert_eq!(resolved.unresolved(), vec!["Unlinked citation".to_string(), "10.1000/d".to_string()]);
+        assert_eq!(resolver.cached(&references[6]), Some(None));
+        assert!(!resolved.rate_limited);
+
+        let again = resolver.resolve_all(&references).unwrap();
+        assert_eq!(again.resolved().len(), 5);
+        assert!(matches!(ReferenceResolver::new(&api).resolve_all(&references), Err(Error::InvalidApiKey)));
+    }
+
+    #[test]
+    fn test_search_iter_scrolls_past_offset_limit() {
+        use std::collections::BTreeMap;
+        use crate::{errors::Error, Api, SearchQuery};
+        use crate::cassette::{Cassette, Interaction, RecordedRequest, RecordedResponse};
+
+        let interaction = |query: SearchQuery, ids: std::ops::Range<i32>, scroll_id: Option<&str>| {
+            let results: Vec<String> = ids.map(|id| format!(r#"{{"id": {}}}"#, id)).collect();
+            let scroll_id = scroll_id.map_or(String::new(), |id| format!(r#""scrollId": "{}", "#, id));
+            Interaction {
+                request: RecordedRequest { method: "GET".to_string(), uri: format!("search/works/{}", query.parse()), headers: BTreeMap::new(), body: None },
+                response: RecordedResponse {
+                    status: 200,
+                    rate_limit: None,
+                    body: format!(r#"{{"totalHits": 10002, "limit": 5000, "offset": 0, {}"results": [{}]}}"#, scroll_id, results.join(",")),
+                },
+            }
+        };
+        let scroll = |scroll_id: Option<&str>| SearchQuery::paged(5000, 0).with_scroll(5000, scroll_id.map(str::to_string));
+        let cassette = Cassette {
+            interactions: vec![
+                interaction(SearchQuery::paged(5000, 0), 0..5000, None),
+                // the first page shows the results pass offset 10000, so the iterator scrolls from the start
+                interaction(scroll(None), 0..5000, Some("a")),
+                interaction(scroll(Some("a")), 5000..10000, Some("b")),
+                interaction(scroll(Some("b")), 10000..10002, Some("c")),
+            ],
+        };
+        let api = Api::from("API_KEY").replay(cassette);
+
+        let ids: Vec<i32> = api.iter_works(api.paged_search::<String, String>(5000, 0))
+            .map(|work| work.unwrap().id.unwrap())
+            .collect();
+        assert_eq!(ids, (0..10002).collect::<Vec<_>>());
+        assert!(scroll(Some("a")).parse().contains("scroll=true&scrollId=a"));
+
+        // results within the limit are paged by offset
+        let ids: Vec<i32> = api.iter_works(api.paged_search::<String, String>(5000, 0)).max_results(5000)
+            .map(|work| work.unwrap().id.unwrap())
+            .collect();
+        assert_eq!(ids, (0..5000).collect::<Vec<_>>());
+
+        // a search from a later offset stops at the limit instead of scrolling past the skipped results
+        let api = Api::from("API_KEY").replay(Cassette {
+            interactions: vec![
+                interaction(SearchQuery::paged(5000, 3000), 3000..8000, None),
+                interaction(SearchQuery::paged(2000, 8000), 8000..10000, None),
+            ],
+        });
+        let results: Vec<_> = api.iter_works(api.paged_search::<String, String>(5000, 3000)).collect();
+        assert_eq!(results.len(), 7001);
+        assert!(matches!(results.last(), Some(Err(Error::OffsetLimit(10000)))));
+    }
+
+    #[test]
+    fn test_resolve_refs() {
+        use std::collections::BTreeMap;
+        use crate::{errors::Error, Api, DataProviderRef, JournalRef};
+        use crate::cassette::{Cassette, Interaction, RecordedRequest, RecordedResponse};
+
+        let interaction = |uri: &str, body: &str| Interaction {
+            request: RecordedRequest { method: "GET".to_string(), uri: uri.to_string(), headers: BTreeMap::new(), body: None },
+            response: RecordedResponse { status: 200, rate_limit: Some(5), body: body.to_string() },
+        };
+        let journal = |title: &str| format!(r#"{{"identifiers": [], "title": "{}", "language": "en", "publisher": "", "subjects": [], "dataProviderId": 1}}"#, title);
+        let api = Api::from("API_KEY").replay(Cassette {
+            interactions: vec![
+                interaction("data-providers/86", r#"{"id": 86, "name": "Provider", "software": "DSpace", "type": "REPOSITORY"}"#),
+                interaction("journals/issn:0028-0836", &journal("Nature")),
+                interaction("journals/eissn:abc", &journal("Other")),
+            ],
+        });
+
+        let provider = DataProviderRef { id: 86, ..Default::default() }.resolve(&api).unwrap();
+        assert_eq!((provider.response.id, provider.ratelimit_remaining), (86, Some(5)));
+
+        // the first valid ISSN is preferred over other identifiers
+        let nature = JournalRef { title: None, identifiers: vec!["eissn:abc".to_string(), "issn:00280836".to_string()] };
+        assert_eq!(nature.resolve(&api).unwrap().response.title, "Nature");
+        let other = JournalRef { title: None, identifiers: vec!["eissn:abc".to_string()] };
+        assert_eq!(other.resolve(&api).unwrap().response.title, "Other");
+        let empty = JournalRef { title: Some("Empty".to_string()), identifiers: vec![] };
+        assert!(matches!(empty.resolve(&api), Err(Error::InvalidIdentifier(_))));
+    }
+
+    #[test]
+    fn test_language_lookup() {
+        use crate::{Language, SearchQuery};
+
+        // ISO 639-1, ISO 639-2/T (= 639-3), ISO 639-2/B, regional tags and names all resolve to the same language
+        for value in ["de", "deu", "ger", "DE", "de-AT", "de_CH", "German", " german "] {
+            let language = Language::from(value);
+            assert_eq!((language.iso_639_1(), language.iso_639_2()), (Some("de"), Some("deu")), "{}", value);
+            assert_eq!(language.normalized(), Language { code: Some("de".to_string()), name: Some("German".to_string()) });
+        }
+        assert!(Language::from("fre").same_as(&Language::from("French")));
+        assert_eq!(Language::from("XYZ"), Language { code: Some("xyz".to_string()), name: None });
+        assert_eq!(Language::from("Old Norse"), Language { code: None, name: Some("Old Norse".to_string()) });
+
+        let query = |language: Language| SearchQuery::<String, String>::paged(10, 0).language(language).parse();
+        for value in ["en", "eng", "en-GB", "English"] {
+            assert_eq!(query(Language::from(value)), "?limit=10&offset=0&q=%20AND%20language.code:en", "{}", value);
+        }
+        assert_eq!(query(Language::from("xyz")), "?limit=10&offset=0&q=%20AND%20language.code:xyz");
+        assert_eq!(query(Language::from("Old Norse")), "?limit=10&offset=0&q=%20AND%20language.name:%22Old%20Norse%22");
+        assert_eq!(query(Language::from("Español")), "?limit=10&offset=0&q=%20AND%20language.name:Espa%C3%B1ol");
+        assert_eq!(
+            query(Language::from("Tok Pisin & \"Pidgin\"")),
+            "?limit=10&offset=0&q=%20AND%20language.name:%22Tok%20Pisin%20%26%20%5C%22Pidgin%5C%22%22"
+        );
+        assert_eq!(query(Language::default()), "?limit=10&offset=0");
+    }
+
+    #[test]
+    fn test_find_work_encodes_identifier() {
+        use std::collections::BTreeMap;
+        use crate::{Api, ArxivId, Doi, WorkIdentifier};
+        use crate::cassette::{Cassette, Interaction, RecordedRequest, RecordedResponse};
+
+        // `#` would cut off the URL and `&` would split the query parameter
+        let doi = Doi::parse("10.1002/(SICI)1097-4571#a&b;<c>").unwrap();
+        assert_eq!(doi.search_expression(), "doi:%2210.1002%2F%28sici%291097-4571%23a%26b%3B%3Cc%3E%22");
+        assert_eq!(ArxivId::parse("2101.00001v2").unwrap().search_expression(), "arxivId:%222101.00001%22");
+
+        let api = Api::from("API_KEY").replay(Cassette {
+            interactions: vec![Interaction {
+                request: RecordedRequest {
+                    method: "GET".to_string(),
+                    uri: format!("search/works/?limit=1&offset=0&q=%20AND%20{}", doi.search_expression()),
+                    headers: BTreeMap::new(),
+                    body: None,
+                },
+                response: RecordedResponse {
+                    status: 200,
+                    rate_limit: None,
+                    body: r#"{"totalHits": 1, "limit": 1, "offset": 0, "results": [{"id": 7, "doi": "10.1002/(SICI)1097-4571#a&b;<c>"}]}"#.to_string(),
+                },
+            }],
+        });
+        let work = api.find_work(&doi).unwrap().response.unwrap();
+        assert_eq!(work.id, Some(7));
+    }
 }
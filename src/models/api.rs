@@ -1,9 +1,13 @@
+use std::sync::Mutex;
+
 use reqwest::{blocking::Client, header};
 use serde::de::DeserializeOwned;
 use crate::{
-    helpers::response_handler::{parse_raw_response, parse_json}, 
-    responses::{response::ApiResponse, search::SearchResponse}, 
-    SearchQuery, Work, DataProvider,
+    cache::{disk::DiskCache, memory::ResponseCache, CacheStats, RawResponse, RequestKey},
+    cassette::{recorder::Recorder, replayer::Replayer, Cassette, RecordedResponse},
+    helpers::response_handler::{parse_raw_response, parse_json, read_raw_response}, 
+    responses::{response::ApiResponse, search::SearchResponse, search_iter::SearchIter}, 
+    SearchQuery, Work, DataProvider, Output, Issn, WorkIdentifier,
 };
 
 use super::{query_models::{query::Query, request_type::QueryRequestType}, discovery_models::discovery::Discovery, journal_models::journal::Journal};
@@ -16,15 +20,21 @@ use super::{query_models::{query::Query, request_type::QueryRequestType}, discov
 ///
 /// Key methods include:
 /// * `get_output`: Get a single Output based on CORE id.
+/// * `get_work`: Get a single Work based on CORE id.
+/// * `find_work`: Find a single Work based on an identifier such as a DOI or arXiv id.
 /// * `get_journal`: Get a single journal based on its identifier in CORE
 /// * `discover`: Allows you to find links to full texts based on a DOI. The system will search through the CORE data and other external sources to provide you the best match.
 /// * `search_works`: Executes a search for research works.
 /// * `search_data_providers`: Executes a search for data providers.
 /// * `search_journals`: Executes a search for journal titles.
 /// * `search_outputs`: Executes a search for work outputs.
+/// * `iter_works`, `iter_data_providers`, `iter_journals`, `iter_outputs`: Iterate over all results of a search.
 /// * `paged_search`: Initiates a paginated search. (SearchQuery builder)
 /// * `log_target`: Enables/disables logging of the target URI.
 /// * `log_raw_response`: Enables/disables logging of the raw response.
+/// * `cache`: Answers repeated requests from an in-memory `ResponseCache`.
+/// * `disk_cache`, `offline`: Stores responses in a `DiskCache` and serves requests only from it.
+/// * `record`, `replay`: Records requests and responses to a cassette file and serves them back in tests.
 ///
 /// An instance of `Api` can be created using an API key and provides an easy way to interact with the API service.
 #[derive(Debug)]
@@ -33,6 +43,18 @@ pub struct Api {
     client: Client,
     log_target: bool,
     log_raw_response: bool,
+    cache: Option<Mutex<ResponseCache>>,
+    disk_cache: Option<DiskCache>,
+    offline: bool,
+    transport: Transport,
+}
+
+/// How requests that are not cached are answered.
+#[derive(Debug)]
+enum Transport {
+    Http,
+    Record(Mutex<Recorder>),
+    Replay(Mutex<Replayer>),
 }
 
 
@@ -42,15 +64,16 @@ impl Api {
     /// 
     /// # Parameters
     /// 
-    /// * 'doi' - Doi of the target discover resource 
+    /// * 'doi' - Doi of the target discover resource, either as a string or a `Doi`
     /// 
     /// # Examples
     /// 
     /// ```
-    /// use core_api_client::Api;
+    /// use core_api_client::{Api, Doi};
     /// 
     /// let api = Api::from("API_KEY");
     /// api.discover("10.1016/0370-2693(96)00910-0");
+    /// api.discover(Doi::parse("https://doi.org/10.1016/0370-2693(96)00910-0").unwrap());
     /// ```
     pub fn discover<T>(
         &self,
@@ -63,19 +86,21 @@ impl Api {
     }
 
 
-    /// Fetches a single output from CORE using the provided output id.
+    /// Fetches a single journal from CORE using the provided journal id.
     ///
     /// # Parameters
     ///
     /// * `id` - The Journal id in CORE. Use issn:ISSN to search by ISSN instead of the CORE identifier.
+    ///   Valid ISSNs (including `Issn` values) without the `issn:` prefix are prefixed automatically.
     /// 
     /// # Examples
     ///
     /// ```rust
-    /// use core_api_client::Api;
+    /// use core_api_client::{Api, Issn};
     /// 
     /// let api = Api::from("API_KEY");
     /// api.get_journal("issn:1179-1497");
+    /// api.get_journal(Issn::parse("1179-1497").unwrap());
     /// ```
     pub fn get_journal<T>(
         &self,
@@ -84,7 +109,12 @@ impl Api {
     where 
         T: ToString + Clone
     {
-        self.execute_query::<T, String, Journal>(Query::Journals(id))
+        let id = id.to_string();
+        let id = match Issn::parse(&id) {
+            Ok(issn) if !id.to_lowercase().starts_with("issn:") => issn.journal_id(),
+            _ => id,
+        };
+        self.execute_query::<String, String, Journal>(Query::Journals(id))
     }
 
     /// Fetches a single output from CORE using the provided output id.
@@ -105,11 +135,67 @@ impl Api {
     pub fn get_output<T>(
         &self,
         id: T
+    ) -> Result<ApiResponse<Output>, crate::errors::Error>
+    where 
+        T: ToString + Clone
+    {
+        self.execute_query::<T, String, Output>(Query::Outputs(id))
+    }
+
+    /// Fetches a single work from CORE using the provided work id.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The CORE ID of the work to be fetched.
+    /// 
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core_api_client::Api;
+    /// 
+    /// let api = Api::from("API_KEY");
+    /// api.get_work(0);
+    /// ```
+    pub fn get_work<T>(
+        &self,
+        id: T
     ) -> Result<ApiResponse<Work>, crate::errors::Error>
     where 
         T: ToString + Clone
     {
-        self.execute_query::<T, String, Work>(Query::Outputs(id))
+        self.execute_query::<T, String, Work>(Query::Works(id))
+    }
+
+    /// Finds a single work by an external identifier. The lookup is executed as a search on the works
+    /// endpoint and returns the best match, or `None` if CORE does not know the identifier.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Any `WorkIdentifier`, e.g. `Doi`, `ArxivId`, `Pmid` or `OaiId`.
+    /// 
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core_api_client::{Api, Doi};
+    /// 
+    /// let api = Api::from("API_KEY");
+    /// let doi = Doi::parse("10.1016/0370-2693(96)00910-0").unwrap();
+    /// api.find_work(&doi);
+    /// ```
+    pub fn find_work<I>(
+        &self,
+        id: &I
+    ) -> Result<ApiResponse<Option<Work>>, crate::errors::Error>
+    where 
+        I: WorkIdentifier
+    {
+        let query = SearchQuery::<String, String>::paged(1, 0)
+            .and_raw(id.search_expression());
+        let resp = self.search_works(query)?;
+        Ok(ApiResponse {
+            ratelimit_remaining: resp.ratelimit_remaining,
+            response: resp.response.results.and_then(|r| r.into_iter().next()),
+        })
     }
 
     /// Fetches a specific data provider from CORE using the provided data provider identifier.
@@ -246,7 +332,7 @@ impl Api {
     pub fn search_outputs<T1, T2>(
         &self, 
         query: SearchQuery<T1, T2>
-    ) -> Result<ApiResponse<SearchResponse<Work>>, crate::errors::Error> 
+    ) -> Result<ApiResponse<SearchResponse<Output>>, crate::errors::Error> 
     where 
         T1: ToString + Clone,
         T2: ToString + Clone,
@@ -255,6 +341,51 @@ impl Api {
     }
     
 
+    /// Iterates over all works matching the query, fetching further pages as the iterator is consumed.
+    /// The limit of the query is used as page size. See `SearchIter`.
+    ///
+    /// ```no_run
+    /// use core_api_client::{Api, FilterOperator};
+    ///
+    /// let api = Api::from("API_KEY");
+    /// let query = api.paged_search::<_, String>(100, 0).and(FilterOperator::Exists("doi"));
+    /// let works: Vec<_> = api.iter_works(query).max_results(500).collect::<Result<_, _>>().unwrap();
+    /// ```
+    pub fn iter_works<T1, T2>(&self, query: SearchQuery<T1, T2>) -> SearchIter<'_, Work, T1, T2>
+    where
+        T1: ToString + Clone,
+        T2: ToString + Clone,
+    {
+        SearchIter::new(self, query, Api::search_works)
+    }
+
+    /// Iterates over all data providers matching the query, see `iter_works`.
+    pub fn iter_data_providers<T1, T2>(&self, query: SearchQuery<T1, T2>) -> SearchIter<'_, DataProvider, T1, T2>
+    where
+        T1: ToString + Clone,
+        T2: ToString + Clone,
+    {
+        SearchIter::new(self, query, Api::search_data_providers)
+    }
+
+    /// Iterates over all journals matching the query, see `iter_works`.
+    pub fn iter_journals<T1, T2>(&self, query: SearchQuery<T1, T2>) -> SearchIter<'_, Journal, T1, T2>
+    where
+        T1: ToString + Clone,
+        T2: ToString + Clone,
+    {
+        SearchIter::new(self, query, Api::search_journals)
+    }
+
+    /// Iterates over all outputs matching the query, see `iter_works`.
+    pub fn iter_outputs<T1, T2>(&self, query: SearchQuery<T1, T2>) -> SearchIter<'_, Output, T1, T2>
+    where
+        T1: ToString + Clone,
+        T2: ToString + Clone,
+    {
+        SearchIter::new(self, query, Api::search_outputs)
+    }
+
     /// The `paged_search` method initiates a paginated search on the API.
     /// It takes a limit and an offset as arguments, representing the number of results to return per page and the starting point for the results respectively.
     /// This method returns a `SearchQuery` object that can be further manipulated to define the search criteria.
@@ -292,7 +423,7 @@ impl Api {
     /// let api = Api::from("API_KEY").log_target(true);
     /// ```
     pub fn log_target(self, log_target: bool) -> Self {
-        Self { key: self.key, client: self.client, log_target, log_raw_response: self.log_raw_response }
+        Self { log_target, ..self }
     }
 
 
@@ -303,7 +434,73 @@ impl Api {
     /// let api = Api::from("API_KEY").log_raw_response(true);
     /// ```
     pub fn log_raw_response(self, log_raw_response: bool) -> Self {
-        Self { key: self.key, client: self.client, log_target: self.log_target, log_raw_response }
+        Self { log_raw_response, ..self }
+    }
+
+    /// Enables caching of responses. Requests already answered are served from the cache until their TTL
+    /// passes; responses served from the cache report no remaining rate limit (`ratelimit_remaining` is `None`).
+    /// ```
+    /// use core_api_client::{cache::memory::ResponseCache, Api};
+    /// let api = Api::from("API_KEY").cache(ResponseCache::new(500));
+    /// ```
+    pub fn cache(self, cache: ResponseCache) -> Self {
+        Self { cache: Some(Mutex::new(cache)), ..self }
+    }
+
+    /// Returns the hit and miss counters of the cache, `None` if caching is not enabled.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(|cache| lock(cache).stats())
+    }
+
+    /// Stores responses in a persistent `DiskCache` and answers requests found in it without using the network.
+    /// When an in-memory cache is enabled too, it is checked first. Responses that can't be written to the
+    /// disk cache are still returned, the failure is only logged when `log_target` is enabled.
+    /// ```
+    /// use core_api_client::{cache::disk::DiskCache, Api};
+    /// let cache = DiskCache::open(std::env::temp_dir().join("core-api-cache")).unwrap();
+    /// let api = Api::from("API_KEY").disk_cache(cache);
+    /// ```
+    pub fn disk_cache(self, disk_cache: DiskCache) -> Self {
+        Self { disk_cache: Some(disk_cache), ..self }
+    }
+
+    /// Enables offline mode: requests are answered only from the caches, and fail with `Error::NotCached`
+    /// when they are not cached.
+    /// ```
+    /// use core_api_client::{errors::Error, Api};
+    /// let api = Api::from("API_KEY").offline(true);
+    /// assert!(matches!(api.get_work(1), Err(Error::NotCached(_))));
+    /// ```
+    pub fn offline(self, offline: bool) -> Self {
+        Self { offline, ..self }
+    }
+
+    /// Records every request sent to the API and its response with a `Recorder`, to be replayed later with
+    /// `replay`. The API key is redacted from the recorded requests.
+    /// ```no_run
+    /// use core_api_client::{cassette::recorder::Recorder, Api};
+    /// let api = Api::from("API_KEY").record(Recorder::create("cassette.jsonl").unwrap());
+    /// ```
+    pub fn record(self, recorder: Recorder) -> Self {
+        Self { transport: Transport::Record(Mutex::new(recorder)), ..self }
+    }
+
+    /// Answers requests from a recorded `Cassette` instead of the API. Requests not found in the cassette fail
+    /// with `Error::NotRecorded`.
+    /// ```
+    /// use core_api_client::{cassette::Cassette, errors::Error, Api};
+    /// let api = Api::from("API_KEY").replay(Cassette::default());
+    /// assert!(matches!(api.get_work(1), Err(Error::NotRecorded(_))));
+    /// ```
+    pub fn replay(self, cassette: Cassette) -> Self {
+        Self { transport: Transport::Replay(Mutex::new(Replayer::new(cassette))), ..self }
+    }
+
+    /// Removes all cached responses.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = self.cache.as_ref() {
+            lock(cache).clear();
+        }
     }
 
 
@@ -323,14 +520,118 @@ impl Api {
         T2: ToString + Clone,
         T3: DeserializeOwned
     {
-        let (req_type, query_uri, body) = query.parse_request();
+        let (method, uri, body) = query.parse_request();
+        let key = RequestKey { method, uri, body };
+
+        if let Some(cached) = self.cached(&key)? {
+            if self.log_target {
+                println!("{} (cached)", key.uri);
+            }
+            return Ok(ApiResponse {
+                ratelimit_remaining: None,
+                response: parse_json::<T3>(&cached.data)?,
+            });
+        }
+        if self.offline {
+            return Err(crate::errors::Error::NotCached(key.uri));
+        }
+
+        let raw = self.fetch(&key)?;
+        let response = parse_json::<T3>(&raw.data)?;
+        let ratelimit_remaining = raw.rate_limit;
+        if let Some(disk_cache) = self.disk_cache.as_ref() {
+            // the response is valid even if it could not be cached
+            if let Err(e) = disk_cache.insert(&key, &raw) {
+                if self.log_target {
+                    println!("{} (not cached: {:?})", key.uri, e);
+                }
+            }
+        }
+        if let Some(cache) = self.cache.as_ref() {
+            lock(cache).insert(key, raw);
+        }
+
+        Ok(ApiResponse {
+            ratelimit_remaining,
+            response,
+        })
+    }
+
+    /// Searches works bypassing the in-memory and disk caches, for requests that must see the current data.
+    /// Fails with `Error::NotCached` in offline mode.
+    pub(crate) fn search_works_uncached<T1, T2>(
+        &self,
+        query: SearchQuery<T1, T2>
+    ) -> Result<ApiResponse<SearchResponse<Work>>, crate::errors::Error>
+    where
+        T1: ToString + Clone,
+        T2: ToString + Clone,
+    {
+        let (method, uri, body) = Query::SearchWorks(query).parse_request();
+        let key = RequestKey { method, uri, body };
+        if self.offline {
+            return Err(crate::errors::Error::NotCached(key.uri));
+        }
+        let raw = self.fetch(&key)?;
+        Ok(ApiResponse {
+            ratelimit_remaining: raw.rate_limit,
+            response: parse_json(&raw.data)?,
+        })
+    }
+
+    /// Looks a request up in the in-memory cache, then in the disk cache.
+    fn cached(&self, key: &RequestKey) -> Result<Option<RawResponse>, crate::errors::Error> {
+        if let Some(cached) = self.cache.as_ref().and_then(|cache| lock(cache).get(key)) {
+            return Ok(Some(cached));
+        }
+        let Some(cached) = self.disk_cache.as_ref().map(|disk_cache| disk_cache.get(key)).transpose()?.flatten() else {
+            return Ok(None);
+        };
+        if let Some(cache) = self.cache.as_ref() {
+            lock(cache).insert(key.clone(), cached.clone());
+        }
+        Ok(Some(cached))
+    }
+
+    /// Sends a request to the API (or replays it from a cassette) and returns the raw response body with the
+    /// remaining rate limit.
+    fn fetch(&self, key: &RequestKey) -> Result<RawResponse, crate::errors::Error> {
+        let (status, data, rate_limit) = match &self.transport {
+            Transport::Http => self.send(key)?,
+            Transport::Record(recorder) => {
+                let (status, data, rate_limit) = self.send(key)?;
+                let response = RecordedResponse { status, rate_limit, body: data.clone() };
+                lock(recorder).record(key, response)?;
+                (status, data, rate_limit)
+            },
+            Transport::Replay(replayer) => {
+                if self.log_target {
+                    println!("{} (replayed)", key.uri);
+                }
+                match lock(replayer).replay(key) {
+                    Some(response) => (response.status, response.body, response.rate_limit),
+                    None => return Err(crate::errors::Error::NotRecorded(format!("{} {}", key.method.as_str(), key.uri))),
+                }
+            },
+        };
         
-        let target = format!("https://api.core.ac.uk/v3/{}", query_uri);
+        let (data, rate_limit) = parse_raw_response(status, data, rate_limit)?;
+        
+        if self.log_raw_response {
+            println!("{}", data);
+        }
+        
+        Ok(RawResponse { data, rate_limit })
+    }
+
+    /// Sends a request to the API and returns the status, body and remaining rate limit of the response.
+    fn send(&self, key: &RequestKey) -> Result<(u16, String, Option<i32>), crate::errors::Error> {
+        let target = format!("https://api.core.ac.uk/v3/{}", key.uri);
         if self.log_target {
-            println!("{}", query_uri);
+            println!("{}", key.uri);
         }
         
-        let client_builer = match req_type {
+        let client_builer = match key.method {
             QueryRequestType::Get   => self.client.get(target),
             QueryRequestType::Post  => self.client.post(target),
         };
@@ -340,35 +641,29 @@ impl Api {
             format!("Bearer {}", self.key.clone())
         );
 
-        let client_builder = match (req_type, body) {
+        let client_builder = match (&key.method, &key.body) {
             (QueryRequestType::Get,     None)                   => client_builer,
             (QueryRequestType::Get,     Some(_))                => client_builer,
             (QueryRequestType::Post,    None)                   => client_builer,
-            (QueryRequestType::Post,    Some(content))          => client_builer.body(content),
+            (QueryRequestType::Post,    Some(content))          => client_builer.body(content.clone()),
         };
 
-        let response = match client_builder.send() {
-            Ok(r) => r,
-            Err(e) => return Err(crate::errors::Error::Request(e)),
-        };
-        
-        let (data, rate_limit) = parse_raw_response(response)?;
-        
-        if self.log_raw_response {
-            println!("{}", data);
+        match client_builder.send() {
+            Ok(response) => read_raw_response(response),
+            Err(e) => Err(crate::errors::Error::Request(e)),
         }
-        
-        Ok(ApiResponse {
-            ratelimit_remaining: rate_limit,
-            response: parse_json::<T3>(&data)?,
-        })
     }
 
 }
 
+/// Locks shared state, recovering it if a previous holder panicked.
+fn lock<T>(state: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
 impl<T: Into<String>> From<T> for Api {
     fn from(key: T) -> Self {
         let client = reqwest::blocking::Client::new();
-        Api { key: key.into(), client, log_target: false, log_raw_response: false }
+        Api { key: key.into(), client, log_target: false, log_raw_response: false, cache: None, disk_cache: None, offline: false, transport: Transport::Http }
     }
 }
@@ -1,10 +1,18 @@
 use serde::{Deserialize, Serialize};
 
-use super::{other_identifiers::OtherIdentifiers, location::Location};
+use crate::ApiDate;
+
+use super::{
+    other_identifiers::OtherIdentifiers,
+    location::Location,
+    stats::{DataProviderStats, deserialize_stats},
+    data_provider_type::DataProviderType,
+    software::RepositorySoftware,
+};
 
 /// Represents a provider of open access scientific papers and other resources.
 /// More information [here](https://api.core.ac.uk/docs/v3#tag/Data-Providers)
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
 pub struct DataProvider {
     /// Unique ID of the data provider
     pub id: i32,
@@ -14,17 +22,17 @@ pub struct DataProvider {
     pub open_doar_id: Option<i32>,
     
     /// Name of the data provider
-    pub name: String,
+    pub name: Option<String>,
     
     /// Contact email of the data provider
-    pub email: String,
+    pub email: Option<String>,
     
     /// URI of the data provider
     pub uri: Option<String>,
     
     /// OAI-PMH (Open Archives Initiative Protocol for Metadata Harvesting) URL of the data provider
     #[serde(rename = "oaiPmhUrl")]
-    pub oai_pmh_url: String,
+    pub oai_pmh_url: Option<String>,
     
     /// Homepage URL of the data provider
     #[serde(rename = "homepageUrl")]
@@ -34,28 +42,29 @@ pub struct DataProvider {
     pub source: Option<String>,
     
     /// Software used by the data provider
-    pub software: Option<String>,
+    pub software: Option<RepositorySoftware>,
     
     /// Metadata format used by the data provider
     #[serde(rename = "metadataFormat")]
-    pub metadata_format: String,
+    pub metadata_format: Option<String>,
     
     /// Date the data provider was created
     #[serde(rename = "createdDate")]
-    pub created_date: Option<String>,
+    pub created_date: Option<ApiDate>,
     
     /// Location of the data provider
-    pub location: Location,
+    pub location: Option<Location>,
     
     /// Logo URL of the data provider
-    pub logo: String,
+    pub logo: Option<String>,
     
     /// Type of the data provider
     #[serde(rename = "type")]
-    pub data_provider_type: String,
+    pub data_provider_type: Option<DataProviderType>,
     
     /// Statistical data about the data provider
-    pub stats: Option<String>,
+    #[serde(deserialize_with = "deserialize_stats", default)]
+    pub stats: Option<DataProviderStats>,
     
     /// ROR (Research Organization Registry) ID of the data provider
     #[serde(rename = "rorId")]
@@ -66,12 +75,9 @@ pub struct DataProvider {
     pub institution_name: Option<String>,
     
     /// Aliases of the data provider
-    pub aliases: Vec<String>,
+    pub aliases: Option<Vec<String>>,
     
     /// Other identifiers associated with the data provider
     #[serde(rename = "otherIdentifiers")]
     pub other_identifiers: Option<OtherIdentifiers>,
 }
-
-
-
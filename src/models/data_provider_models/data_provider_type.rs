@@ -0,0 +1,70 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Type of a data provider. Types not known to the client are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DataProviderType {
+    /// Institutional or disciplinary repository.
+    Repository,
+    /// Journal.
+    Journal,
+    /// Publisher.
+    Publisher,
+    /// Preprint server.
+    PreprintServer,
+    /// Aggregator of other data providers.
+    Aggregator,
+    /// Any other type, holding the type as returned by the API.
+    Other(String),
+    /// Data provider without a type.
+    #[default]
+    Unknown,
+}
+
+impl DataProviderType {
+    /// Returns the type as used by the API (e.g. `REPOSITORY`).
+    pub fn as_str(&self) -> &str {
+        match self {
+            DataProviderType::Repository => "REPOSITORY",
+            DataProviderType::Journal => "JOURNAL",
+            DataProviderType::Publisher => "PUBLISHER",
+            DataProviderType::PreprintServer => "PREPRINT_SERVER",
+            DataProviderType::Aggregator => "AGGREGATOR",
+            DataProviderType::Other(kind) => kind,
+            DataProviderType::Unknown => "",
+        }
+    }
+}
+
+impl From<&str> for DataProviderType {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().replace([' ', '-'], "_").as_str() {
+            "REPOSITORY" => DataProviderType::Repository,
+            "JOURNAL" => DataProviderType::Journal,
+            "PUBLISHER" => DataProviderType::Publisher,
+            "PREPRINT_SERVER" | "PREPRINT" => DataProviderType::PreprintServer,
+            "AGGREGATOR" => DataProviderType::Aggregator,
+            "" => DataProviderType::Unknown,
+            _ => DataProviderType::Other(value.to_string()),
+        }
+    }
+}
+
+impl Serialize for DataProviderType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for DataProviderType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value: Option<String> = Option::deserialize(deserializer)?;
+        Ok(value.map_or(DataProviderType::Unknown, |v| DataProviderType::from(v.as_str())))
+    }
+}
+
+impl fmt::Display for DataProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -2,7 +2,7 @@ use std::hash::{Hash, Hasher};
 use serde::{Deserialize, Serialize};
 
 /// Represents the geographical location of a data provider.
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd,  Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd,  Default)]
 pub struct Location {
     /// Country code of the location
     #[serde(rename = "countryCode")]
@@ -1,4 +1,7 @@
 pub mod data_provider;
 pub mod identifier_type;
 pub mod other_identifiers;
-pub mod location;
\ No newline at end of file
+pub mod location;
+pub mod stats;
+pub mod data_provider_type;
+pub mod software;
\ No newline at end of file
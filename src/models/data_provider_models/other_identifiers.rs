@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use super::identifier_type::IdentifierType;
 
 /// Represents other identifiers associated with a data provider.
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
 pub struct OtherIdentifiers {
     /// GRID (Global Research Identifier Database) ID of the data provider
     #[serde(rename = "GRID")]
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Repository software used by a data provider. Software not known to the client is kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum RepositorySoftware {
+    /// DSpace.
+    DSpace,
+    /// EPrints.
+    EPrints,
+    /// Open Journal Systems.
+    Ojs,
+    /// Fedora Commons.
+    Fedora,
+    /// bepress Digital Commons.
+    DigitalCommons,
+    /// Invenio.
+    Invenio,
+    /// Islandora.
+    Islandora,
+    /// Dataverse.
+    Dataverse,
+    /// Figshare.
+    Figshare,
+    /// Elsevier Pure.
+    Pure,
+    /// OPUS.
+    Opus,
+    /// Samvera Hyrax.
+    Hyrax,
+    /// Any other software, holding the software as returned by the API.
+    Other(String),
+    /// Data provider without known software.
+    #[default]
+    Unknown,
+}
+
+impl RepositorySoftware {
+    /// Returns the software as used by the API (e.g. `dspace`).
+    pub fn as_str(&self) -> &str {
+        match self {
+            RepositorySoftware::DSpace => "dspace",
+            RepositorySoftware::EPrints => "eprints",
+            RepositorySoftware::Ojs => "ojs",
+            RepositorySoftware::Fedora => "fedora",
+            RepositorySoftware::DigitalCommons => "digital_commons",
+            RepositorySoftware::Invenio => "invenio",
+            RepositorySoftware::Islandora => "islandora",
+            RepositorySoftware::Dataverse => "dataverse",
+            RepositorySoftware::Figshare => "figshare",
+            RepositorySoftware::Pure => "pure",
+            RepositorySoftware::Opus => "opus",
+            RepositorySoftware::Hyrax => "hyrax",
+            RepositorySoftware::Other(software) => software,
+            RepositorySoftware::Unknown => "",
+        }
+    }
+}
+
+impl From<&str> for RepositorySoftware {
+    fn from(value: &str) -> Self {
+        let normalised: String = value.trim()
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect();
+        match normalised.as_str() {
+            "dspace" => RepositorySoftware::DSpace,
+            "eprints" => RepositorySoftware::EPrints,
+            "ojs" | "openjournalsystems" => RepositorySoftware::Ojs,
+            "fedora" => RepositorySoftware::Fedora,
+            "digitalcommons" | "bepress" => RepositorySoftware::DigitalCommons,
+            "invenio" => RepositorySoftware::Invenio,
+            "islandora" => RepositorySoftware::Islandora,
+            "dataverse" => RepositorySoftware::Dataverse,
+            "figshare" => RepositorySoftware::Figshare,
+            "pure" => RepositorySoftware::Pure,
+            "opus" => RepositorySoftware::Opus,
+            "hyrax" | "samvera" => RepositorySoftware::Hyrax,
+            "" | "unknown" => RepositorySoftware::Unknown,
+            _ => RepositorySoftware::Other(value.to_string()),
+        }
+    }
+}
+
+impl Serialize for RepositorySoftware {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RepositorySoftware {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value: Option<String> = Option::deserialize(deserializer)?;
+        Ok(value.map_or(RepositorySoftware::Unknown, |v| RepositorySoftware::from(v.as_str())))
+    }
+}
+
+impl fmt::Display for RepositorySoftware {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
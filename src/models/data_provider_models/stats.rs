@@ -0,0 +1,51 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{helpers::string_number_deserializer::deserialize_as_number, ApiDate};
+
+/// Represents the harvesting statistics of a data provider.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+pub struct DataProviderStats {
+    /// Number of metadata records harvested from the data provider
+    #[serde(rename = "countMetadata", alias = "metadataCount", deserialize_with = "deserialize_as_number", default)]
+    pub count_metadata: Option<i64>,
+
+    /// Number of records with a full text harvested from the data provider
+    #[serde(rename = "countFulltext", alias = "countFullText", alias = "fulltextCount", deserialize_with = "deserialize_as_number", default)]
+    pub count_fulltext: Option<i64>,
+
+    /// Number of harvested records with a DOI
+    #[serde(rename = "countDoi", alias = "countMetadataWithDoi", deserialize_with = "deserialize_as_number", default)]
+    pub count_doi: Option<i64>,
+
+    /// Date the data provider was last harvested
+    #[serde(rename = "lastHarvestingDate", alias = "lastHarvestDate")]
+    pub last_harvesting_date: Option<ApiDate>,
+
+    /// Date of the last successful harvest of the data provider
+    #[serde(rename = "lastSuccessfulHarvestingDate", alias = "lastSuccessfulHarvestDate")]
+    pub last_successful_harvesting_date: Option<ApiDate>,
+}
+
+impl DataProviderStats {
+    /// Returns the share of metadata records that have a full text, if both counts are known.
+    pub fn fulltext_ratio(&self) -> Option<f64> {
+        match (self.count_metadata, self.count_fulltext) {
+            (Some(metadata), Some(fulltext)) if metadata > 0 => Some(fulltext as f64 / metadata as f64),
+            _ => None,
+        }
+    }
+}
+
+/// Custom deserialization function for the stats of a data provider. The API returns the stats
+/// either as an object or as a JSON encoded string, unparseable stats are deserialized as `None`.
+pub(crate) fn deserialize_stats<'de, D>(deserializer: D) -> Result<Option<DataProviderStats>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt = Option::deserialize(deserializer)?;
+    match opt {
+        Some(serde_json::Value::String(s)) => Ok(serde_json::from_str(&s).ok()),
+        Some(value @ serde_json::Value::Object(_)) => Ok(serde_json::from_value(value).ok()),
+        _ => Ok(None),
+    }
+}
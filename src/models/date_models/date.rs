@@ -0,0 +1,178 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Datelike};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// Precision of a date returned by the API. CORE frequently only knows the year or the month
+/// a work was published in, so dates are not guaranteed to be complete.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DatePrecision {
+    /// Only the year is known (e.g. `2019`).
+    Year,
+    /// The year and month are known (e.g. `2019-05`).
+    Month,
+    /// The full date is known (e.g. `2019-05-21`).
+    Day,
+    /// The date and time are known (e.g. `2019-05-21T10:15:00`).
+    DateTime,
+}
+
+/// A date as returned by the CORE API.
+///
+/// The raw value is always preserved, while `value` holds the parsed date if the format was recognised.
+/// Partial dates are parsed to the first day of the period they describe (`2019` is 2019-01-01 and 
+/// `2019-05` is 2019-05-01), with `precision` telling how much of the date was actually present.
+/// Dates are ordered by their parsed value, unparseable dates order before any parsed date.
+///
+/// ```
+/// use core_api_client::{ApiDate, DatePrecision};
+///
+/// let date = ApiDate::parse("2019-05");
+/// assert_eq!(date.year(), Some(2019));
+/// assert_eq!(date.precision, Some(DatePrecision::Month));
+/// assert_eq!(date.raw, "2019-05");
+/// assert!(ApiDate::parse("2019-05-21T10:15:00") > date);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ApiDate {
+    /// Parsed date, `None` if the raw value could not be parsed.
+    pub value: Option<NaiveDateTime>,
+
+    /// Precision of the parsed date, `None` if the raw value could not be parsed.
+    pub precision: Option<DatePrecision>,
+
+    /// Raw value as returned by the API.
+    pub raw: String,
+}
+
+impl ApiDate {
+    /// Parses a date returned by the API. Parsing never fails, unrecognised formats keep only the raw value.
+    ///
+    /// Supported formats are RFC 3339 timestamps, `YYYY-MM-DDTHH:MM:SS[.f]`, `YYYY-MM-DD HH:MM:SS`,
+    /// `YYYY-MM-DD`, `YYYY/MM/DD`, `YYYY-MM` and `YYYY`.
+    pub fn parse<T: ToString>(raw: T) -> Self {
+        let raw = raw.to_string();
+        let (value, precision) = match parse_date(raw.trim()) {
+            Some((value, precision)) => (Some(value), Some(precision)),
+            None => (None, None),
+        };
+        ApiDate { value, precision, raw }
+    }
+
+    /// Returns the date part of the parsed value.
+    pub fn date(&self) -> Option<NaiveDate> {
+        self.value.map(|v| v.date())
+    }
+
+    /// Returns the year of the parsed value.
+    pub fn year(&self) -> Option<i32> {
+        self.value.map(|v| v.year())
+    }
+
+    /// Returns the month of the parsed value, if the date is at least month precise.
+    pub fn month(&self) -> Option<u32> {
+        match self.precision {
+            Some(DatePrecision::Year) | None => None,
+            Some(_) => self.value.map(|v| v.month()),
+        }
+    }
+
+    /// Returns the day of the parsed value, if the date is at least day precise.
+    pub fn day(&self) -> Option<u32> {
+        match self.precision {
+            Some(DatePrecision::Day) | Some(DatePrecision::DateTime) => self.value.map(|v| v.day()),
+            _ => None,
+        }
+    }
+
+    /// Checks whether the raw value was recognised as a date.
+    pub fn is_parsed(&self) -> bool {
+        self.value.is_some()
+    }
+}
+
+fn parse_date(raw: &str) -> Option<(NaiveDateTime, DatePrecision)> {
+    if raw.is_empty() {
+        return None;
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(raw) {
+        return Some((dt.naive_utc(), DatePrecision::DateTime));
+    }
+    for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(raw, format) {
+            return Some((dt, DatePrecision::DateTime));
+        }
+    }
+    for format in ["%Y-%m-%d", "%Y/%m/%d"] {
+        if let Ok(d) = NaiveDate::parse_from_str(raw, format) {
+            return Some((d.and_time(NaiveTime::MIN), DatePrecision::Day));
+        }
+    }
+
+    let mut parts = raw.splitn(2, ['-', '/']);
+    let year = parts.next()
+        .filter(|y| y.len() == 4 && y.chars().all(|c| c.is_ascii_digit()))?
+        .parse::<i32>()
+        .ok()?;
+    match parts.next() {
+        None => NaiveDate::from_ymd_opt(year, 1, 1)
+            .map(|d| (d.and_time(NaiveTime::MIN), DatePrecision::Year)),
+        Some(month) => {
+            let month = month.parse::<u32>().ok()?;
+            NaiveDate::from_ymd_opt(year, month, 1)
+                .map(|d| (d.and_time(NaiveTime::MIN), DatePrecision::Month))
+        }
+    }
+}
+
+impl FromStr for ApiDate {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(ApiDate::parse(s))
+    }
+}
+
+impl From<NaiveDate> for ApiDate {
+    fn from(date: NaiveDate) -> Self {
+        ApiDate { 
+            value: Some(date.and_time(NaiveTime::MIN)), 
+            precision: Some(DatePrecision::Day), 
+            raw: date.format("%Y-%m-%d").to_string(),
+        }
+    }
+}
+
+impl From<NaiveDateTime> for ApiDate {
+    fn from(value: NaiveDateTime) -> Self {
+        ApiDate { 
+            value: Some(value), 
+            precision: Some(DatePrecision::DateTime), 
+            raw: value.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        }
+    }
+}
+
+impl Serialize for ApiDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum IntOrString { Int(i64), String(String) }
+
+        match IntOrString::deserialize(deserializer)? {
+            IntOrString::Int(i) => Ok(ApiDate::parse(i)),
+            IntOrString::String(s) => Ok(ApiDate::parse(s)),
+        }
+    }
+}
+
+impl fmt::Display for ApiDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 
 /// Struct holds the information of the discovoered resource. More info on the work struct [here](https://api.core.ac.uk/docs/v3#tag/Discovery).
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
 pub struct Discovery {
     /// Url link to the full text of the discovered source
     #[serde(rename = "fullTextLink")]
@@ -0,0 +1,117 @@
+use crate::errors::Error;
+
+use super::{impl_identifier, strip_prefixes};
+
+/// A validated [arXiv](https://arxiv.org/) identifier.
+///
+/// Both the current scheme (`2101.00001`, `2101.00001v2`) and the pre-2007 scheme 
+/// (`hep-th/9901001`, `math.GT/0309136v1`) are accepted. The `arXiv:` prefix and 
+/// `https://arxiv.org/abs/` URLs are stripped.
+///
+/// ```
+/// use core_api_client::ArxivId;
+///
+/// let new = ArxivId::parse("arXiv:2101.00001v2").unwrap();
+/// assert_eq!(new.as_str(), "2101.00001v2");
+/// assert_eq!(new.without_version(), "2101.00001");
+/// assert_eq!(new.version(), Some(2));
+///
+/// let old = ArxivId::parse("https://arxiv.org/abs/hep-th/9901001").unwrap();
+/// assert_eq!(old.as_str(), "hep-th/9901001");
+/// assert!(old.is_old_style());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArxivId(String);
+
+impl ArxivId {
+    /// Parses and normalises an arXiv identifier.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let stripped = strip_prefixes(value.trim(), &[
+            "https://arxiv.org/abs/",
+            "http://arxiv.org/abs/",
+            "arxiv.org/abs/",
+            "arxiv:",
+        ]);
+
+        let (base, version) = split_version(stripped);
+        let valid_version = match version {
+            Some(v) => !v.is_empty() && v.chars().all(|c| c.is_ascii_digit()),
+            None => true,
+        };
+        if !valid_version || !(is_new_style(base) || is_old_style(base)) {
+            return Err(Error::InvalidIdentifier(format!("arXiv identifier {}", value)));
+        }
+
+        let normalised = match base.split_once('/') {
+            // archive names are lower case, subject classes are upper case (math.GT)
+            Some((archive, number)) => {
+                let archive = match archive.split_once('.') {
+                    Some((name, class)) => format!("{}.{}", name.to_lowercase(), class.to_uppercase()),
+                    None => archive.to_lowercase(),
+                };
+                format!("{}/{}", archive, number)
+            },
+            None => base.to_string(),
+        };
+        match version {
+            Some(v) => Ok(ArxivId(format!("{}v{}", normalised, v))),
+            None => Ok(ArxivId(normalised)),
+        }
+    }
+
+    /// Returns the identifier without the version suffix.
+    pub fn without_version(&self) -> &str {
+        split_version(&self.0).0
+    }
+
+    /// Returns the version of the identifier, if one was given.
+    pub fn version(&self) -> Option<u32> {
+        split_version(&self.0).1.and_then(|v| v.parse().ok())
+    }
+
+    /// Checks whether the identifier uses the pre-2007 `archive/YYMMNNN` scheme.
+    pub fn is_old_style(&self) -> bool {
+        self.0.contains('/')
+    }
+
+    /// Returns the arXiv abstract page URL of the identifier.
+    pub fn url(&self) -> String {
+        format!("https://arxiv.org/abs/{}", self.0)
+    }
+}
+
+fn split_version(value: &str) -> (&str, Option<&str>) {
+    match value.rfind(['v', 'V']) {
+        Some(i) if value[..i].ends_with(|c: char| c.is_ascii_digit()) => (&value[..i], Some(&value[i + 1..])),
+        _ => (value, None),
+    }
+}
+
+/// `YYMM.NNNN` (until 2014) or `YYMM.NNNNN`.
+fn is_new_style(value: &str) -> bool {
+    match value.split_once('.') {
+        Some((yymm, number)) => {
+            yymm.len() == 4 
+                && yymm.chars().all(|c| c.is_ascii_digit())
+                && (4..=5).contains(&number.len())
+                && number.chars().all(|c| c.is_ascii_digit())
+        },
+        None => false,
+    }
+}
+
+/// `archive(.SUBJECT)?/YYMMNNN`.
+fn is_old_style(value: &str) -> bool {
+    match value.split_once('/') {
+        Some((archive, number)) => {
+            let name = archive.split('.').next().unwrap_or_default();
+            !name.is_empty()
+                && name.chars().all(|c| c.is_ascii_alphabetic() || c == '-')
+                && number.len() == 7
+                && number.chars().all(|c| c.is_ascii_digit())
+        },
+        None => false,
+    }
+}
+
+impl_identifier!(ArxivId);
@@ -0,0 +1,65 @@
+use crate::errors::Error;
+
+use super::{impl_identifier, strip_prefixes};
+
+/// A validated [Digital Object Identifier](https://www.doi.org/).
+///
+/// DOIs are case-insensitive, so they are normalised to lower case. Resolver prefixes such as
+/// `https://doi.org/` or `doi:` are stripped.
+///
+/// ```
+/// use core_api_client::Doi;
+///
+/// let doi = Doi::parse("https://doi.org/10.1016/0370-2693(96)00910-0").unwrap();
+/// assert_eq!(doi.as_str(), "10.1016/0370-2693(96)00910-0");
+/// assert_eq!(doi.prefix(), "10.1016");
+/// assert!(Doi::parse("not-a-doi").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Doi(String);
+
+impl Doi {
+    /// Parses and normalises a DOI.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let doi = strip_prefixes(value.trim(), &[
+            "https://doi.org/",
+            "http://doi.org/",
+            "https://dx.doi.org/",
+            "http://dx.doi.org/",
+            "doi.org/",
+            "doi:",
+        ]).to_lowercase();
+
+        let (prefix, suffix) = match doi.split_once('/') {
+            Some(parts) => parts,
+            None => return Err(Error::InvalidIdentifier(format!("DOI {}", value))),
+        };
+        let registrant = match prefix.strip_prefix("10.") {
+            Some(r) => r,
+            None => return Err(Error::InvalidIdentifier(format!("DOI {}", value))),
+        };
+        let valid_registrant = !registrant.is_empty() 
+            && registrant.split('.').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()));
+        if !valid_registrant || suffix.is_empty() || suffix.chars().any(char::is_whitespace) {
+            return Err(Error::InvalidIdentifier(format!("DOI {}", value)));
+        }
+        Ok(Doi(doi))
+    }
+
+    /// Returns the DOI prefix (e.g. `10.1016`), identifying the registrant.
+    pub fn prefix(&self) -> &str {
+        self.0.split_once('/').map_or(&self.0, |(prefix, _)| prefix)
+    }
+
+    /// Returns the DOI suffix, the part after the first `/`.
+    pub fn suffix(&self) -> &str {
+        self.0.split_once('/').map_or("", |(_, suffix)| suffix)
+    }
+
+    /// Returns the DOI as a resolvable `https://doi.org/` URL.
+    pub fn url(&self) -> String {
+        format!("https://doi.org/{}", self.0)
+    }
+}
+
+impl_identifier!(Doi);
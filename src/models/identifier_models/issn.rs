@@ -0,0 +1,57 @@
+use crate::errors::Error;
+
+use super::{impl_identifier, strip_prefixes};
+
+/// A validated [International Standard Serial Number](https://www.issn.org/) of a journal.
+///
+/// The ISSN is normalised to the hyphenated `NNNN-NNNC` form and its check digit is verified.
+/// The `issn:` prefix used by the journals endpoint is stripped.
+///
+/// ```
+/// use core_api_client::Issn;
+///
+/// let issn = Issn::parse("issn:11791497").unwrap();
+/// assert_eq!(issn.as_str(), "1179-1497");
+/// assert!(Issn::parse("1179-1498").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Issn(String);
+
+impl Issn {
+    /// Parses, validates and normalises an ISSN.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let stripped = strip_prefixes(value.trim(), &["issn:", "issn "]);
+        let chars: Vec<char> = stripped.chars()
+            .filter(|c| *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        let valid_shape = chars.len() == 8
+            && chars[..7].iter().all(|c| c.is_ascii_digit())
+            && (chars[7].is_ascii_digit() || chars[7] == 'X');
+        if !valid_shape || check_digit(&chars[..7]) != chars[7] {
+            return Err(Error::InvalidIdentifier(format!("ISSN {}", value)));
+        }
+
+        let digits: String = chars.iter().collect();
+        Ok(Issn(format!("{}-{}", &digits[..4], &digits[4..])))
+    }
+
+    /// Returns the identifier the journals endpoint expects (`issn:NNNN-NNNC`).
+    pub fn journal_id(&self) -> String {
+        format!("issn:{}", self.0)
+    }
+}
+
+fn check_digit(digits: &[char]) -> char {
+    let total: u32 = digits.iter()
+        .zip((2..=8).rev())
+        .filter_map(|(c, weight)| c.to_digit(10).map(|d| d * weight))
+        .sum();
+    match (11 - total % 11) % 11 {
+        10 => 'X',
+        d => char::from_digit(d, 10).unwrap_or('0'),
+    }
+}
+
+impl_identifier!(Issn);
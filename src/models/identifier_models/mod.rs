@@ -0,0 +1,86 @@
+pub mod doi;
+pub mod orcid;
+pub mod issn;
+pub mod arxiv;
+pub mod pmid;
+pub mod oai;
+pub mod work_identifier;
+pub mod value;
+
+/// Implements the shared trait boilerplate of identifier newtypes: parsing through `FromStr` and
+/// `TryFrom`, string access, `Display` and (de)serialization as the normalised string.
+macro_rules! impl_identifier {
+    ($name:ident) => {
+        impl $name {
+            /// Returns the normalised identifier.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::errors::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $name::parse(s)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = crate::errors::Error;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                $name::parse(value)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = crate::errors::Error;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                $name::parse(&value)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let raw = crate::helpers::string_number_deserializer::deserialize_as_string(deserializer)?
+                    .unwrap_or_default();
+                $name::parse(&raw).map_err(|e| serde::de::Error::custom(format!("{:?}", e)))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_identifier;
+
+/// Strips the first matching prefix (compared case-insensitively) from the value.
+pub(crate) fn strip_prefixes<'a>(value: &'a str, prefixes: &[&str]) -> &'a str {
+    for prefix in prefixes {
+        if value.len() >= prefix.len() 
+            && value.is_char_boundary(prefix.len()) 
+            && value[..prefix.len()].eq_ignore_ascii_case(prefix) 
+        {
+            return value[prefix.len()..].trim_start();
+        }
+    }
+    value
+}
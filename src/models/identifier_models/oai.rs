@@ -0,0 +1,49 @@
+use crate::errors::Error;
+
+use super::{impl_identifier, strip_prefixes};
+
+/// A validated [OAI](https://www.openarchives.org/OAI/2.0/guidelines-oai-identifier.htm) identifier 
+/// (`oai:<namespace>:<local identifier>`) of a record in a data provider.
+///
+/// The `oai` scheme is normalised to lower case, the rest of the identifier is kept as is since
+/// local identifiers are case sensitive.
+///
+/// ```
+/// use core_api_client::OaiId;
+///
+/// let oai = OaiId::parse("OAI:arXiv.org:hep-th/9901001").unwrap();
+/// assert_eq!(oai.as_str(), "oai:arXiv.org:hep-th/9901001");
+/// assert_eq!(oai.namespace(), "arXiv.org");
+/// assert_eq!(oai.local_identifier(), "hep-th/9901001");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OaiId(String);
+
+impl OaiId {
+    /// Parses and normalises an OAI identifier.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let trimmed = value.trim();
+        let rest = strip_prefixes(trimmed, &["oai:"]);
+        if rest.len() == trimmed.len() || rest.chars().any(char::is_whitespace) {
+            return Err(Error::InvalidIdentifier(format!("OAI identifier {}", value)));
+        }
+        match rest.split_once(':') {
+            Some((namespace, local)) if !namespace.is_empty() && !local.is_empty() => {
+                Ok(OaiId(format!("oai:{}", rest)))
+            },
+            _ => Err(Error::InvalidIdentifier(format!("OAI identifier {}", value))),
+        }
+    }
+
+    /// Returns the namespace (repository identifier) part of the identifier.
+    pub fn namespace(&self) -> &str {
+        self.0[4..].split_once(':').map_or("", |(namespace, _)| namespace)
+    }
+
+    /// Returns the local identifier of the record within the repository.
+    pub fn local_identifier(&self) -> &str {
+        self.0[4..].split_once(':').map_or("", |(_, local)| local)
+    }
+}
+
+impl_identifier!(OaiId);
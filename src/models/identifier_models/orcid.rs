@@ -0,0 +1,64 @@
+use crate::errors::Error;
+
+use super::{impl_identifier, strip_prefixes};
+
+/// A validated [ORCID](https://orcid.org/) identifier of a researcher.
+///
+/// The identifier is normalised to the hyphenated `0000-0002-1825-0097` form and its
+/// ISO 7064 11,2 check digit is verified. `https://orcid.org/` prefixes are stripped.
+///
+/// ```
+/// use core_api_client::Orcid;
+///
+/// let orcid = Orcid::parse("https://orcid.org/0000-0002-1825-0097").unwrap();
+/// assert_eq!(orcid.as_str(), "0000-0002-1825-0097");
+/// assert!(Orcid::parse("0000-0002-1825-0098").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Orcid(String);
+
+impl Orcid {
+    /// Parses, validates and normalises an ORCID.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let stripped = strip_prefixes(value.trim(), &[
+            "https://orcid.org/",
+            "http://orcid.org/",
+            "orcid.org/",
+            "orcid:",
+        ]);
+        let chars: Vec<char> = stripped.chars()
+            .filter(|c| *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        let valid_shape = chars.len() == 16 
+            && chars[..15].iter().all(|c| c.is_ascii_digit())
+            && (chars[15].is_ascii_digit() || chars[15] == 'X');
+        if !valid_shape || check_digit(&chars[..15]) != chars[15] {
+            return Err(Error::InvalidIdentifier(format!("ORCID {}", value)));
+        }
+
+        let normalised = chars.chunks(4)
+            .map(|chunk| chunk.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("-");
+        Ok(Orcid(normalised))
+    }
+
+    /// Returns the ORCID as a resolvable `https://orcid.org/` URL.
+    pub fn url(&self) -> String {
+        format!("https://orcid.org/{}", self.0)
+    }
+}
+
+fn check_digit(digits: &[char]) -> char {
+    let total = digits.iter()
+        .filter_map(|c| c.to_digit(10))
+        .fold(0, |total, d| (total + d) * 2);
+    match (12 - total % 11) % 11 {
+        10 => 'X',
+        d => char::from_digit(d, 10).unwrap_or('0'),
+    }
+}
+
+impl_identifier!(Orcid);
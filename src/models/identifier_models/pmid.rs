@@ -0,0 +1,42 @@
+use crate::errors::Error;
+
+use super::{impl_identifier, strip_prefixes};
+
+/// A validated [PubMed](https://pubmed.ncbi.nlm.nih.gov/) identifier.
+///
+/// ```
+/// use core_api_client::Pmid;
+///
+/// let pmid = Pmid::parse("PMID: 31452104").unwrap();
+/// assert_eq!(pmid.as_str(), "31452104");
+/// assert!(Pmid::parse("PMC6711123").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pmid(String);
+
+impl Pmid {
+    /// Parses and normalises a PubMed identifier, leading zeros are removed.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let stripped = strip_prefixes(value.trim(), &[
+            "https://pubmed.ncbi.nlm.nih.gov/",
+            "pmid:",
+            "pmid",
+        ]).trim_end_matches('/');
+
+        if stripped.is_empty() || stripped.len() > 10 || !stripped.chars().all(|c| c.is_ascii_digit()) {
+            return Err(Error::InvalidIdentifier(format!("PMID {}", value)));
+        }
+        let normalised = stripped.trim_start_matches('0');
+        if normalised.is_empty() {
+            return Err(Error::InvalidIdentifier(format!("PMID {}", value)));
+        }
+        Ok(Pmid(normalised.to_string()))
+    }
+
+    /// Returns the PubMed URL of the identifier.
+    pub fn url(&self) -> String {
+        format!("https://pubmed.ncbi.nlm.nih.gov/{}/", self.0)
+    }
+}
+
+impl_identifier!(Pmid);
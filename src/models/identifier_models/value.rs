@@ -0,0 +1,91 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+use crate::helpers::string_number_deserializer::deserialize_as_string;
+
+/// An identifier field as returned by the API. Values that are not valid identifiers are kept as `Unparsed`
+/// instead of being dropped, so they survive serializing the record again.
+///
+/// ```
+/// use core_api_client::{Doi, IdentifierValue};
+///
+/// let doi = IdentifierValue::<Doi>::parse("https://doi.org/10.1038/NATURE14539");
+/// assert_eq!(doi.valid().map(Doi::prefix), Some("10.1038"));
+/// assert_eq!(doi.as_str(), "10.1038/nature14539");
+///
+/// let broken = IdentifierValue::<Doi>::parse("n/a");
+/// assert!(broken.valid().is_none());
+/// assert_eq!(broken.as_str(), "n/a");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IdentifierValue<T> {
+    /// A valid, normalised identifier.
+    Valid(T),
+    /// A value that could not be parsed, as returned by the API.
+    Unparsed(String),
+}
+
+impl<T> IdentifierValue<T> {
+    /// Returns the identifier if it is valid.
+    pub fn valid(&self) -> Option<&T> {
+        match self {
+            IdentifierValue::Valid(identifier) => Some(identifier),
+            IdentifierValue::Unparsed(_) => None,
+        }
+    }
+
+    /// Converts into the identifier if it is valid.
+    pub fn into_valid(self) -> Option<T> {
+        match self {
+            IdentifierValue::Valid(identifier) => Some(identifier),
+            IdentifierValue::Unparsed(_) => None,
+        }
+    }
+
+    /// Checks whether the value is a valid identifier.
+    pub fn is_valid(&self) -> bool {
+        matches!(self, IdentifierValue::Valid(_))
+    }
+}
+
+impl<T: AsRef<str>> IdentifierValue<T> {
+    /// Returns the normalised identifier, or the raw value if it could not be parsed.
+    pub fn as_str(&self) -> &str {
+        match self {
+            IdentifierValue::Valid(identifier) => identifier.as_ref(),
+            IdentifierValue::Unparsed(raw) => raw,
+        }
+    }
+}
+
+impl<T: FromStr> IdentifierValue<T> {
+    /// Parses a value, keeping it as `Unparsed` if it is not a valid identifier.
+    pub fn parse(value: &str) -> Self {
+        value.parse().map_or_else(|_| IdentifierValue::Unparsed(value.to_string()), IdentifierValue::Valid)
+    }
+}
+
+impl<T> From<T> for IdentifierValue<T> {
+    fn from(identifier: T) -> Self {
+        IdentifierValue::Valid(identifier)
+    }
+}
+
+impl<T: AsRef<str>> fmt::Display for IdentifierValue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<T: AsRef<str>> Serialize for IdentifierValue<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, T: FromStr> Deserialize<'de> for IdentifierValue<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = deserialize_as_string(deserializer)?.unwrap_or_default();
+        Ok(IdentifierValue::parse(&raw))
+    }
+}
@@ -0,0 +1,35 @@
+use crate::models::query_models::search_query::phrase;
+
+use super::{arxiv::ArxivId, doi::Doi, oai::OaiId, pmid::Pmid};
+
+/// Identifiers that can be used to look up a work with `Api::find_work`.
+///
+/// Implementors produce the search expression that matches works carrying the identifier.
+pub trait WorkIdentifier {
+    /// Returns the URL-encoded search expression (e.g. `doi:%2210.1000%2Fxyz%22`) matching works with this identifier.
+    fn search_expression(&self) -> String;
+}
+
+impl WorkIdentifier for Doi {
+    fn search_expression(&self) -> String {
+        format!("doi:{}", phrase(self.as_str()))
+    }
+}
+
+impl WorkIdentifier for ArxivId {
+    fn search_expression(&self) -> String {
+        format!("arxivId:{}", phrase(self.without_version()))
+    }
+}
+
+impl WorkIdentifier for Pmid {
+    fn search_expression(&self) -> String {
+        format!("pubmedId:{}", phrase(self.as_str()))
+    }
+}
+
+impl WorkIdentifier for OaiId {
+    fn search_expression(&self) -> String {
+        format!("oaiIds:{}", phrase(self.as_str()))
+    }
+}
@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+use crate::Issn;
+
 /// Represents a Journal with various associated data.
 /// More information [here](https://api.core.ac.uk/docs/v3#tag/Journals)
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
 pub struct Journal {
     /// The various identifiers of the Journal.
     pub identifiers: Vec<String>,
@@ -23,3 +25,12 @@ pub struct Journal {
     /// The title of the Journal.
     pub title: String,
 }
+
+impl Journal {
+    /// Returns the valid ISSNs among the identifiers of the journal.
+    pub fn issns(&self) -> Vec<Issn> {
+        self.identifiers.iter()
+            .filter_map(|id| Issn::parse(id).ok())
+            .collect()
+    }
+}
@@ -3,4 +3,7 @@ pub mod work_models;
 pub mod data_provider_models;
 pub mod journal_models;
 pub mod query_models;
-pub mod discovery_models;
\ No newline at end of file
+pub mod discovery_models;
+pub mod output_models;
+pub mod date_models;
+pub mod identifier_models;
\ No newline at end of file
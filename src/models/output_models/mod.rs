@@ -0,0 +1 @@
+pub mod output;
\ No newline at end of file
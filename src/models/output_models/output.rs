@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    helpers::{string_number_deserializer::deserialize_as_year, identifier_deserializer::deserialize_identifier},
+    ApiDate, ArxivId, Doi, DocumentType, FieldOfStudy, IdentifierValue, OaiId, Pmid, Work,
+};
+use crate::models::work_models::{
+    author::Author,
+    data_provider::DataProviderRef,
+    identifier::{DoiIdentifier, Identifier, IdentifierKind},
+    journal::JournalRef,
+    language::Language,
+    link::LinkType,
+    reference::Reference,
+};
+
+/// Struct holds the output information. Outputs are a representation of a Work in a data provider.
+/// The data is not enriched and mirrors the content harvested from the data provider.
+/// More info on the output struct [here](https://api.core.ac.uk/docs/v3#tag/Outputs)
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+pub struct Output {
+    /// ID of the output
+    pub id: Option<i32>,
+
+    /// Date the output was accepted
+    #[serde(rename = "acceptedDate")]
+    pub accepted_date: Option<ApiDate>,
+
+    /// ARXIV identifier of the output
+    #[serde(rename = "arxivId", default, deserialize_with = "deserialize_identifier")]
+    pub arxiv_id: Option<IdentifierValue<ArxivId>>,
+
+    /// List of authors of the output
+    pub authors: Option<Vec<Author>>,
+
+    /// Number of citations the output has received
+    #[serde(rename = "citationCount")]
+    pub citation_count: Option<i32>,
+
+    /// List of contributors to the output
+    pub contributors: Option<Vec<String>>,
+
+    /// Date the output was created
+    #[serde(rename = "createdDate")]
+    pub created_date: Option<ApiDate>,
+
+    /// Data provider the output was harvested from
+    #[serde(rename = "dataProvider")]
+    pub data_provider: Option<DataProviderRef>,
+
+    /// Date the output was deposited
+    #[serde(rename = "depositedDate")]
+    pub deposited_date: Option<ApiDate>,
+
+    /// Abstract text of the output
+    #[serde(rename = "abstract", alias = "abstractText")]
+    pub abstract_text: Option<String>,
+
+    /// Type of the document (e.g., article, thesis, etc.)
+    #[serde(rename = "documentType")]
+    pub document_type: Option<DocumentType>,
+
+    /// Digital Object Identifier (DOI) of the output
+    #[serde(default, deserialize_with = "deserialize_identifier")]
+    pub doi: Option<IdentifierValue<Doi>>,
+
+    /// URL for downloading the output
+    #[serde(rename = "downloadUrl")]
+    pub download_url: Option<String>,
+
+    /// Field of study associated with the output
+    #[serde(rename = "fieldOfStudy")]
+    pub field_of_study: Option<FieldOfStudy>,
+
+    /// Full text of the output
+    #[serde(rename = "fullText")]
+    pub full_text: Option<String>,
+
+    /// DOI and OAI identifiers of the output
+    pub identifiers: Option<DoiIdentifier>,
+
+    /// Title of the output
+    pub title: Option<String>,
+
+    /// Language of the output
+    pub language: Option<Language>,
+
+    /// MAG (Microsoft Academic Graph) identifier of the output
+    #[serde(rename = "magId")]
+    pub mag_id: Option<String>,
+
+    /// OAI (Open Archives Initiative) identifier of the output in its data provider
+    #[serde(default, deserialize_with = "deserialize_identifier")]
+    pub oai: Option<IdentifierValue<OaiId>>,
+
+    /// Date the output was published
+    #[serde(rename = "publishedDate")]
+    pub published_date: Option<ApiDate>,
+
+    /// Publisher of the output
+    pub publisher: Option<String>,
+
+    /// PubMed identifier of the output
+    #[serde(rename = "pubmedId", default, deserialize_with = "deserialize_identifier")]
+    pub pubmed_id: Option<IdentifierValue<Pmid>>,
+
+    /// List of references cited by the output
+    pub references: Option<Vec<Reference>>,
+
+    /// List of URLs to the full text of the output from different sources
+    #[serde(rename = "sourceFulltextUrls")]
+    pub source_fulltext_urls: Option<Vec<String>>,
+
+    /// List of journals associated with the output
+    pub journals: Option<Vec<JournalRef>>,
+
+    /// OAI-PMH set specifications the output belongs to in its data provider
+    #[serde(rename = "setSpecs")]
+    pub set_specs: Option<Vec<String>>,
+
+    /// Repository specific tags (e.g. subjects) of the output
+    pub tags: Option<Vec<String>>,
+
+    /// Date the output was last updated
+    #[serde(rename = "updatedDate")]
+    pub updated_date: Option<ApiDate>,
+
+    /// Year the output was published
+    #[serde(rename = "yearPublished", deserialize_with="deserialize_as_year", default)]
+    pub year_published: Option<i32>,
+
+    // List of links associated with the output
+    pub links: Option<Vec<LinkType>>,
+}
+
+impl Output {
+    /// Returns the CORE API URL of the output, as listed in `Work::outputs`.
+    pub fn api_url(&self) -> Option<String> {
+        self.id.map(|id| format!("https://api.core.ac.uk/v3/outputs/{}", id))
+    }
+}
+
+/// Converts a repository specific output into a `Work`.
+///
+/// The output's `dataProvider` becomes the single entry of `data_providers`, its `doi`/`oai`
+/// identifiers are listed in `identifiers` and the output itself is referenced in `outputs`.
+/// The work `id` is left empty, since output and work ids are distinct in CORE.
+impl From<Output> for Work {
+    fn from(output: Output) -> Self {
+        let outputs = output.api_url().map(|url| vec![url]);
+
+        let mut identifiers = vec![];
+        let mut oai_ids = vec![];
+        if let Some(DoiIdentifier { doi, oai }) = output.identifiers {
+            if let Some(doi) = doi {
+                identifiers.push(Identifier { identifier: doi.to_string(), identifier_type: IdentifierKind::Doi });
+            }
+            if let Some(oai) = oai {
+                oai_ids.push(oai);
+            }
+        }
+        if let Some(oai) = output.oai {
+            if !oai_ids.contains(&oai) {
+                oai_ids.push(oai);
+            }
+        }
+        for oai in oai_ids.iter() {
+            identifiers.push(Identifier { identifier: oai.to_string(), identifier_type: IdentifierKind::Oai });
+        }
+
+        Work {
+            accepted_date: output.accepted_date,
+            arxiv_id: output.arxiv_id,
+            authors: output.authors,
+            citation_count: output.citation_count,
+            contributors: output.contributors,
+            outputs,
+            created_date: output.created_date,
+            data_providers: output.data_provider.map(|dp| vec![dp]),
+            deposited_date: output.deposited_date,
+            abstract_text: output.abstract_text,
+            document_type: output.document_type,
+            doi: output.doi,
+            download_url: output.download_url,
+            field_of_study: output.field_of_study,
+            full_text: output.full_text,
+            id: None,
+            identifiers: if identifiers.is_empty() { None } else { Some(identifiers) },
+            title: output.title,
+            language: output.language,
+            mag_id: output.mag_id,
+            oai_ids: if oai_ids.is_empty() { None } else { Some(oai_ids) },
+            published_date: output.published_date,
+            publisher: output.publisher,
+            pubmed_id: output.pubmed_id,
+            references: output.references,
+            source_fulltext_urls: output.source_fulltext_urls,
+            journals: output.journals,
+            updated_date: output.updated_date,
+            year_published: output.year_published,
+            links: output.links,
+        }
+    }
+}
@@ -11,7 +11,45 @@ where
     T2: ToString, 
 {
     pub(crate) logical_operator: LogicalOperator,
-    pub(crate) filter_operator: FilterOperator<T1, T2>,
+    pub(crate) expression: FilterExpression<T1, T2>,
+}
+
+/// The expression of a `Filter`: either a typed `FilterOperator` or an already formatted 
+/// query expression (e.g. `language.code:en`) that is passed through unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd, Hash)]
+pub(crate) enum FilterExpression<T1, T2>
+where
+    T1: ToString,
+    T2: ToString,
+{
+    Operator(FilterOperator<T1, T2>),
+    Raw(String),
+}
+
+impl<T1, T2> FilterExpression<T1, T2>
+where
+    T1: ToString,
+    T2: ToString,
+{
+    pub(crate) fn parse(self) -> String {
+        match self {
+            FilterExpression::Operator(operator) => operator.parse(),
+            FilterExpression::Raw(expression) => expression,
+        }
+    }
+}
+
+impl<T1, T2> fmt::Display for FilterExpression<T1, T2>
+where
+    T1: ToString,
+    T2: ToString,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterExpression::Operator(operator) => write!(f, "{}", operator),
+            FilterExpression::Raw(expression) => write!(f, "Raw({})", expression),
+        }
+    }
 }
 
 impl<T1, T2> Filter<T1, T2>
@@ -23,7 +61,7 @@ where
         format!(
             "{}{}", 
             self.logical_operator.parse(), 
-            self.filter_operator.parse()
+            self.expression.parse()
         )
     }
 }
@@ -36,9 +74,9 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Assuming LogicalOperator and FilterOperator both implement Display or ToString.
-        write!(f, "Filter {{ logical_operator: {}, filter_operator: {} }}", 
+        write!(f, "Filter {{ logical_operator: {}, expression: {} }}", 
             self.logical_operator, 
-            self.filter_operator
+            self.expression
         )
     }
 }
\ No newline at end of file
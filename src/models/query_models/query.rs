@@ -14,6 +14,7 @@ use super::{search_query::SearchQuery, request_type::QueryRequestType};
 /// * `ExpertFinder`: Represents an expert finder request.
 /// * `Journals(T1)`: Represents a request to the journals endpoint with a given identifier.
 /// * `Outputs(T1)`: Represents a request to the outputs endpoint with a given identifier.
+/// * `Works(T1)`: Represents a request to the works endpoint with a given identifier.
 /// * `SearchWorks(SearchQuery<T1, T2>)`: Represents a search request for works.
 /// * `SearchOutputs(SearchQuery<T1, T2>)`: Represents a search request for outputs.
 /// * `SearchDataProviders(SearchQuery<T1, T2>)`: Represents a search request for data providers.
@@ -32,6 +33,7 @@ where
     Discovery(T1),
     Journals(T1),
     Outputs(T1),
+    Works(T1),
     SearchWorks(SearchQuery<T1, T2>),
     SearchOutputs(SearchQuery<T1, T2>),
     SearchDataProviders(SearchQuery<T1, T2>),
@@ -49,6 +51,7 @@ where
             Query::Discovery(doi) => (QueryRequestType::Post, "discover".to_string(), Some(create_discovery_body(doi))),
             Query::Journals(id) => (QueryRequestType::Get, format!("journals/{}", id.to_string()), None),
             Query::Outputs(id) => (QueryRequestType::Get, format!("outputs/{}", id.to_string()), None),
+            Query::Works(id) => (QueryRequestType::Get, format!("works/{}", id.to_string()), None),
             Query::SearchWorks(sq) => (QueryRequestType::Get, format!("search/works/{}", sq.parse()), None),
             Query::SearchOutputs(sq) => (QueryRequestType::Get, format!("search/outputs/{}", sq.parse()), None),
             Query::SearchDataProviders(sq) => (QueryRequestType::Get, format!("search/data-providers/{}", sq.parse()), None),
@@ -72,6 +75,7 @@ where
             Query::Discovery(data) => write!(f, "Discovery({})", data.to_string()),
             Query::Journals(data) => write!(f, "Journals({})", data.to_string()),
             Query::Outputs(data) => write!(f, "Outputs({})", data.to_string()),
+            Query::Works(data) => write!(f, "Works({})", data.to_string()),
             Query::SearchWorks(query) => write!(f, "SearchWorks({})", query), // assuming SearchQuery also implements ToString
             Query::SearchOutputs(query) => write!(f, "SearchOutputs({})", query),
             Query::SearchDataProviders(query) => write!(f, "SearchDataProviders({})", query),
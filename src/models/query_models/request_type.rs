@@ -1,12 +1,22 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, PartialOrd, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Hash)]
 pub(crate) enum QueryRequestType {
     Get,
     Post,
 }
 
+impl QueryRequestType {
+    /// Returns the HTTP method name (`GET` or `POST`).
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            QueryRequestType::Get => "GET",
+            QueryRequestType::Post => "POST",
+        }
+    }
+}
+
 impl fmt::Display for QueryRequestType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -1,9 +1,9 @@
 
 use serde::{Serialize, Deserialize};
 use std::fmt;
-use crate::FilterOperator;
+use crate::{DocumentType, FieldOfStudy, FilterOperator, Language};
 
-use super::{logical_operator::LogicalOperator, filter::Filter};
+use super::{logical_operator::LogicalOperator, filter::{Filter, FilterExpression}};
 
 /// `SearchQuery` is a structure that represents a search query to the API. It allows the user to define the criteria
 /// to filter data from the API.
@@ -38,6 +38,9 @@ use super::{logical_operator::LogicalOperator, filter::Filter};
 /// # Methods
 /// * `and`: Adds a new filter condition with a logical AND operator.
 /// * `or`: Adds a new filter condition with a logical OR operator.
+/// * `language`: Restricts the results to a language.
+/// * `document_type`: Restricts the results to a document type.
+/// * `field_of_study`: Restricts the results to a field of study.
 /// * `parse`: Parses the `SearchQuery` object into a string to be used in the API request.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd, Hash, Default)]
 pub struct SearchQuery<T1 = String, T2 = String>
@@ -49,7 +52,9 @@ where
     limit: Option<i32>,
     offset: Option<i32>,
     scroll: Option<bool>,
-    stats: Option<bool>
+    stats: Option<bool>,
+    sort: Option<String>,
+    scroll_id: Option<String>,
 }
 
 impl<T1, T2> SearchQuery<T1, T2>
@@ -76,7 +81,7 @@ where
     pub fn and(mut self, operator: FilterOperator<T1, T2>) -> Self {
         self.filters.push(Filter { 
             logical_operator: LogicalOperator::And, 
-            filter_operator: operator,
+            expression: FilterExpression::Operator(operator),
         });
         self
     }
@@ -100,7 +105,84 @@ where
     pub fn or(mut self, operator: FilterOperator<T1, T2>) -> Self {
         self.filters.push(Filter { 
             logical_operator: LogicalOperator::Or, 
-            filter_operator: operator, 
+            expression: FilterExpression::Operator(operator), 
+        });
+        self
+    }
+
+    /// Restricts the search to works in the given language, combined with an AND logical operator.
+    /// The language is normalised to its ISO 639-1 code, so `"en"`, `"eng"`, `"en-GB"` and `"English"` 
+    /// all produce the same filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `language: impl Into<Language>` - Language code, name or a `Language` struct.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_api_client::{Api, SearchQuery, FilterOperator};
+    /// 
+    /// let api = Api::from("API_KEY");
+    /// let query = api.paged_search::<_, String>(10, 0)
+    ///     .and(FilterOperator::Exists("doi"))
+    ///     .language("eng");
+    ///
+    /// assert_eq!("?limit=10&offset=0&q=%20AND%20_exists_:doi%20AND%20language.code:en".to_string(), query.parse());
+    /// ```
+    pub fn language<L: Into<Language>>(self, language: L) -> Self {
+        let language = language.into().normalized();
+        let expression = match (language.code, language.name) {
+            (Some(code), _) => format!("language.code:{}", encode(&code)),
+            (None, Some(name)) => format!("language.name:{}", quote(&name)),
+            (None, None) => return self,
+        };
+        self.and_raw(expression)
+    }
+
+    /// Restricts the search to works of the given document type, combined with an AND logical operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_api_client::{Api, SearchQuery, FilterOperator, DocumentType};
+    /// 
+    /// let api = Api::from("API_KEY");
+    /// let query = api.paged_search::<_, String>(10, 0)
+    ///     .and(FilterOperator::Exists("doi"))
+    ///     .document_type(DocumentType::Thesis);
+    ///
+    /// assert_eq!("?limit=10&offset=0&q=%20AND%20_exists_:doi%20AND%20documentType:thesis".to_string(), query.parse());
+    /// ```
+    pub fn document_type(self, document_type: DocumentType) -> Self {
+        let expression = format!("documentType:{}", quote(document_type.as_str()));
+        self.and_raw(expression)
+    }
+
+    /// Restricts the search to works of the given field of study, combined with an AND logical operator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core_api_client::{Api, SearchQuery, FilterOperator, FieldOfStudy};
+    /// 
+    /// let api = Api::from("API_KEY");
+    /// let query = api.paged_search::<_, String>(10, 0)
+    ///     .and(FilterOperator::Exists("doi"))
+    ///     .field_of_study(FieldOfStudy::ComputerScience);
+    ///
+    /// assert_eq!("?limit=10&offset=0&q=%20AND%20_exists_:doi%20AND%20fieldOfStudy:%22computer%20science%22".to_string(), query.parse());
+    /// ```
+    pub fn field_of_study(self, field_of_study: FieldOfStudy) -> Self {
+        let expression = format!("fieldOfStudy:{}", quote(field_of_study.as_str()));
+        self.and_raw(expression)
+    }
+
+    /// Adds an already formatted query expression with an AND logical operator.
+    pub(crate) fn and_raw(mut self, expression: String) -> Self {
+        self.filters.push(Filter { 
+            logical_operator: LogicalOperator::And, 
+            expression: FilterExpression::Raw(expression),
         });
         self
     }
@@ -139,6 +221,12 @@ where
         if let Some(s) = self.stats {
             final_filter = format!("{}&stats={}", final_filter, s);
         }
+        if let Some(s) = self.sort {
+            final_filter = format!("{}&sort={}", final_filter, s);
+        }
+        if let Some(s) = self.scroll_id {
+            final_filter = format!("{}&scrollId={}", final_filter, s);
+        }
 
         if self.filters.is_empty() {
             return final_filter
@@ -166,7 +254,52 @@ where
             offset: Some(offset), 
             scroll: None, 
             stats: None ,
+            sort: None,
+            scroll_id: None,
+        }
+    }
+
+    pub(crate) fn limit(&self) -> Option<i32> {
+        self.limit
+    }
+
+    pub(crate) fn offset(&self) -> Option<i32> {
+        self.offset
+    }
+
+    pub(crate) fn with_page(self, limit: i32, offset: i32) -> Self {
+        Self { limit: Some(limit), offset: Some(offset), ..self }
+    }
+
+    pub(crate) fn without_page(self) -> Self {
+        Self { limit: None, offset: None, ..self }
+    }
+
+    /// Requests a page in scroll mode, continuing the scroll with the given id (a new scroll if `None`).
+    pub(crate) fn with_scroll(self, limit: i32, scroll_id: Option<String>) -> Self {
+        Self { limit: Some(limit), offset: None, scroll: Some(true), scroll_id, ..self }
+    }
+
+    /// Sorts the results, e.g. by `updatedDate:asc`.
+    pub(crate) fn sorted(self, sort: &str) -> Self {
+        Self { sort: Some(sort.to_string()), ..self }
+    }
+
+    /// Wraps the filters in parentheses, so filters added later apply to the whole expression.
+    pub(crate) fn grouped(self) -> Self {
+        if self.filters.is_empty() {
+            return self;
         }
+        let expression = self.filters.into_iter().map(Filter::parse).collect::<String>();
+        let expression = [LogicalOperator::And, LogicalOperator::Or]
+            .into_iter()
+            .find_map(|operator| expression.strip_prefix(&operator.parse()).map(str::to_string))
+            .unwrap_or(expression);
+        let filters = vec![Filter {
+            logical_operator: LogicalOperator::And,
+            expression: FilterExpression::Raw(format!("({})", expression)),
+        }];
+        Self { filters, ..self }
     }
 }
 
@@ -191,3 +324,28 @@ where
                filters, limit, offset, scroll, stats)
     }
 }
+
+/// URL-encodes a filter value, quoting it as a phrase when it contains spaces or other
+/// characters with a meaning in the query syntax.
+pub(crate) fn quote(value: &str) -> String {
+    let plain = value.chars().all(|c| c.is_alphanumeric() || "-_.~".contains(c));
+    if plain {
+        encode(value)
+    } else {
+        phrase(value)
+    }
+}
+
+/// URL-encodes a filter value as a quoted phrase, escaping quotes and backslashes in it.
+pub(crate) fn phrase(value: &str) -> String {
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("%22{}%22", encode(&escaped))
+}
+
+/// Percent-encodes every byte of `value` outside the unreserved set of RFC 3986.
+pub(crate) fn encode(value: &str) -> String {
+    value.bytes().map(|byte| match byte {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+        _ => format!("%{:02X}", byte),
+    }).collect()
+}
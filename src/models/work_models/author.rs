@@ -1,8 +1,38 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{authors::name::PersonName, helpers::identifier_deserializer::deserialize_identifier, IdentifierValue, Orcid};
+
 /// Struct holds the name of the author.
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
 pub struct Author {
     /// Name of the author.
     pub name: String,
-}
\ No newline at end of file
+
+    /// ORCID of the author, when the data provider supplied one.
+    #[serde(default, deserialize_with = "deserialize_identifier", skip_serializing_if = "Option::is_none")]
+    pub orcid: Option<IdentifierValue<Orcid>>,
+}
+
+impl Author {
+    /// Parses the name of the author into given and family names.
+    pub fn parsed_name(&self) -> PersonName {
+        PersonName::parse(&self.name)
+    }
+
+    /// Returns the ORCID of the author, if it is valid.
+    pub fn orcid(&self) -> Option<&Orcid> {
+        self.orcid.as_ref().and_then(IdentifierValue::valid)
+    }
+}
+
+impl From<&str> for Author {
+    fn from(name: &str) -> Self {
+        Author { name: name.to_string(), orcid: None }
+    }
+}
+
+impl From<String> for Author {
+    fn from(name: String) -> Self {
+        Author { name, orcid: None }
+    }
+}
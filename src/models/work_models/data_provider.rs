@@ -1,17 +1,51 @@
 use serde::{Deserialize, Serialize};
 
-/// struct holds the avalible data on the [data providers](https://api.core.ac.uk/docs/v3#tag/Data-Providers)
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
-pub struct DataProvider {
+use crate::{errors::Error, responses::response::ApiResponse, Api, DataProvider};
+
+/// Struct holds the summary of a [data provider](https://api.core.ac.uk/docs/v3#tag/Data-Providers) 
+/// embedded in works and outputs. Use `resolve` to fetch the full `DataProvider`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+pub struct DataProviderRef {
     /// ID of the data provider.
     pub id: i32,
 
     /// Name of the data provider.
+    #[serde(default)]
     pub name: String,
 
     /// URL of the data provider.
+    #[serde(default)]
     pub url: String,
 
     /// Logo of the data provider.
-    pub logo: String,
-}
\ No newline at end of file
+    pub logo: Option<String>,
+}
+
+impl DataProviderRef {
+    /// Fetches the full data provider from CORE.
+    ///
+    /// ```rust
+    /// use core_api_client::Api;
+    ///
+    /// let api = Api::from("API_KEY");
+    /// if let Ok(work) = api.get_work(0) {
+    ///     for provider in work.response.data_providers.iter().flatten() {
+    ///         let full = provider.resolve(&api);
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve(&self, api: &Api) -> Result<ApiResponse<DataProvider>, Error> {
+        api.get_data_provider(self.id)
+    }
+}
+
+impl From<&DataProvider> for DataProviderRef {
+    fn from(provider: &DataProvider) -> Self {
+        DataProviderRef {
+            id: provider.id,
+            name: provider.name.clone().unwrap_or_default(),
+            url: format!("https://api.core.ac.uk/v3/data-providers/{}", provider.id),
+            logo: provider.logo.clone(),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Type of a document as classified by CORE. Types not known to the client are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DocumentType {
+    /// Research paper (article, conference paper, report, ...).
+    Research,
+    /// Thesis or dissertation.
+    Thesis,
+    /// Presentation slides.
+    Slides,
+    /// Presentation.
+    Presentation,
+    /// Any other document type, holding the type as returned by the API.
+    Other(String),
+    /// Document type CORE could not determine.
+    #[default]
+    Unknown,
+}
+
+impl DocumentType {
+    /// Returns the document type as used by the API (e.g. `research`).
+    pub fn as_str(&self) -> &str {
+        match self {
+            DocumentType::Research => "research",
+            DocumentType::Thesis => "thesis",
+            DocumentType::Slides => "slides",
+            DocumentType::Presentation => "presentation",
+            DocumentType::Other(kind) => kind,
+            DocumentType::Unknown => "unknown",
+        }
+    }
+}
+
+impl From<&str> for DocumentType {
+    fn from(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "research" => DocumentType::Research,
+            "thesis" => DocumentType::Thesis,
+            "slides" => DocumentType::Slides,
+            "presentation" => DocumentType::Presentation,
+            "" | "unknown" => DocumentType::Unknown,
+            _ => DocumentType::Other(value.to_string()),
+        }
+    }
+}
+
+impl Serialize for DocumentType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for DocumentType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value: Option<String> = Option::deserialize(deserializer)?;
+        Ok(value.map_or(DocumentType::Unknown, |v| DocumentType::from(v.as_str())))
+    }
+}
+
+impl fmt::Display for DocumentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
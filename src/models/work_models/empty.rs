@@ -1,5 +0,0 @@
-use serde::{Deserialize, Serialize};
-
-
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
-pub struct Empty {}
\ No newline at end of file
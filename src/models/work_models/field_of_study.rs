@@ -0,0 +1,114 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Top level field of study of a work. Fields not known to the client are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FieldOfStudy {
+    /// Art.
+    Art,
+    /// Biology.
+    Biology,
+    /// Business.
+    Business,
+    /// Chemistry.
+    Chemistry,
+    /// Computer science.
+    ComputerScience,
+    /// Economics.
+    Economics,
+    /// Engineering.
+    Engineering,
+    /// Environmental science.
+    EnvironmentalScience,
+    /// Geography.
+    Geography,
+    /// Geology.
+    Geology,
+    /// History.
+    History,
+    /// Materials science.
+    MaterialsScience,
+    /// Mathematics.
+    Mathematics,
+    /// Medicine.
+    Medicine,
+    /// Philosophy.
+    Philosophy,
+    /// Physics.
+    Physics,
+    /// Political science.
+    PoliticalScience,
+    /// Psychology.
+    Psychology,
+    /// Sociology.
+    Sociology,
+    /// Any other field of study, holding the field as returned by the API.
+    Other(String),
+}
+
+const FIELDS: &[(FieldOfStudy, &str)] = &[
+    (FieldOfStudy::Art, "art"),
+    (FieldOfStudy::Biology, "biology"),
+    (FieldOfStudy::Business, "business"),
+    (FieldOfStudy::Chemistry, "chemistry"),
+    (FieldOfStudy::ComputerScience, "computer science"),
+    (FieldOfStudy::Economics, "economics"),
+    (FieldOfStudy::Engineering, "engineering"),
+    (FieldOfStudy::EnvironmentalScience, "environmental science"),
+    (FieldOfStudy::Geography, "geography"),
+    (FieldOfStudy::Geology, "geology"),
+    (FieldOfStudy::History, "history"),
+    (FieldOfStudy::MaterialsScience, "materials science"),
+    (FieldOfStudy::Mathematics, "mathematics"),
+    (FieldOfStudy::Medicine, "medicine"),
+    (FieldOfStudy::Philosophy, "philosophy"),
+    (FieldOfStudy::Physics, "physics"),
+    (FieldOfStudy::PoliticalScience, "political science"),
+    (FieldOfStudy::Psychology, "psychology"),
+    (FieldOfStudy::Sociology, "sociology"),
+];
+
+impl FieldOfStudy {
+    /// Returns the field of study as used by the API (e.g. `computer science`).
+    pub fn as_str(&self) -> &str {
+        match self {
+            FieldOfStudy::Other(field) => field,
+            known => FIELDS.iter()
+                .find(|(field, _)| field == known)
+                .map_or("", |(_, name)| name),
+        }
+    }
+
+    /// Returns all fields of study known to the client.
+    pub fn known() -> impl Iterator<Item = FieldOfStudy> {
+        FIELDS.iter().map(|(field, _)| field.clone())
+    }
+}
+
+impl From<&str> for FieldOfStudy {
+    fn from(value: &str) -> Self {
+        let normalised = value.trim().to_lowercase().replace('_', " ");
+        FIELDS.iter()
+            .find(|(_, name)| *name == normalised)
+            .map_or_else(|| FieldOfStudy::Other(value.to_string()), |(field, _)| field.clone())
+    }
+}
+
+impl Serialize for FieldOfStudy {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldOfStudy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(FieldOfStudy::from(value.as_str()))
+    }
+}
+
+impl fmt::Display for FieldOfStudy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
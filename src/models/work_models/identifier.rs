@@ -1,52 +1,111 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash)]
-#[serde(untagged)]
-pub enum IdentifierEntry {
-    IdentifierWork(Vec<Identifier>),
-    IdentifierOuputs(DoiIdentifier),
-}
-
-impl Default for IdentifierEntry {
-    fn default() -> Self {
-        IdentifierEntry::IdentifierWork(vec![])
-    }
-}
+use crate::{helpers::identifier_deserializer::deserialize_identifier, Doi, IdentifierValue, OaiId};
 
 // Struct holds the work identifiers and their type.
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
 pub struct Identifier {
     /// Identifier value.
     pub identifier: String,
 
     /// Type of the identifier.
     #[serde(rename = "type")]
-    pub identifier_type: String,
+    pub identifier_type: IdentifierKind,
 }
 
-// Struct holds the work identifiers and their type.
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
-pub struct DoiIdentifier {
-    pub doi: Option<String>,
-    pub oai: Option<String>
+/// Kinds of identifiers CORE attaches to works. Kinds not known to the client are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum IdentifierKind {
+    /// Digital Object Identifier.
+    Doi,
+    /// OAI identifier of a record in a data provider.
+    Oai,
+    /// CORE identifier of an output.
+    CoreId,
+    /// arXiv identifier.
+    ArxivId,
+    /// Microsoft Academic Graph identifier.
+    MagId,
+    /// PubMed identifier.
+    PubmedId,
+    /// PubMed Central identifier.
+    PmcId,
+    /// ORCID of a contributor.
+    Orcid,
+    /// Any other identifier type, holding the type as returned by the API.
+    Other(String),
+    /// Identifier without a type.
+    #[default]
+    Unknown,
 }
 
-impl fmt::Display for IdentifierEntry {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl IdentifierKind {
+    /// Returns the identifier type as used by the API (e.g. `DOI`, `CORE_ID`).
+    pub fn as_str(&self) -> &str {
         match self {
-            IdentifierEntry::IdentifierWork(identifiers) => {
-                let ids = identifiers.iter()
-                                     .map(|id| id.to_string()) // Assuming Identifier implements ToString
-                                     .collect::<Vec<_>>()
-                                     .join(", ");
-                write!(f, "IdentifierWork: [{}]", ids)
-            },
-            IdentifierEntry::IdentifierOuputs(doi) => write!(f, "IdentifierOuputs: {}", doi), // Assuming DoiIdentifier implements ToString
+            IdentifierKind::Doi => "DOI",
+            IdentifierKind::Oai => "OAI",
+            IdentifierKind::CoreId => "CORE_ID",
+            IdentifierKind::ArxivId => "ARXIV_ID",
+            IdentifierKind::MagId => "MAG_ID",
+            IdentifierKind::PubmedId => "PUBMED_ID",
+            IdentifierKind::PmcId => "PMC_ID",
+            IdentifierKind::Orcid => "ORCID",
+            IdentifierKind::Other(kind) => kind,
+            IdentifierKind::Unknown => "",
         }
     }
 }
 
+impl From<&str> for IdentifierKind {
+    fn from(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "DOI" => IdentifierKind::Doi,
+            "OAI" | "OAI_ID" => IdentifierKind::Oai,
+            "CORE_ID" => IdentifierKind::CoreId,
+            "ARXIV_ID" | "ARXIV" => IdentifierKind::ArxivId,
+            "MAG_ID" | "MAG" => IdentifierKind::MagId,
+            "PUBMED_ID" | "PUBMED" | "PMID" => IdentifierKind::PubmedId,
+            "PMC_ID" | "PMCID" => IdentifierKind::PmcId,
+            "ORCID" | "ORCID_ID" => IdentifierKind::Orcid,
+            "" => IdentifierKind::Unknown,
+            _ => IdentifierKind::Other(value.to_string()),
+        }
+    }
+}
+
+impl Serialize for IdentifierKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for IdentifierKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value: Option<String> = Option::deserialize(deserializer)?;
+        Ok(value.map_or(IdentifierKind::Unknown, |v| IdentifierKind::from(v.as_str())))
+    }
+}
+
+impl fmt::Display for IdentifierKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// Struct holds the DOI and OAI identifiers of an output.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+pub struct DoiIdentifier {
+    /// Digital Object Identifier (DOI) of the output.
+    #[serde(default, deserialize_with = "deserialize_identifier")]
+    pub doi: Option<IdentifierValue<Doi>>,
+
+    /// OAI identifier of the output in its data provider.
+    #[serde(default, deserialize_with = "deserialize_identifier")]
+    pub oai: Option<IdentifierValue<OaiId>>,
+}
+
 impl fmt::Display for Identifier {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Identifier {{ value: {}, type: {} }}", self.identifier, self.identifier_type)
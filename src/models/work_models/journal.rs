@@ -1,11 +1,60 @@
 use serde::{Deserialize, Serialize};
 
-/// Struct holds data of the [journal](https://api.core.ac.uk/docs/v3#tag/Journals)
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
-pub struct Journal {
+use crate::{errors::Error, responses::response::ApiResponse, Api, Issn, Journal};
+
+/// Struct holds the summary of a [journal](https://api.core.ac.uk/docs/v3#tag/Journals) embedded in works 
+/// and outputs. Use `resolve` to fetch the full `Journal`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+pub struct JournalRef {
     /// Title of the journal.
-    title: Option<String>,
+    pub title: Option<String>,
     
     /// List of identifiers associated with the journal.
-    identifiers: Vec<String>,
-}
\ No newline at end of file
+    #[serde(default)]
+    pub identifiers: Vec<String>,
+}
+
+impl JournalRef {
+    /// Returns the valid ISSNs among the identifiers of the journal.
+    pub fn issns(&self) -> Vec<Issn> {
+        self.identifiers.iter()
+            .filter_map(|id| Issn::parse(id).ok())
+            .collect()
+    }
+
+    /// Fetches the full journal from CORE, using the first valid ISSN of the journal or,
+    /// if it has none, its first identifier.
+    ///
+    /// ```rust
+    /// use core_api_client::Api;
+    ///
+    /// let api = Api::from("API_KEY");
+    /// if let Ok(work) = api.get_work(0) {
+    ///     for journal in work.response.journals.iter().flatten() {
+    ///         let full = journal.resolve(&api);
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve(&self, api: &Api) -> Result<ApiResponse<Journal>, Error> {
+        let id = match self.issns().into_iter().next() {
+            Some(issn) => issn.journal_id(),
+            None => match self.identifiers.first() {
+                Some(id) => id.clone(),
+                None => return Err(Error::InvalidIdentifier(format!(
+                    "journal {} has no identifiers",
+                    self.title.as_deref().unwrap_or_default()
+                ))),
+            },
+        };
+        api.get_journal(id)
+    }
+}
+
+impl From<&Journal> for JournalRef {
+    fn from(journal: &Journal) -> Self {
+        JournalRef {
+            title: Some(journal.title.clone()),
+            identifiers: journal.identifiers.clone(),
+        }
+    }
+}
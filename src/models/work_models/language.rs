@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Struct holds the language of a work or output as reported by CORE.
+///
+/// CORE does not always report the language in the same form, codes can come as ISO 639-1 (`en`),
+/// ISO 639-2 (`eng`) or regional tags (`en-GB`). Use `iso_639_1`/`iso_639_2` to get a normalised code
+/// or `normalized` to get a copy with a canonical code and name.
+///
+/// ```
+/// use core_api_client::Language;
+///
+/// let language = Language::from("ENG");
+/// assert_eq!(language.iso_639_1(), Some("en"));
+/// assert_eq!(language.normalized().name.as_deref(), Some("English"));
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+pub struct Language {
+    /// Language code (e.g. `en`).
+    pub code: Option<String>,
+
+    /// Human readable name of the language (e.g. `English`).
+    pub name: Option<String>,
+}
+
+/// Known languages as (ISO 639-1, ISO 639-2/T, ISO 639-2/B, English name).
+const LANGUAGES: &[(&str, &str, &str, &str)] = &[
+    ("af", "afr", "afr", "Afrikaans"),
+    ("ar", "ara", "ara", "Arabic"),
+    ("be", "bel", "bel", "Belarusian"),
+    ("bg", "bul", "bul", "Bulgarian"),
+    ("bs", "bos", "bos", "Bosnian"),
+    ("ca", "cat", "cat", "Catalan"),
+    ("cs", "ces", "cze", "Czech"),
+    ("cy", "cym", "wel", "Welsh"),
+    ("da", "dan", "dan", "Danish"),
+    ("de", "deu", "ger", "German"),
+    ("el", "ell", "gre", "Greek"),
+    ("en", "eng", "eng", "English"),
+    ("eo", "epo", "epo", "Esperanto"),
+    ("es", "spa", "spa", "Spanish"),
+    ("et", "est", "est", "Estonian"),
+    ("eu", "eus", "baq", "Basque"),
+    ("fa", "fas", "per", "Persian"),
+    ("fi", "fin", "fin", "Finnish"),
+    ("fr", "fra", "fre", "French"),
+    ("ga", "gle", "gle", "Irish"),
+    ("gl", "glg", "glg", "Galician"),
+    ("he", "heb", "heb", "Hebrew"),
+    ("hi", "hin", "hin", "Hindi"),
+    ("hr", "hrv", "hrv", "Croatian"),
+    ("hu", "hun", "hun", "Hungarian"),
+    ("hy", "hye", "arm", "Armenian"),
+    ("id", "ind", "ind", "Indonesian"),
+    ("is", "isl", "ice", "Icelandic"),
+    ("it", "ita", "ita", "Italian"),
+    ("ja", "jpn", "jpn", "Japanese"),
+    ("ka", "kat", "geo", "Georgian"),
+    ("kk", "kaz", "kaz", "Kazakh"),
+    ("ko", "kor", "kor", "Korean"),
+    ("la", "lat", "lat", "Latin"),
+    ("lt", "lit", "lit", "Lithuanian"),
+    ("lv", "lav", "lav", "Latvian"),
+    ("mk", "mkd", "mac", "Macedonian"),
+    ("ms", "msa", "may", "Malay"),
+    ("mt", "mlt", "mlt", "Maltese"),
+    ("nl", "nld", "dut", "Dutch"),
+    ("no", "nor", "nor", "Norwegian"),
+    ("pl", "pol", "pol", "Polish"),
+    ("pt", "por", "por", "Portuguese"),
+    ("ro", "ron", "rum", "Romanian"),
+    ("ru", "rus", "rus", "Russian"),
+    ("sk", "slk", "slo", "Slovak"),
+    ("sl", "slv", "slv", "Slovenian"),
+    ("sq", "sqi", "alb", "Albanian"),
+    ("sr", "srp", "srp", "Serbian"),
+    ("sv", "swe", "swe", "Swedish"),
+    ("sw", "swa", "swa", "Swahili"),
+    ("th", "tha", "tha", "Thai"),
+    ("tr", "tur", "tur", "Turkish"),
+    ("uk", "ukr", "ukr", "Ukrainian"),
+    ("ur", "urd", "urd", "Urdu"),
+    ("vi", "vie", "vie", "Vietnamese"),
+    ("zh", "zho", "chi", "Chinese"),
+];
+
+impl Language {
+    /// Creates a language from a code, the code is normalised to ISO 639-1 when it is known.
+    pub fn from_code<T: ToString>(code: T) -> Self {
+        let code = code.to_string();
+        match lookup(&code) {
+            Some((iso1, _, _, name)) => Language { code: Some(iso1.to_string()), name: Some(name.to_string()) },
+            None => Language { code: Some(code.trim().to_lowercase()), name: None },
+        }
+    }
+
+    /// Returns the ISO 639-1 (two letter) code of the language, if it can be determined
+    /// from either the code or the name.
+    pub fn iso_639_1(&self) -> Option<&'static str> {
+        self.entry().map(|(iso1, _, _, _)| *iso1)
+    }
+
+    /// Returns the ISO 639-2/T (three letter) code of the language, if it can be determined
+    /// from either the code or the name.
+    pub fn iso_639_2(&self) -> Option<&'static str> {
+        self.entry().map(|(_, iso2, _, _)| *iso2)
+    }
+
+    /// Returns a copy of the language with the code normalised to ISO 639-1 and the English name filled in.
+    /// Unknown languages are returned with a lower-cased code and the name left untouched.
+    pub fn normalized(&self) -> Self {
+        match self.entry() {
+            Some((iso1, _, _, name)) => Language { code: Some(iso1.to_string()), name: Some(name.to_string()) },
+            None => Language {
+                code: self.code.as_ref().map(|c| c.trim().to_lowercase()),
+                name: self.name.clone(),
+            },
+        }
+    }
+
+    /// Checks whether two languages denote the same language, regardless of the code form used.
+    pub fn same_as(&self, other: &Language) -> bool {
+        match (self.iso_639_1(), other.iso_639_1()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.normalized() == other.normalized(),
+        }
+    }
+
+    fn entry(&self) -> Option<&'static (&'static str, &'static str, &'static str, &'static str)> {
+        self.code.as_deref().and_then(lookup)
+            .or_else(|| self.name.as_deref().and_then(lookup))
+    }
+}
+
+/// Looks up a language by ISO 639-1/639-2 code, regional tag (`en-GB`, `pt_BR`) or English name.
+fn lookup(value: &str) -> Option<&'static (&'static str, &'static str, &'static str, &'static str)> {
+    let value = value.trim().to_lowercase();
+    let primary = value.split(['-', '_']).next().unwrap_or_default();
+    LANGUAGES.iter().find(|(iso1, iso2t, iso2b, name)| {
+        primary == *iso1 || primary == *iso2t || primary == *iso2b || value == name.to_lowercase()
+    })
+}
+
+impl From<&str> for Language {
+    fn from(value: &str) -> Self {
+        let trimmed = value.trim();
+        let looks_like_code = (2..=3).contains(&trimmed.len()) && trimmed.chars().all(|c| c.is_ascii_alphabetic());
+        match (lookup(trimmed), looks_like_code) {
+            (Some(_), _) | (None, true) => Language::from_code(trimmed),
+            (None, false) => Language { code: None, name: Some(trimmed.to_string()) },
+        }
+    }
+}
+
+impl From<String> for Language {
+    fn from(value: String) -> Self {
+        Language::from(value.as_str())
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = self.code.as_ref().map_or(String::from("None"), |c| c.to_string());
+        let name = self.name.as_ref().map_or(String::from("None"), |n| n.to_string());
+        write!(f, "Language {{ code: {}, name: {} }}", code, name)
+    }
+}
@@ -1,9 +1,10 @@
-use serde::{Deserialize as Des, Serialize};
+use serde::{Deserialize as Des, Serialize, Serializer};
 use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
 use std::fmt;
 
 /// Links are wrapped in an enum since they can either be simple string links or a structured piece of data with a link type.
-#[derive(Debug, Serialize, PartialEq, PartialOrd, Hash)]
+#[derive(Debug, Clone, Serialize, PartialEq, PartialOrd, Hash)]
+#[serde(untagged)]
 pub enum LinkType {
     /// Represents a raw string link.
     Raw(String),
@@ -13,14 +14,103 @@ pub enum LinkType {
 }
 
 /// Struct representing a structured link.
-#[derive(Debug, Des, Serialize, PartialEq, PartialOrd, Hash, Default)]
+#[derive(Debug, Clone, Des, Serialize, PartialEq, PartialOrd, Hash, Default)]
 pub struct Link {
     /// Type of the link.
     #[serde(rename = "type")]
-    link_type: String,
+    pub link_type: LinkKind,
 
     /// URL of the link.
-    url: String,
+    pub url: String,
+}
+
+/// Kinds of structured links CORE attaches to works. Kinds not known to the client are kept in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum LinkKind {
+    /// Link to download the full text.
+    Download,
+    /// Link to the CORE reader of the full text.
+    Reader,
+    /// Small thumbnail of the first page.
+    ThumbnailSmall,
+    /// Medium thumbnail of the first page.
+    ThumbnailMedium,
+    /// Large thumbnail of the first page.
+    ThumbnailLarge,
+    /// Link to the CORE display page.
+    Display,
+    /// Any other link type, holding the type as returned by the API.
+    Other(String),
+    /// Link without a type.
+    #[default]
+    Unknown,
+}
+
+impl LinkKind {
+    /// Returns the link type as used by the API (e.g. `download`, `thumbnail_m`).
+    pub fn as_str(&self) -> &str {
+        match self {
+            LinkKind::Download => "download",
+            LinkKind::Reader => "reader",
+            LinkKind::ThumbnailSmall => "thumbnail_s",
+            LinkKind::ThumbnailMedium => "thumbnail_m",
+            LinkKind::ThumbnailLarge => "thumbnail_l",
+            LinkKind::Display => "display",
+            LinkKind::Other(kind) => kind,
+            LinkKind::Unknown => "",
+        }
+    }
+
+    /// Checks whether the link is a thumbnail of any size.
+    pub fn is_thumbnail(&self) -> bool {
+        matches!(self, LinkKind::ThumbnailSmall | LinkKind::ThumbnailMedium | LinkKind::ThumbnailLarge)
+    }
+}
+
+impl From<&str> for LinkKind {
+    fn from(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "download" => LinkKind::Download,
+            "reader" => LinkKind::Reader,
+            "thumbnail_s" => LinkKind::ThumbnailSmall,
+            "thumbnail_m" => LinkKind::ThumbnailMedium,
+            "thumbnail_l" => LinkKind::ThumbnailLarge,
+            "display" => LinkKind::Display,
+            "" => LinkKind::Unknown,
+            _ => LinkKind::Other(value.to_string()),
+        }
+    }
+}
+
+impl Serialize for LinkKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LinkKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value: Option<String> = Option::deserialize(deserializer)?;
+        Ok(value.map_or(LinkKind::Unknown, |v| LinkKind::from(v.as_str())))
+    }
+}
+
+impl LinkType {
+    /// Returns the URL of the link.
+    pub fn url(&self) -> &str {
+        match self {
+            LinkType::Raw(url) => url,
+            LinkType::Structured(link) => &link.url,
+        }
+    }
+
+    /// Returns the kind of the link, raw links have no kind.
+    pub fn kind(&self) -> Option<&LinkKind> {
+        match self {
+            LinkType::Raw(_) => None,
+            LinkType::Structured(link) => Some(&link.link_type),
+        }
+    }
 }
 
 impl Default for LinkType {
@@ -77,4 +167,10 @@ impl fmt::Display for Link {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Link {{ type: {}, url: {} }}", self.link_type, self.url)
     }
-}
\ No newline at end of file
+}
+
+impl fmt::Display for LinkKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -5,4 +5,6 @@ pub mod journal;
 pub mod author;
 pub mod data_provider;
 pub mod link;
-pub mod empty;
\ No newline at end of file
+pub mod language;
+pub mod document_type;
+pub mod field_of_study;
\ No newline at end of file
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{helpers::identifier_deserializer::deserialize_identifier, ApiDate, Doi, IdentifierValue};
+
 /// Struct representing a reference.
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
 pub struct Reference {
     /// ID of the reference.
     pub id: Option<i32>,
@@ -12,10 +14,11 @@ pub struct Reference {
     pub cites: Option<String>,
 
     /// Date associated with the reference.
-    pub date: Option<String>,
+    pub date: Option<ApiDate>,
 
     /// Digital Object Identifier (DOI) of the reference.
-    pub doi: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_identifier")]
+    pub doi: Option<IdentifierValue<Doi>>,
 
     /// Raw content of the reference.
     pub raw: Option<String>,
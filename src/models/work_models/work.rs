@@ -1,20 +1,23 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{helpers::string_number_deserializer::deserialize_as_string};
+use crate::{
+    helpers::{string_number_deserializer::deserialize_as_year, identifier_deserializer::{deserialize_identifier, deserialize_identifiers}},
+    ApiDate, ArxivId, Doi, DocumentType, FieldOfStudy, IdentifierValue, OaiId, Orcid, Pmid,
+};
 
-use super::{reference::Reference, journal::Journal, author::Author, data_provider::DataProvider, link::LinkType, identifier::IdentifierEntry, empty::Empty};
+use super::{reference::Reference, journal::JournalRef, author::Author, data_provider::DataProviderRef, link::{LinkType, LinkKind}, identifier::{Identifier, IdentifierKind}, language::Language};
 
 
 /// Struct holds the work information. More info on the work struct [here](https://api.core.ac.uk/docs/v3#tag/Works) 
-#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Hash, Default)]
 pub struct Work {
     /// Date the work was accepted
     #[serde(rename = "acceptedDate")]
-    pub accepted_date: Option<String>,
+    pub accepted_date: Option<ApiDate>,
     
     /// ARXIV identifier of the work
-    #[serde(rename = "arxivId")]
-    pub arxiv_id: Option<String>,
+    #[serde(rename = "arxivId", default, deserialize_with = "deserialize_identifier")]
+    pub arxiv_id: Option<IdentifierValue<ArxivId>>,
 
     /// List of authors of the work
     pub authors: Option<Vec<Author>>,
@@ -31,15 +34,15 @@ pub struct Work {
 
     /// Date the work was created
     #[serde(rename = "createdDate")]
-    pub created_date: Option<String>,
+    pub created_date: Option<ApiDate>,
 
     /// List of data providers associated with the work
     #[serde(rename = "dataProviders")]
-    pub data_providers: Option<Vec<DataProvider>>,
+    pub data_providers: Option<Vec<DataProviderRef>>,
 
     /// Date the work was deposited
     #[serde(rename = "depositedDate")]
-    pub deposited_date: Option<String>,
+    pub deposited_date: Option<ApiDate>,
 
     /// Abstract text of the work
     #[serde(rename = "abstractText")]
@@ -47,10 +50,11 @@ pub struct Work {
 
     /// Type of the document (e.g., article, thesis, etc.)
     #[serde(rename = "documentType")]
-    pub document_type: Option<String>,
+    pub document_type: Option<DocumentType>,
 
     /// Digital Object Identifier (DOI) of the work
-    pub doi: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_identifier")]
+    pub doi: Option<IdentifierValue<Doi>>,
 
     /// URL for downloading the work
     #[serde(rename = "downloadUrl")]
@@ -58,7 +62,7 @@ pub struct Work {
 
     /// Field of study associated with the work
     #[serde(rename = "fieldOfStudy")]
-    pub field_of_study: Option<String>,
+    pub field_of_study: Option<FieldOfStudy>,
 
     /// Full text of the work
     #[serde(rename = "fullText")]
@@ -68,32 +72,32 @@ pub struct Work {
     pub id: Option<i32>,
 
     /// List of identifiers associated with the work
-    pub identifiers: Option<IdentifierEntry>,
+    pub identifiers: Option<Vec<Identifier>>,
 
     /// Title of the work
     pub title: Option<String>,
 
     /// Language of the work
-    pub language: Option<Empty>,
+    pub language: Option<Language>,
 
     /// MAG (Microsoft Academic Graph) identifier of the work
     #[serde(rename = "magId")]
     pub mag_id: Option<String>,
 
     /// List of OAI (Open Archives Initiative) identifiers of the work
-    #[serde(rename = "oaiIds")]
-    pub oai_ids: Option<Vec<String>>,
+    #[serde(rename = "oaiIds", default, deserialize_with = "deserialize_identifiers")]
+    pub oai_ids: Option<Vec<IdentifierValue<OaiId>>>,
 
     /// Date the work was published
     #[serde(rename = "publishedDate")]
-    pub published_date: Option<String>,
+    pub published_date: Option<ApiDate>,
 
     /// Publisher of the work
     pub publisher: Option<String>,
 
     /// PubMed identifier of the work
-    #[serde(rename = "pubmedId")]
-    pub pubmed_id: Option<String>,
+    #[serde(rename = "pubmedId", default, deserialize_with = "deserialize_identifier")]
+    pub pubmed_id: Option<IdentifierValue<Pmid>>,
 
     /// List of references cited by the work
     pub references: Option<Vec<Reference>>,
@@ -103,16 +107,101 @@ pub struct Work {
     pub source_fulltext_urls: Option<Vec<String>>,
 
     /// List of journals associated with the work
-    pub journals: Option<Vec<Journal>>,
+    pub journals: Option<Vec<JournalRef>>,
 
     /// Date the work was last updated
     #[serde(rename = "updatedDate")]
-    pub updated_date: Option<String>,
+    pub updated_date: Option<ApiDate>,
 
     /// Year the work was published
-    #[serde(rename = "yearPublished", deserialize_with="deserialize_as_string", default)]
-    pub year_published: Option<String>,
+    #[serde(rename = "yearPublished", deserialize_with="deserialize_as_year", default)]
+    pub year_published: Option<i32>,
         
     // List of links associated with the work
     pub links: Option<Vec<LinkType>>,
-}
\ No newline at end of file
+}
+
+impl Work {
+    /// Returns the DOI of the work, if it is valid.
+    pub fn doi(&self) -> Option<&Doi> {
+        self.doi.as_ref().and_then(IdentifierValue::valid)
+    }
+
+    /// Returns the arXiv identifier of the work, if it is valid.
+    pub fn arxiv_id(&self) -> Option<&ArxivId> {
+        self.arxiv_id.as_ref().and_then(IdentifierValue::valid)
+    }
+
+    /// Returns the PubMed identifier of the work, if it is valid.
+    pub fn pubmed_id(&self) -> Option<&Pmid> {
+        self.pubmed_id.as_ref().and_then(IdentifierValue::valid)
+    }
+
+    /// Returns the valid OAI identifiers of the work.
+    pub fn oai_ids(&self) -> Vec<&OaiId> {
+        self.oai_ids.iter().flatten().filter_map(IdentifierValue::valid).collect()
+    }
+
+    /// Returns the URL of the first link of the given kind.
+    pub fn link(&self, kind: &LinkKind) -> Option<&str> {
+        self.links.as_ref()?
+            .iter()
+            .find(|link| link.kind() == Some(kind))
+            .map(|link| link.url())
+    }
+
+    /// Returns the URL of the largest thumbnail of the work's first page.
+    pub fn thumbnail_url(&self) -> Option<&str> {
+        self.link(&LinkKind::ThumbnailLarge)
+            .or_else(|| self.link(&LinkKind::ThumbnailMedium))
+            .or_else(|| self.link(&LinkKind::ThumbnailSmall))
+    }
+
+    /// Returns the URL of the CORE reader for the work's full text.
+    pub fn reader_url(&self) -> Option<&str> {
+        self.link(&LinkKind::Reader)
+    }
+
+    /// Returns the URL of the CORE display page of the work.
+    pub fn display_url(&self) -> Option<&str> {
+        self.link(&LinkKind::Display)
+    }
+
+    /// Returns the download URL of the full text, from `download_url` or the download link.
+    pub fn download_link(&self) -> Option<&str> {
+        self.download_url.as_deref()
+            .or_else(|| self.link(&LinkKind::Download))
+    }
+
+    /// Returns the valid ORCIDs among the identifiers of the work and its authors.
+    pub fn orcids(&self) -> Vec<Orcid> {
+        let mut orcids: Vec<Orcid> = self.authors.iter()
+            .flatten()
+            .filter_map(|author| author.orcid.clone().and_then(IdentifierValue::into_valid))
+            .collect();
+        for id in self.identifiers_of(&IdentifierKind::Orcid) {
+            if let Ok(orcid) = Orcid::parse(id) {
+                if !orcids.contains(&orcid) {
+                    orcids.push(orcid);
+                }
+            }
+        }
+        orcids
+    }
+
+    /// Returns the values of all identifiers of the given kind.
+    pub fn identifiers_of(&self, kind: &IdentifierKind) -> Vec<&str> {
+        self.identifiers.iter()
+            .flatten()
+            .filter(|id| &id.identifier_type == kind)
+            .map(|id| id.identifier.as_str())
+            .collect()
+    }
+
+    /// Returns the year the work was published, taken from `year_published` or, if missing,
+    /// from `published_date`.
+    pub fn publication_year(&self) -> Option<i32> {
+        self.year_published
+            .or_else(|| self.published_date.as_ref().and_then(|d| d.year()))
+    }
+}
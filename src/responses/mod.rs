@@ -1,3 +1,4 @@
 pub mod search;
+pub mod search_iter;
 pub mod response;
 pub mod response_types;
\ No newline at end of file
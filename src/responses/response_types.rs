@@ -1,22 +1,24 @@
 use serde::{Serialize, Deserialize};
 
 use crate::{
-    Work, 
-    DataProvider, 
-    Journal, 
+    Work,
+    Output,
+    DataProvider,
+    Journal,
     Discovery
 };
 
 use super::search::SearchResponse;
 
 #[derive(Debug, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
 pub enum ApiResponseType {
     DataProviders(DataProvider),
     Discovery(Discovery),
     Journals(Journal),
-    Outputs(Work),
+    Outputs(Output),
     SearchWorks(SearchResponse<Work>),
-    SearchOutputs(SearchResponse<Work>),
+    SearchOutputs(SearchResponse<Output>),
     SearchDataProviders(SearchResponse<DataProvider>),
     SearchJournals(SearchResponse<Journal>),
 }
\ No newline at end of file
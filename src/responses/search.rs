@@ -1,27 +1,30 @@
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::collections::BTreeMap;
+
+use crate::{helpers::string_number_deserializer::deserialize_as_string, DocumentType, FieldOfStudy, Work};
 
 use super::response::ApiResponseTrait;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SearchResponse<T> {
-    
+
     #[serde(rename = "totalHits")]
     #[serde(deserialize_with = "deserialize_limit")]
     pub total_hits: Option<i32>,
 
 	#[serde(deserialize_with = "deserialize_limit")]
     pub limit: Option<i32>,
-	
+
     #[serde(deserialize_with = "deserialize_limit")]
     pub offset: Option<i32>,
-	
-    #[serde(rename = "scrollId")]
-    pub scroll_id: Option<i32>,
+
+    #[serde(rename = "scrollId", default, deserialize_with = "deserialize_as_string")]
+    pub scroll_id: Option<String>,
 
 	pub results: Option<Vec<T>>,
-    
+
     pub tooks: Option<String>,
-	
+
     #[serde(rename = "esTook")]
     pub es_took: Option<String>,
 }
@@ -30,6 +33,29 @@ pub struct SearchResponse<T> {
 
 impl<T: DeserializeOwned> ApiResponseTrait for SearchResponse<T> {}
 
+impl SearchResponse<Work> {
+    /// Counts the works on this page by document type. Works without a document type are counted as `Unknown`.
+    /// Only the returned results are counted, not all `total_hits` of the search.
+    pub fn page_document_type_counts(&self) -> BTreeMap<DocumentType, usize> {
+        let mut counts = BTreeMap::new();
+        for work in self.results.iter().flatten() {
+            let document_type = work.document_type.clone().unwrap_or_default();
+            *counts.entry(document_type).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Counts the works on this page by field of study. Works without a field of study are not counted.
+    /// Only the returned results are counted, not all `total_hits` of the search.
+    pub fn page_field_of_study_counts(&self) -> BTreeMap<FieldOfStudy, usize> {
+        let mut counts = BTreeMap::new();
+        for field in self.results.iter().flatten().filter_map(|work| work.field_of_study.clone()) {
+            *counts.entry(field).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
 fn deserialize_limit<'de, D>(deserializer: D) -> Result<Option<i32>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -0,0 +1,181 @@
+use std::collections::VecDeque;
+
+use crate::{errors::Error, Api, SearchQuery};
+
+use super::{response::ApiResponse, search::SearchResponse};
+
+type SearchFn<T, T1, T2> = fn(&Api, SearchQuery<T1, T2>) -> Result<ApiResponse<SearchResponse<T>>, Error>;
+
+/// Highest offset the API pages to, further results are only reachable by scrolling.
+const MAX_OFFSET: i32 = 10_000;
+
+/// How the next page is requested.
+#[derive(Debug, Clone, PartialEq)]
+enum Paging {
+    Offset,
+    /// Scrolling from the first result.
+    Scroll { scroll_id: Option<String> },
+}
+
+/// Iterator over all results of a search, fetching one page at a time as it is consumed.
+///
+/// Pages have the limit of the query (100 if it has none) and start at its offset. The API only pages to
+/// 10,000 results: when the first page shows that a search from the first result goes further (within
+/// `max_results`), the iterator scrolls from the start instead, requesting the first page a second time.
+/// A search starting at a later offset can't be scrolled without fetching the skipped results again, so
+/// it ends with `Error::OffsetLimit` when it reaches the limit. Iteration stops after the last page, after 
+/// `max_results` results or after the first error, which is returned as the last item.
+/// Created with `Api::iter_works`, `Api::iter_outputs`, `Api::iter_data_providers` or `Api::iter_journals`.
+///
+/// ```no_run
+/// use core_api_client::{Api, FilterOperator};
+///
+/// let api = Api::from("API_KEY");
+/// let query = api.paged_search(100, 0).and(FilterOperator::Eq("publisher", "OJS"));
+///
+/// for work in api.iter_works(query).max_results(1000) {
+///     println!("{:?}", work.unwrap().title);
+/// }
+/// ```
+pub struct SearchIter<'a, T, T1 = String, T2 = String>
+where
+    T1: ToString,
+    T2: ToString,
+{
+    api: &'a Api,
+    query: SearchQuery<T1, T2>,
+    search: SearchFn<T, T1, T2>,
+    buffer: VecDeque<T>,
+    page_size: i32,
+    offset: i32,
+    start: i32,
+    paging: Paging,
+    max_results: Option<usize>,
+    returned: usize,
+    total_hits: Option<i32>,
+    ratelimit_remaining: Option<i32>,
+    done: bool,
+}
+
+impl<'a, T, T1, T2> SearchIter<'a, T, T1, T2>
+where
+    T1: ToString + Clone,
+    T2: ToString + Clone,
+{
+    pub(crate) fn new(api: &'a Api, query: SearchQuery<T1, T2>, search: SearchFn<T, T1, T2>) -> Self {
+        let page_size = query.limit().filter(|limit| *limit > 0).unwrap_or(100);
+        let offset = query.offset().unwrap_or(0).max(0);
+        SearchIter {
+            api,
+            query,
+            search,
+            buffer: VecDeque::new(),
+            page_size,
+            offset,
+            start: offset,
+            paging: Paging::Offset,
+            max_results: None,
+            returned: 0,
+            total_hits: None,
+            ratelimit_remaining: None,
+            done: false,
+        }
+    }
+
+    /// Stops the iteration after the given number of results.
+    pub fn max_results(self, max_results: usize) -> Self {
+        Self { max_results: Some(max_results), ..self }
+    }
+
+    /// Total number of results of the search, known after the first page was fetched.
+    pub fn total_hits(&self) -> Option<i32> {
+        self.total_hits
+    }
+
+    /// Remaining rate limit after the last fetched page.
+    pub fn ratelimit_remaining(&self) -> Option<i32> {
+        self.ratelimit_remaining
+    }
+
+    /// Number of results the iteration will return at most, known after the first page was fetched.
+    fn expected(&self) -> Option<usize> {
+        let available = self.total_hits.map(|total| (total - self.start).max(0) as usize);
+        match (available, self.max_results) {
+            (Some(available), Some(max)) => Some(available.min(max)),
+            (available, max) => available.or(max),
+        }
+    }
+
+    fn fetch_page(&mut self) -> Result<(), Error> {
+        let (query, limit) = match &self.paging {
+            Paging::Offset => {
+                // the last page before the limit is shortened, so it does not pass it
+                let limit = self.page_size.min(MAX_OFFSET - self.offset);
+                if limit <= 0 {
+                    self.done = true;
+                    return Err(Error::OffsetLimit(self.offset));
+                }
+                (self.query.clone().with_page(limit, self.offset), limit)
+            }
+            Paging::Scroll { scroll_id } => (self.query.clone().with_scroll(self.page_size, scroll_id.clone()), self.page_size),
+        };
+        let response = (self.search)(self.api, query)?;
+        self.ratelimit_remaining = response.ratelimit_remaining;
+        self.total_hits = response.response.total_hits.or(self.total_hits);
+
+        let results = response.response.results.unwrap_or_default();
+        if results.len() < limit as usize {
+            self.done = true;
+        }
+        if let Paging::Scroll { scroll_id } = &mut self.paging {
+            *scroll_id = response.response.scroll_id;
+            if scroll_id.is_none() && !self.done {
+                self.done = true;
+                return Err(Error::Parsing("scroll response without a scrollId".to_string()));
+            }
+        }
+        let first_page = self.paging == Paging::Offset && self.offset == 0;
+        self.offset += results.len() as i32;
+        if self.total_hits.is_some_and(|total| self.offset >= total) {
+            self.done = true;
+        }
+        let past_limit = self.expected().is_some_and(|expected| expected > MAX_OFFSET as usize);
+        if first_page && !self.done && past_limit {
+            // drop the page and scroll from the start, the first scroll page returns the same results
+            self.paging = Paging::Scroll { scroll_id: None };
+            self.offset = 0;
+            return Ok(());
+        }
+        self.buffer.extend(results);
+        Ok(())
+    }
+}
+
+impl<T, T1, T2> Iterator for SearchIter<'_, T, T1, T2>
+where
+    T1: ToString + Clone,
+    T2: ToString + Clone,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.max_results.is_some_and(|max| self.returned >= max) {
+            return None;
+        }
+        // the first page is dropped when switching to scroll mode
+        while self.buffer.is_empty() && !self.done {
+            if let Err(e) = self.fetch_page() {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+        let item = self.buffer.pop_front()?;
+        self.returned += 1;
+        Some(Ok(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = self.expected().map(|expected| expected.saturating_sub(self.returned));
+        (self.buffer.len().min(upper.unwrap_or(usize::MAX)), upper)
+    }
+}
@@ -0,0 +1,51 @@
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::{errors::Error, DataProvider};
+
+use super::{query_records, work::like_pattern, Store, StoreRecord};
+
+impl StoreRecord for DataProvider {
+    fn upsert_into(&self, conn: &Connection) -> Result<(), Error> {
+        conn.execute(
+            "INSERT INTO data_providers (id, name, institution_name, ror_id, homepage_url, oai_pmh_url, data)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT (id) DO UPDATE SET
+                name = excluded.name, institution_name = excluded.institution_name, ror_id = excluded.ror_id,
+                homepage_url = excluded.homepage_url, oai_pmh_url = excluded.oai_pmh_url, data = excluded.data",
+            params![
+                self.id,
+                self.name.as_deref().unwrap_or_default(),
+                self.institution_name,
+                self.ror_id,
+                self.homepage_url,
+                self.oai_pmh_url,
+                serde_json::to_string(self)?,
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+impl Store {
+    /// Returns the data provider with a CORE id.
+    pub fn data_provider(&self, id: i32) -> Result<Option<DataProvider>, Error> {
+        let data: Option<String> = self.conn
+            .query_row("SELECT data FROM data_providers WHERE id = ?1", [id], |row| row.get(0))
+            .optional()?;
+        Ok(data.map(|data| serde_json::from_str(&data)).transpose()?)
+    }
+
+    /// Returns all stored data providers, ordered by id.
+    pub fn data_providers(&self) -> Result<Vec<DataProvider>, Error> {
+        query_records(&self.conn, "SELECT data FROM data_providers ORDER BY id", &[])
+    }
+
+    /// Returns the data providers whose name or institution name contains `name`.
+    pub fn data_providers_by_name(&self, name: &str) -> Result<Vec<DataProvider>, Error> {
+        query_records(
+            &self.conn,
+            "SELECT data FROM data_providers WHERE name LIKE ?1 ESCAPE '\\' OR institution_name LIKE ?1 ESCAPE '\\' ORDER BY id",
+            &[&like_pattern(name)],
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use rusqlite::{params, Connection};
+
+use crate::{errors::Error, Journal};
+
+use super::{query_records, Store, StoreRecord};
+
+impl StoreRecord for Journal {
+    /// Journals have no CORE id, they are keyed by their first identifier (e.g. `issn:0028-0836`).
+    fn upsert_into(&self, conn: &Connection) -> Result<(), Error> {
+        let key = self.identifiers
+            .first()
+            .ok_or_else(|| Error::InvalidIdentifier(format!("journal without identifiers: {}", self.title)))?;
+        conn.execute(
+            "INSERT INTO journals (identifier, title, publisher, language, data) VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT (identifier) DO UPDATE SET
+                title = excluded.title, publisher = excluded.publisher, language = excluded.language, data = excluded.data",
+            params![key, self.title, self.publisher, self.language, serde_json::to_string(self)?],
+        )?;
+
+        conn.execute("DELETE FROM journal_identifiers WHERE journal = ?1", [key])?;
+        let mut identifiers = conn.prepare_cached("INSERT OR IGNORE INTO journal_identifiers (journal, identifier) VALUES (?1, ?2)")?;
+        for identifier in self.identifiers.iter() {
+            identifiers.execute(params![key, identifier])?;
+        }
+        Ok(())
+    }
+}
+
+impl Store {
+    /// Returns the journal with an identifier, e.g. `issn:0028-0836`.
+    pub fn journal(&self, identifier: &str) -> Result<Option<Journal>, Error> {
+        let journals = query_records(
+            &self.conn,
+            "SELECT data FROM journals WHERE identifier IN (SELECT journal FROM journal_identifiers WHERE identifier = ?1)",
+            &[&identifier],
+        )?;
+        Ok(journals.into_iter().next())
+    }
+
+    /// Returns all stored journals, ordered by title.
+    pub fn journals(&self) -> Result<Vec<Journal>, Error> {
+        query_records(&self.conn, "SELECT data FROM journals ORDER BY title, identifier", &[])
+    }
+}
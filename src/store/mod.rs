@@ -0,0 +1,143 @@
+pub mod work;
+pub mod data_provider;
+pub mod journal;
+mod schema;
+
+use std::path::Path;
+
+use rusqlite::{params_from_iter, Connection, ToSql};
+use serde::de::DeserializeOwned;
+
+use crate::{errors::Error, models::date_models::date::ApiDate};
+
+/// Number of records written per transaction by `Store::import`.
+const IMPORT_BATCH: usize = 500;
+
+/// Local SQLite copy of CORE records, available with the `sqlite` feature.
+///
+/// Works, data providers and journals are upserted by their CORE id (journals by their first identifier)
+/// and keep their full JSON, so they are read back unchanged. Authors, identifiers, references, links and
+/// data providers of works are also written to their own tables (`work_authors`, `work_identifiers`,
+/// `work_references`, `work_links` and `work_data_providers`) for querying, either with the helpers of the
+/// store or with plain SQL through `connection`.
+///
+/// ```no_run
+/// use core_api_client::{store::Store, Api, FilterOperator};
+///
+/// let api = Api::from("API_KEY");
+/// let mut store = Store::open("works.sqlite").unwrap();
+///
+/// let query = api.paged_search(100, 0).and(FilterOperator::Eq("dataProviders", "86"));
+/// let imported = store.import(api.iter_works(query)).unwrap();
+///
+/// let works = store.works_by_author("Smith").unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Store {
+    conn: Connection,
+}
+
+/// A record that can be written to a `Store`: `Work`, `DataProvider` or `Journal`.
+pub trait StoreRecord {
+    /// Inserts the record, or replaces the stored record with the same key, including its rows in the
+    /// normalized tables.
+    fn upsert_into(&self, conn: &Connection) -> Result<(), Error>;
+}
+
+impl Store {
+    /// Opens (or creates) the store in an SQLite database file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::init(Connection::open(path)?)
+    }
+
+    /// Creates a store held in memory, lost when dropped.
+    pub fn open_in_memory() -> Result<Self, Error> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self, Error> {
+        conn.pragma_update(None, "foreign_keys", true)?;
+        conn.execute_batch(schema::SCHEMA)?;
+        Ok(Store { conn })
+    }
+
+    /// Returns the underlying SQLite connection, for queries not covered by the store.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Inserts or replaces a record.
+    pub fn upsert<T: StoreRecord>(&mut self, record: &T) -> Result<(), Error> {
+        let tx = self.conn.transaction()?;
+        record.upsert_into(&tx)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Inserts or replaces records in a single transaction, returning how many were written.
+    pub fn upsert_all<'a, T, I>(&mut self, records: I) -> Result<usize, Error>
+    where
+        T: StoreRecord + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        let tx = self.conn.transaction()?;
+        let mut count = 0;
+        for record in records {
+            record.upsert_into(&tx)?;
+            count += 1;
+        }
+        tx.commit()?;
+        Ok(count)
+    }
+
+    /// Writes the records of a search iterator (e.g. `Api::iter_works`) as they are fetched, returning how many
+    /// were written. Records are committed in batches, so when the iterator or an upsert fails the records written
+    /// before the error are kept and the error is returned.
+    pub fn import<T, I>(&mut self, records: I) -> Result<usize, Error>
+    where
+        T: StoreRecord,
+        I: IntoIterator<Item = Result<T, Error>>,
+    {
+        let mut records = records.into_iter();
+        let mut count = 0;
+        loop {
+            let tx = self.conn.transaction()?;
+            let mut batch = 0;
+            let mut failure = None;
+            for record in records.by_ref() {
+                if let Err(e) = record.and_then(|record| record.upsert_into(&tx)) {
+                    failure = Some(e);
+                    break;
+                }
+                batch += 1;
+                if batch == IMPORT_BATCH {
+                    break;
+                }
+            }
+            tx.commit()?;
+            count += batch;
+            if let Some(e) = failure {
+                return Err(e);
+            }
+            if batch < IMPORT_BATCH {
+                return Ok(count);
+            }
+        }
+    }
+}
+
+/// Formats a date as sortable ISO 8601 text.
+pub(crate) fn date_text(date: Option<&ApiDate>) -> Option<String> {
+    date.and_then(|date| date.value).map(|value| value.format("%Y-%m-%dT%H:%M:%S").to_string())
+}
+
+/// Runs a query selecting the `data` column and parses each row.
+pub(crate) fn query_records<T: DeserializeOwned>(conn: &Connection, sql: &str, params: &[&dyn ToSql]) -> Result<Vec<T>, Error> {
+    let mut statement = conn.prepare(sql)?;
+    let rows = statement.query_map(params_from_iter(params.iter()), |row| row.get::<_, String>(0))?;
+    let mut records = vec![];
+    for data in rows {
+        records.push(serde_json::from_str(&data?)?);
+    }
+    Ok(records)
+}
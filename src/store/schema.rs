@@ -0,0 +1,85 @@
+/// Tables of the store. Every record keeps its full JSON in `data`, the other columns and tables are
+/// normalized copies used for querying.
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS works (
+    id INTEGER PRIMARY KEY,
+    title TEXT,
+    doi TEXT,
+    year_published INTEGER,
+    document_type TEXT,
+    publisher TEXT,
+    language_code TEXT,
+    citation_count INTEGER,
+    updated_date TEXT,
+    data TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS works_doi ON works (doi);
+CREATE INDEX IF NOT EXISTS works_updated_date ON works (updated_date);
+
+CREATE TABLE IF NOT EXISTS work_authors (
+    work_id INTEGER NOT NULL REFERENCES works (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    orcid TEXT,
+    PRIMARY KEY (work_id, position)
+);
+CREATE INDEX IF NOT EXISTS work_authors_name ON work_authors (name);
+
+CREATE TABLE IF NOT EXISTS work_identifiers (
+    work_id INTEGER NOT NULL REFERENCES works (id) ON DELETE CASCADE,
+    type TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (work_id, type, value)
+);
+CREATE INDEX IF NOT EXISTS work_identifiers_value ON work_identifiers (type, value);
+
+CREATE TABLE IF NOT EXISTS work_references (
+    work_id INTEGER NOT NULL REFERENCES works (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    reference_id INTEGER,
+    doi TEXT,
+    title TEXT,
+    raw TEXT,
+    PRIMARY KEY (work_id, position)
+);
+
+CREATE TABLE IF NOT EXISTS work_links (
+    work_id INTEGER NOT NULL REFERENCES works (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    kind TEXT,
+    url TEXT NOT NULL,
+    PRIMARY KEY (work_id, position)
+);
+
+CREATE TABLE IF NOT EXISTS work_data_providers (
+    work_id INTEGER NOT NULL REFERENCES works (id) ON DELETE CASCADE,
+    data_provider_id INTEGER NOT NULL,
+    PRIMARY KEY (work_id, data_provider_id)
+);
+CREATE INDEX IF NOT EXISTS work_data_providers_provider ON work_data_providers (data_provider_id);
+
+CREATE TABLE IF NOT EXISTS data_providers (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    institution_name TEXT,
+    ror_id TEXT,
+    homepage_url TEXT,
+    oai_pmh_url TEXT,
+    data TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS journals (
+    identifier TEXT PRIMARY KEY,
+    title TEXT NOT NULL,
+    publisher TEXT,
+    language TEXT,
+    data TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS journal_identifiers (
+    journal TEXT NOT NULL REFERENCES journals (identifier) ON DELETE CASCADE,
+    identifier TEXT NOT NULL,
+    PRIMARY KEY (journal, identifier)
+);
+CREATE INDEX IF NOT EXISTS journal_identifiers_identifier ON journal_identifiers (identifier);
+";
@@ -0,0 +1,172 @@
+use chrono::NaiveDateTime;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::{errors::Error, Doi, IdentifierKind, Work};
+
+use super::{date_text, query_records, Store, StoreRecord};
+
+impl StoreRecord for Work {
+    fn upsert_into(&self, conn: &Connection) -> Result<(), Error> {
+        let id = self.id.ok_or_else(|| Error::InvalidIdentifier(format!("work without CORE id: {:?}", self.title)))?;
+        conn.execute(
+            "INSERT INTO works (id, title, doi, year_published, document_type, publisher, language_code, citation_count, updated_date, data)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+             ON CONFLICT (id) DO UPDATE SET
+                title = excluded.title, doi = excluded.doi, year_published = excluded.year_published,
+                document_type = excluded.document_type, publisher = excluded.publisher,
+                language_code = excluded.language_code, citation_count = excluded.citation_count,
+                updated_date = excluded.updated_date, data = excluded.data",
+            params![
+                id,
+                self.title,
+                self.doi.as_ref().map(|doi| doi.as_str()),
+                self.year_published,
+                self.document_type.as_ref().map(|kind| kind.as_str()),
+                self.publisher,
+                self.language.as_ref().and_then(|language| language.code.as_deref()),
+                self.citation_count,
+                date_text(self.updated_date.as_ref()),
+                serde_json::to_string(self)?,
+            ],
+        )?;
+
+        for table in ["work_authors", "work_identifiers", "work_references", "work_links", "work_data_providers"] {
+            conn.execute(&format!("DELETE FROM {} WHERE work_id = ?1", table), [id])?;
+        }
+
+        let mut authors = conn.prepare_cached("INSERT INTO work_authors (work_id, position, name, orcid) VALUES (?1, ?2, ?3, ?4)")?;
+        for (position, author) in self.authors.iter().flatten().enumerate() {
+            authors.execute(params![id, position, author.name, author.orcid.as_ref().map(|orcid| orcid.as_str())])?;
+        }
+
+        let mut identifiers = conn.prepare_cached("INSERT OR IGNORE INTO work_identifiers (work_id, type, value) VALUES (?1, ?2, ?3)")?;
+        for (kind, value) in work_identifiers(self) {
+            identifiers.execute(params![id, kind.as_str(), value])?;
+        }
+
+        let mut references = conn.prepare_cached(
+            "INSERT INTO work_references (work_id, position, reference_id, doi, title, raw) VALUES (?1, ?2, ?3, ?4, ?5, ?6)"
+        )?;
+        for (position, reference) in self.references.iter().flatten().enumerate() {
+            references.execute(params![
+                id,
+                position,
+                reference.id,
+                reference.doi.as_ref().map(|doi| doi.as_str()),
+                reference.title,
+                reference.raw,
+            ])?;
+        }
+
+        let mut links = conn.prepare_cached("INSERT INTO work_links (work_id, position, kind, url) VALUES (?1, ?2, ?3, ?4)")?;
+        for (position, link) in self.links.iter().flatten().enumerate() {
+            links.execute(params![id, position, link.kind().map(|kind| kind.as_str()), link.url()])?;
+        }
+
+        let mut providers = conn.prepare_cached("INSERT OR IGNORE INTO work_data_providers (work_id, data_provider_id) VALUES (?1, ?2)")?;
+        for provider in self.data_providers.iter().flatten() {
+            providers.execute(params![id, provider.id])?;
+        }
+        Ok(())
+    }
+}
+
+/// Identifiers of a work as (kind, value) pairs, from both its identifier fields and its `identifiers` list.
+fn work_identifiers(work: &Work) -> Vec<(IdentifierKind, String)> {
+    let mut identifiers = vec![];
+    if let Some(doi) = work.doi.as_ref() {
+        identifiers.push((IdentifierKind::Doi, doi.as_str().to_string()));
+    }
+    if let Some(arxiv_id) = work.arxiv_id.as_ref() {
+        identifiers.push((IdentifierKind::ArxivId, arxiv_id.as_str().to_string()));
+    }
+    if let Some(pubmed_id) = work.pubmed_id.as_ref() {
+        identifiers.push((IdentifierKind::PubmedId, pubmed_id.as_str().to_string()));
+    }
+    if let Some(mag_id) = work.mag_id.as_ref() {
+        identifiers.push((IdentifierKind::MagId, mag_id.clone()));
+    }
+    for oai_id in work.oai_ids.iter().flatten() {
+        identifiers.push((IdentifierKind::Oai, oai_id.as_str().to_string()));
+    }
+    for identifier in work.identifiers.iter().flatten() {
+        identifiers.push((identifier.identifier_type.clone(), identifier.identifier.clone()));
+    }
+    identifiers
+}
+
+impl Store {
+    /// Returns the work with a CORE id.
+    pub fn work(&self, id: i32) -> Result<Option<Work>, Error> {
+        let data: Option<String> = self.conn
+            .query_row("SELECT data FROM works WHERE id = ?1", [id], |row| row.get(0))
+            .optional()?;
+        Ok(data.map(|data| serde_json::from_str(&data)).transpose()?)
+    }
+
+    /// Returns the works with a DOI.
+    pub fn works_by_doi(&self, doi: &Doi) -> Result<Vec<Work>, Error> {
+        self.works_by_identifier(&IdentifierKind::Doi, doi.as_str())
+    }
+
+    /// Returns the works with an identifier of the given kind, e.g. `works_by_identifier(&IdentifierKind::Oai, "oai:...")`.
+    pub fn works_by_identifier(&self, kind: &IdentifierKind, value: &str) -> Result<Vec<Work>, Error> {
+        query_records(
+            &self.conn,
+            "SELECT data FROM works WHERE id IN (SELECT work_id FROM work_identifiers WHERE type = ?1 AND value = ?2) ORDER BY id",
+            &[&kind.as_str(), &value],
+        )
+    }
+
+    /// Returns the works with an author whose name contains `name` (case-insensitive for ASCII letters).
+    pub fn works_by_author(&self, name: &str) -> Result<Vec<Work>, Error> {
+        query_records(
+            &self.conn,
+            "SELECT data FROM works WHERE id IN (SELECT work_id FROM work_authors WHERE name LIKE ?1 ESCAPE '\\') ORDER BY id",
+            &[&like_pattern(name)],
+        )
+    }
+
+    /// Returns the works whose title contains `text` (case-insensitive for ASCII letters).
+    pub fn works_by_title(&self, text: &str) -> Result<Vec<Work>, Error> {
+        query_records(&self.conn, "SELECT data FROM works WHERE title LIKE ?1 ESCAPE '\\' ORDER BY id", &[&like_pattern(text)])
+    }
+
+    /// Returns the works harvested from a data provider.
+    pub fn works_by_data_provider(&self, data_provider_id: i32) -> Result<Vec<Work>, Error> {
+        query_records(
+            &self.conn,
+            "SELECT data FROM works WHERE id IN (SELECT work_id FROM work_data_providers WHERE data_provider_id = ?1) ORDER BY id",
+            &[&data_provider_id],
+        )
+    }
+
+    /// Returns the works updated in CORE after `since`, oldest update first.
+    pub fn works_updated_since(&self, since: NaiveDateTime) -> Result<Vec<Work>, Error> {
+        let since = since.format("%Y-%m-%dT%H:%M:%S").to_string();
+        query_records(&self.conn, "SELECT data FROM works WHERE updated_date > ?1 ORDER BY updated_date, id", &[&since])
+    }
+
+    /// Returns the most recent `updatedDate` of the stored works.
+    pub fn latest_updated_date(&self) -> Result<Option<NaiveDateTime>, Error> {
+        let latest: Option<String> = self.conn.query_row("SELECT MAX(updated_date) FROM works", [], |row| row.get(0))?;
+        Ok(latest.and_then(|latest| NaiveDateTime::parse_from_str(&latest, "%Y-%m-%dT%H:%M:%S").ok()))
+    }
+
+    /// Returns the number of stored works.
+    pub fn work_count(&self) -> Result<usize, Error> {
+        let count: i64 = self.conn.query_row("SELECT COUNT(*) FROM works", [], |row| row.get(0))?;
+        Ok(count as usize)
+    }
+
+    /// Removes a work, returning whether it was stored.
+    pub fn remove_work(&mut self, id: i32) -> Result<bool, Error> {
+        Ok(self.conn.execute("DELETE FROM works WHERE id = ?1", [id])? > 0)
+    }
+}
+
+/// Builds a `LIKE` pattern matching values containing `text`.
+pub(crate) fn like_pattern(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    format!("%{}%", escaped)
+}
@@ -0,0 +1,254 @@
+pub mod sink;
+mod state;
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use chrono::NaiveDateTime;
+
+use crate::{errors::Error, Api, SearchQuery, Work};
+
+use sink::{Change, SyncSink};
+use state::{format_date, parse_date, Checkpoint, HeldIds, RunProgress, SyncState};
+
+type TombstoneFn<'a> = Box<dyn Fn(&Work) -> bool + 'a>;
+
+/// Keeps a sink up to date with the works matching a base query, fetching only works updated since the last run.
+///
+/// The highest `updatedDate` applied for the base query is kept in a JSON state file (one file can hold many base
+/// queries). Each `run` searches for the base query restricted to `updatedDate` at or after that date, sorted by
+/// `updatedDate`, and applies every work to the sink as an upsert. Every page starts at the highest `updatedDate`
+/// applied so far rather than at an offset, so works updated during the run are not skipped and large result sets
+/// are not cut off by the offset limit of the API. Works for which the `tombstone_if` predicate holds are applied as
+/// deletions instead, and `full_sync` deletes the works the sink holds that no longer match the query. Works without
+/// an `updatedDate` can not be ordered and are only fetched until a dated work has been applied.
+///
+/// The position of a run is saved after every page, so a run that fails (e.g. on the rate limit) is resumed by the
+/// next one; works may be applied twice, which sinks treat as a no-op. An interrupted `full_sync` starts over.
+/// The CORE ids held by the sink are kept in a second file next to the state file (`<state>.ids.json`), written
+/// once per run. Pages have the limit of the base query (100 if it has none). Requests bypass the response caches
+/// of the `Api`, so a sync never sees stale results.
+///
+/// ```no_run
+/// use std::fs::OpenOptions;
+/// use core_api_client::{sync::{sink::JsonLinesSink, SyncEngine}, Api, FilterOperator};
+///
+/// let api = Api::from("API_KEY");
+/// let query = api.paged_search(100, 0).and(FilterOperator::Eq("dataProviders", "86"));
+/// let mut sink = JsonLinesSink::new(OpenOptions::new().create(true).append(true).open("changes.jsonl").unwrap());
+///
+/// let report = SyncEngine::new(&api, query, "sync-state.json").run(&mut sink).unwrap();
+/// println!("{} updated, {} deleted", report.upserted, report.deleted);
+/// ```
+pub struct SyncEngine<'a, T1 = String, T2 = String>
+where
+    T1: ToString,
+    T2: ToString,
+{
+    api: &'a Api,
+    query: SearchQuery<T1, T2>,
+    state_path: PathBuf,
+    tombstone: Option<TombstoneFn<'a>>,
+}
+
+/// Summary of a sync run.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SyncReport {
+    /// Lower bound of `updatedDate` used by the run, `None` if all works were fetched.
+    pub since: Option<NaiveDateTime>,
+
+    /// Highest `updatedDate` applied so far, used by the next run.
+    pub last_updated: Option<NaiveDateTime>,
+
+    /// Whether the run resumed an interrupted one.
+    pub resumed: bool,
+
+    /// Number of pages fetched.
+    pub pages: usize,
+
+    /// Number of works upserted.
+    pub upserted: usize,
+
+    /// Number of works deleted.
+    pub deleted: usize,
+}
+
+impl<'a, T1, T2> SyncEngine<'a, T1, T2>
+where
+    T1: ToString + Clone,
+    T2: ToString + Clone,
+{
+    /// Creates an engine syncing the works matching `query`, with its progress kept in the file at `state_path`.
+    pub fn new<P: AsRef<Path>>(api: &'a Api, query: SearchQuery<T1, T2>, state_path: P) -> Self {
+        SyncEngine { api, query, state_path: state_path.as_ref().to_path_buf(), tombstone: None }
+    }
+
+    /// Applies the fetched works for which `predicate` holds as deletions, e.g. works marked as withdrawn.
+    pub fn tombstone_if<F>(self, predicate: F) -> Self
+    where
+        F: Fn(&Work) -> bool + 'a,
+    {
+        Self { tombstone: Some(Box::new(predicate)), ..self }
+    }
+
+    /// Returns the highest `updatedDate` applied by the completed runs for the base query.
+    pub fn last_updated(&self) -> Result<Option<NaiveDateTime>, Error> {
+        let state = SyncState::load(&self.state_path)?;
+        let checkpoint = state.checkpoints.get(&self.key());
+        Ok(checkpoint.and_then(|checkpoint| checkpoint.last_updated.as_deref()).and_then(parse_date))
+    }
+
+    /// Forgets the progress for the base query, so the next run fetches all works again.
+    pub fn reset(&self) -> Result<(), Error> {
+        let mut state = SyncState::load(&self.state_path)?;
+        if state.checkpoints.remove(&self.key()).is_some() {
+            state.save(&self.state_path)?;
+        }
+        Ok(())
+    }
+
+    /// Applies the works updated since the last run to the sink (all works on the first run).
+    pub fn run<S: SyncSink>(&self, sink: &mut S) -> Result<SyncReport, Error> {
+        self.sync(sink, false)
+    }
+
+    /// Applies all works matching the base query to the sink, then deletes the works applied by earlier runs
+    /// that no longer match it.
+    pub fn full_sync<S: SyncSink>(&self, sink: &mut S) -> Result<SyncReport, Error> {
+        self.sync(sink, true)
+    }
+
+    fn key(&self) -> String {
+        self.query.clone().without_page().parse()
+    }
+
+    fn sync<S: SyncSink>(&self, sink: &mut S, full: bool) -> Result<SyncReport, Error> {
+        let key = self.key();
+        let mut state = SyncState::load(&self.state_path)?;
+        let mut held = HeldIds::load(&self.state_path)?;
+        let mut checkpoint = state.checkpoints.remove(&key).unwrap_or_default();
+
+        let (progress, resumed) = match checkpoint.run.take() {
+            Some(progress) if !full => (progress, true),
+            _ => {
+                let since = if full { None } else { checkpoint.last_updated.clone() };
+                (RunProgress { since: since.clone(), cursor: since, ..Default::default() }, false)
+            },
+        };
+        let mut run = Run {
+            ids: held.ids.remove(&key).unwrap_or_default(),
+            seen: BTreeSet::new(),
+            report: SyncReport {
+                since: progress.since.as_deref().and_then(parse_date),
+                resumed,
+                ..SyncReport::default()
+            },
+            progress,
+        };
+
+        // the held ids are saved even if the run fails, as the sink already holds the applied works
+        let result = self.fetch_pages(sink, &mut state, &key, &mut checkpoint, &mut run, full);
+        held.ids.insert(key.clone(), run.ids.clone());
+        held.save(&self.state_path)?;
+        result?;
+
+        if full {
+            let removed: Vec<i32> = run.ids.difference(&run.seen).copied().collect();
+            for id in removed {
+                sink.apply(Change::Delete(id))?;
+                run.ids.remove(&id);
+                run.report.deleted += 1;
+            }
+            sink.commit()?;
+            held.ids.insert(key.clone(), run.ids);
+            held.save(&self.state_path)?;
+        }
+
+        checkpoint.last_updated = run.progress.cursor.max(checkpoint.last_updated);
+        checkpoint.run = None;
+        self.save(&mut state, &key, &checkpoint)?;
+        run.report.last_updated = checkpoint.last_updated.as_deref().and_then(parse_date);
+        Ok(run.report)
+    }
+
+    /// Fetches the works matching the base query sorted by `updatedDate`, starting each page at the highest
+    /// `updatedDate` applied so far instead of paging by offset.
+    fn fetch_pages<S: SyncSink>(
+        &self,
+        sink: &mut S,
+        state: &mut SyncState,
+        key: &str,
+        checkpoint: &mut Checkpoint,
+        run: &mut Run,
+        full: bool,
+    ) -> Result<(), Error> {
+        let page_size = self.query.limit().filter(|limit| *limit > 0).unwrap_or(100);
+        let base = self.query.clone().grouped().sorted("updatedDate:asc");
+
+        loop {
+            let progress = &mut run.progress;
+            let mut query = base.clone();
+            if let Some(cursor) = progress.cursor.as_ref() {
+                query = query.and_raw(format!("updatedDate>=%22{}%22", cursor));
+            }
+            let offset = progress.offset;
+            let response = self.api.search_works_uncached(query.with_page(page_size, offset))?.response;
+            let results = response.results.unwrap_or_default();
+            run.report.pages += 1;
+
+            for work in results.iter() {
+                let updated = work.updated_date.as_ref().and_then(|date| date.value).map(format_date);
+                let advanced = updated.is_some() && updated > progress.cursor;
+                if advanced {
+                    progress.cursor = updated;
+                    progress.offset = 0;
+                    progress.boundary.clear();
+                }
+                progress.offset += 1;
+
+                let Some(id) = work.id else { continue };
+                if !progress.boundary.insert(id) {
+                    continue;
+                }
+                if self.tombstone.as_ref().is_some_and(|tombstone| tombstone(work)) {
+                    sink.apply(Change::Delete(id))?;
+                    run.ids.remove(&id);
+                    run.seen.remove(&id);
+                    run.report.deleted += 1;
+                } else {
+                    sink.apply(Change::Upsert(work))?;
+                    run.ids.insert(id);
+                    run.seen.insert(id);
+                    run.report.upserted += 1;
+                }
+            }
+            sink.commit()?;
+
+            let fetched = offset + results.len() as i32;
+            let done = results.len() < page_size as usize
+                || response.total_hits.is_some_and(|total| fetched >= total);
+            if !full {
+                checkpoint.run = Some(progress.clone());
+                self.save(state, key, checkpoint)?;
+            }
+            if done {
+                return Ok(());
+            }
+        }
+    }
+
+    fn save(&self, state: &mut SyncState, key: &str, checkpoint: &Checkpoint) -> Result<(), Error> {
+        state.checkpoints.insert(key.to_string(), checkpoint.clone());
+        state.save(&self.state_path)
+    }
+}
+
+/// Progress of a run kept in memory.
+struct Run {
+    progress: RunProgress,
+    ids: BTreeSet<i32>,
+    seen: BTreeSet<i32>,
+    report: SyncReport,
+}
@@ -0,0 +1,84 @@
+use std::io::Write;
+
+use serde_json::json;
+
+use crate::{errors::Error, Work};
+
+/// A change applied to a `SyncSink`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change<'a> {
+    /// A work was added or updated.
+    Upsert(&'a Work),
+    /// A work was removed (a tombstone), holds its CORE id.
+    Delete(i32),
+}
+
+/// Destination of the changes found by a `SyncEngine`.
+///
+/// Changes may be applied again after an interrupted run is resumed, so sinks must treat upserting an
+/// unchanged work and deleting a missing one as no-ops. Implemented for `JsonLinesSink`, for closures
+/// taking a `Change` and, with the `sqlite` feature, for `Store`.
+pub trait SyncSink {
+    /// Applies a change.
+    fn apply(&mut self, change: Change<'_>) -> Result<(), Error>;
+
+    /// Called after every page of changes, before the sync progress is saved.
+    fn commit(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<F> SyncSink for F
+where
+    F: FnMut(Change<'_>) -> Result<(), Error>,
+{
+    fn apply(&mut self, change: Change<'_>) -> Result<(), Error> {
+        self(change)
+    }
+}
+
+/// Sink writing changes as JSON Lines, one object per change:
+/// `{"action":"upsert","id":1,"work":{...}}` or `{"action":"delete","id":1}`.
+#[derive(Debug)]
+pub struct JsonLinesSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonLinesSink<W> {
+    /// Creates a sink writing to `writer`, e.g. a file opened in append mode.
+    pub fn new(writer: W) -> Self {
+        JsonLinesSink { writer }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> SyncSink for JsonLinesSink<W> {
+    fn apply(&mut self, change: Change<'_>) -> Result<(), Error> {
+        let line = match change {
+            Change::Upsert(work) => json!({ "action": "upsert", "id": work.id, "work": work }),
+            Change::Delete(id) => json!({ "action": "delete", "id": id }),
+        };
+        serde_json::to_writer(&mut self.writer, &line)?;
+        self.writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    fn commit(&mut self) -> Result<(), Error> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl SyncSink for crate::store::Store {
+    fn apply(&mut self, change: Change<'_>) -> Result<(), Error> {
+        match change {
+            Change::Upsert(work) => self.upsert(work),
+            Change::Delete(id) => self.remove_work(id).map(|_| ()),
+        }
+    }
+}
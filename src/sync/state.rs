@@ -0,0 +1,98 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Error;
+
+const DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+/// Sync progress of every base query, persisted as a JSON file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub(crate) struct SyncState {
+    pub(crate) checkpoints: BTreeMap<String, Checkpoint>,
+}
+
+/// What has been synced for a base query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub(crate) struct Checkpoint {
+    /// Highest `updatedDate` of the works applied by the last completed run.
+    pub(crate) last_updated: Option<String>,
+
+    /// Progress of an interrupted incremental run, resumed by the next one.
+    pub(crate) run: Option<RunProgress>,
+}
+
+/// Position of a run in the results sorted by `updatedDate`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub(crate) struct RunProgress {
+    /// Lower bound of `updatedDate` the run started from.
+    pub(crate) since: Option<String>,
+
+    /// Highest `updatedDate` applied so far, the next page starts at it.
+    pub(crate) cursor: Option<String>,
+
+    /// Number of results already fetched at or after the cursor.
+    pub(crate) offset: i32,
+
+    /// CORE ids of the works already applied at the cursor, skipped when they are fetched again.
+    pub(crate) boundary: BTreeSet<i32>,
+}
+
+/// CORE ids of the works held by the sink for every base query, kept next to the state file and only
+/// written at the end of a run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub(crate) struct HeldIds {
+    pub(crate) ids: BTreeMap<String, BTreeSet<i32>>,
+}
+
+impl SyncState {
+    pub(crate) fn load(path: &Path) -> Result<Self, Error> {
+        if !path.exists() {
+            return Ok(SyncState::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    /// Writes the state next to `path` and renames it, so an interrupted write never loses the previous state.
+    pub(crate) fn save(&self, path: &Path) -> Result<(), Error> {
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(self)?)?;
+        fs::rename(tmp, path)?;
+        Ok(())
+    }
+}
+
+impl HeldIds {
+    pub(crate) fn load(state_path: &Path) -> Result<Self, Error> {
+        let path = Self::path(state_path);
+        if !path.exists() {
+            return Ok(HeldIds::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub(crate) fn save(&self, state_path: &Path) -> Result<(), Error> {
+        let path = Self::path(state_path);
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_string(self)?)?;
+        fs::rename(tmp, path)?;
+        Ok(())
+    }
+
+    fn path(state_path: &Path) -> std::path::PathBuf {
+        state_path.with_extension("ids.json")
+    }
+}
+
+pub(crate) fn format_date(date: NaiveDateTime) -> String {
+    date.format(DATE_FORMAT).to_string()
+}
+
+pub(crate) fn parse_date(date: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(date, DATE_FORMAT).ok()
+}